@@ -1,25 +1,182 @@
-use std::collections::VecDeque;
-use std::time::Instant;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::Result;
 use crossterm::event::{KeyEvent, MouseEvent};
 use image::imageops::FilterType;
+use ratatui::layout::{Position, Rect};
 use ratatui_image::picker::Picker;
 use ratatui_image::protocol::StatefulProtocol;
 
-use crate::config::TuiConfig;
+use crate::config::{
+    CpuNormalize, CpuView, IpVersion, MemWarnMode, SelectionStyle, SparklineNewest, SparklineStyle,
+    TuiConfig, WAIFU_PREFETCH,
+};
 use crate::data::claudepersonal::ClaudePersonalReport;
+use crate::data::sysmetrics::{NetInfo, PressureWeights};
 use crate::data::waifu::WaifuEntry;
 use crate::data::waifu_client::FetchResult;
 use crate::data::{
-    self, BillingReport, CacheReader, ClaudeUsage, K8sStatus, SysMetrics, TailscaleStatus,
+    self, BillingReport, CacheFileInfo, CacheReader, CacheWatcher, ClaudeUsage, K8sStatus,
+    SysMetrics, TailscaleStatus,
 };
+use crate::ui::gradient::{Gradient, PCT_STOPS, TEMP_STOPS};
 
 use tokio::sync::mpsc;
 
 /// Maximum number of historical data points for sparklines (~60s at 1s interval).
 const HISTORY_LEN: usize = 60;
 
+/// How long resize events must settle before the waifu image is re-scaled.
+const RESIZE_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// True on the first launch: the marker in `dir` is missing. Writes it so
+/// later launches skip the hint; if it can't be written, skip the hint too
+/// rather than showing it every time.
+fn take_first_run(dir: &std::path::Path) -> bool {
+    let marker = dir.join(FIRST_RUN_MARKER);
+    if marker.exists() {
+        return false;
+    }
+    match std::fs::create_dir_all(dir).and_then(|()| std::fs::write(&marker, "")) {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::debug!("first-run marker {}: {e}", marker.display());
+            false
+        }
+    }
+}
+
+/// `+`/`-` step from `ms`: 250 ms up to 5 s, then 5 s so slow intervals
+/// (e.g. 30 s) are a few presses away.
+fn refresh_step(ms: u64) -> u64 {
+    if ms < 5000 {
+        250
+    } else {
+        5000
+    }
+}
+
+/// How long a process's open-FD count is reused before `/proc` is re-read.
+const FD_REFRESH: Duration = Duration::from_secs(5);
+
+/// How long a process's swapped-out size is reused before `/proc` is re-read.
+const SWAP_REFRESH: Duration = Duration::from_secs(5);
+
+/// How long a process's nice value is reused before `/proc` is re-read.
+const NICE_REFRESH: Duration = Duration::from_secs(5);
+
+/// Minimum gap between terminal title rewrites.
+const TITLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long the first-run "Press ? for help" hint stays in the help bar.
+pub const FIRST_RUN_HINT: Duration = Duration::from_secs(10);
+
+/// Marker file (in the state dir) recording that the first-run hint was shown.
+const FIRST_RUN_MARKER: &str = "tui-first-run";
+
+/// How long a normal quit waits for an in-flight waifu fetch to finish, so
+/// the mirror doesn't log a reset connection. Ctrl+C skips the wait.
+pub const WAIFU_DRAIN: Duration = Duration::from_millis(500);
+
+/// Window for the second `q` press when `confirm_quit` is enabled.
+pub const QUIT_CONFIRM_WINDOW: Duration = Duration::from_millis(1500);
+
+/// Braille spinner frames for in-flight async operations.
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// How long a process that appeared after launch is highlighted as new.
+pub const NEW_PROCESS_WINDOW: Duration = Duration::from_secs(10);
+
+/// Change feed: CPU moves of at least this many points count as a change.
+const DIFF_CPU_POINTS: f32 = 5.0;
+/// Change feed: memory moves of at least this fraction count as a change.
+const DIFF_MEM_RATIO: f64 = 0.10;
+
+/// How a process row differs from the previous sample (change feed mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessChange {
+    New,
+    Changed,
+    Gone,
+}
+
+/// Memory usage (%) at which the help bar shows a pressure badge.
+pub const MEM_PRESSURE_PCT: f64 = 80.0;
+
+/// How long a status-line message stays visible.
+const STATUS_MSG_TTL: Duration = Duration::from_secs(4);
+
+/// Max entries kept in the in-session kill log (oldest dropped first).
+pub const KILL_LOG_LEN: usize = 50;
+
+/// Signals offered by the process signal menu, in display order.
+pub const SIGNAL_MENU: [(sysinfo::Signal, &str); 10] = [
+    (sysinfo::Signal::Hangup, "SIGHUP"),
+    (sysinfo::Signal::Interrupt, "SIGINT"),
+    (sysinfo::Signal::Quit, "SIGQUIT"),
+    (sysinfo::Signal::Term, "SIGTERM"),
+    (sysinfo::Signal::Kill, "SIGKILL"),
+    (sysinfo::Signal::User1, "SIGUSR1"),
+    (sysinfo::Signal::User2, "SIGUSR2"),
+    (sysinfo::Signal::Stop, "SIGSTOP"),
+    (sysinfo::Signal::Continue, "SIGCONT"),
+    (sysinfo::Signal::Winch, "SIGWINCH"),
+];
+
+/// Display name for a signal (e.g. "SIGHUP").
+pub fn signal_name(signal: sysinfo::Signal) -> &'static str {
+    SIGNAL_MENU
+        .iter()
+        .find(|(s, _)| *s == signal)
+        .map(|(_, name)| *name)
+        .unwrap_or("signal")
+}
+
+/// Actions in the process right-click menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessAction {
+    Terminate,
+    Kill,
+    CopyPid,
+    Detail,
+}
+
+/// Process right-click menu entries, in display order.
+pub const PROCESS_MENU: [(ProcessAction, &str); 4] = [
+    (ProcessAction::Terminate, "Terminate (TERM)"),
+    (ProcessAction::Kill, "Force kill (KILL)"),
+    (ProcessAction::CopyPid, "Copy PID"),
+    (ProcessAction::Detail, "Show detail"),
+];
+
+/// Open process right-click menu: highlighted entry and the clicked cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessMenu {
+    pub selected: usize,
+    pub anchor: Position,
+}
+
+/// Process the signal picker was opened on. The list re-sorts while the
+/// menu is open, so the signal goes to this PID, not the current selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalTarget {
+    pub pid: u32,
+    pub name: String,
+    pub user: String,
+}
+
+/// Daemon cache sources, by cache file name, in re-read order.
+const DAEMON_SOURCES: [&str; 5] = ["tailscale", "claude", "billing", "k8s", "claude-personal"];
+
+/// Cache re-read interval when polling (no watcher available).
+const CACHE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Full cache re-read interval while watching, so max-age expiry still applies
+/// to files that stopped changing.
+const CACHE_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Active tab in the TUI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tab {
@@ -39,6 +196,14 @@ impl Tab {
         Tab::Build,
     ];
 
+    /// Tab from its config name (the lowercase title, e.g. "system").
+    pub fn parse(name: &str) -> Option<Tab> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|t| t.title().eq_ignore_ascii_case(name.trim()))
+    }
+
     pub fn title(&self) -> &str {
         match self {
             Tab::Dashboard => "Dashboard",
@@ -51,6 +216,7 @@ impl Tab {
 }
 
 /// Process info for the process table widget.
+#[derive(Clone)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub ppid: u32,
@@ -61,7 +227,10 @@ pub struct ProcessInfo {
     pub memory_bytes: u64,
     pub state: ProcessState,
     pub run_time_secs: u64,
-    pub tree_depth: usize, // 0 = root, 1+ = child depth
+    pub tree_depth: usize,  // 0 = root, 1+ = child depth
+    pub cgroup: String,     // short container ID or systemd unit ("" = none/unknown)
+    pub exe: String,        // resolved executable path ("" = unknown)
+    pub group_count: usize, // processes folded into this row (1 unless grouped by exe)
 }
 
 /// Process running state.
@@ -86,6 +255,135 @@ impl ProcessState {
     }
 }
 
+/// What the Dashboard shows in the waifu's place when waifu is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashboardRightMode {
+    /// The waifu image.
+    #[default]
+    Waifu,
+    /// The non-waifu layout with Kubernetes and the other daemon widgets.
+    Cluster,
+}
+
+/// Widget that owns tab-specific keys on the active tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusTarget {
+    /// Waifu gallery (Dashboard with waifu area visible).
+    Waifu,
+    /// Tailscale peer table (Network tab).
+    Tailscale,
+    /// Process table (System tab, and the fallback on every other tab).
+    Processes,
+}
+
+impl FocusTarget {
+    /// The zoomable widget this focus lives in.
+    pub fn widget(self) -> WidgetId {
+        match self {
+            Self::Waifu => WidgetId::Waifu,
+            Self::Tailscale => WidgetId::Tailscale,
+            Self::Processes => WidgetId::Processes,
+        }
+    }
+}
+
+/// A widget that can be zoomed to the full screen (`--expand <id>`, or Enter
+/// on a tab and Tab/Shift-Tab to cycle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WidgetId {
+    Waifu,
+    Host,
+    Cpu,
+    CpuCores,
+    Memory,
+    Disks,
+    Temps,
+    Processes,
+    Network,
+    Tailscale,
+    K8s,
+    ClaudePersonal,
+    Claude,
+    Billing,
+    Build,
+}
+
+impl WidgetId {
+    pub const ALL: &[WidgetId] = &[
+        WidgetId::Waifu,
+        WidgetId::Host,
+        WidgetId::Cpu,
+        WidgetId::CpuCores,
+        WidgetId::Memory,
+        WidgetId::Disks,
+        WidgetId::Temps,
+        WidgetId::Processes,
+        WidgetId::Network,
+        WidgetId::Tailscale,
+        WidgetId::K8s,
+        WidgetId::ClaudePersonal,
+        WidgetId::Claude,
+        WidgetId::Billing,
+        WidgetId::Build,
+    ];
+
+    /// Identifier accepted by `--expand`.
+    pub fn id(self) -> &'static str {
+        match self {
+            WidgetId::Waifu => "waifu",
+            WidgetId::Host => "host",
+            WidgetId::Cpu => "cpu",
+            WidgetId::CpuCores => "cores",
+            WidgetId::Memory => "memory",
+            WidgetId::Disks => "disks",
+            WidgetId::Temps => "temps",
+            WidgetId::Processes => "processes",
+            WidgetId::Network => "network",
+            WidgetId::Tailscale => "tailscale",
+            WidgetId::K8s => "k8s",
+            WidgetId::ClaudePersonal => "claude-personal",
+            WidgetId::Claude => "claude",
+            WidgetId::Billing => "billing",
+            WidgetId::Build => "build",
+        }
+    }
+
+    pub fn parse(id: &str) -> Option<WidgetId> {
+        Self::ALL.iter().copied().find(|w| w.id() == id)
+    }
+
+    /// Tab the widget lives on (where zoom cycling continues from).
+    pub fn home_tab(self) -> Tab {
+        match self {
+            WidgetId::Waifu | WidgetId::Host | WidgetId::Cpu => Tab::Dashboard,
+            WidgetId::CpuCores
+            | WidgetId::Memory
+            | WidgetId::Disks
+            | WidgetId::Temps
+            | WidgetId::Processes => Tab::System,
+            WidgetId::Network | WidgetId::Tailscale | WidgetId::K8s => Tab::Network,
+            WidgetId::ClaudePersonal | WidgetId::Claude | WidgetId::Billing => Tab::Billing,
+            WidgetId::Build => Tab::Build,
+        }
+    }
+
+    /// Zoomable widgets of `tab`, in cycle order.
+    pub fn for_tab(tab: Tab, waifu: bool) -> Vec<WidgetId> {
+        use WidgetId::*;
+        match tab {
+            Tab::Dashboard => {
+                let mut list = if waifu { vec![Waifu] } else { Vec::new() };
+                list.extend([Host, Cpu, Memory, Disks, Tailscale, K8s, Claude, Billing]);
+                list
+            }
+            Tab::System => vec![CpuCores, Memory, Disks, Temps, Processes, Network],
+            Tab::Network => vec![Network, Tailscale, K8s],
+            Tab::Billing => vec![ClaudePersonal, Claude, Billing],
+            Tab::Build => vec![Build],
+        }
+    }
+}
+
 /// Process sort column.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProcessSort {
@@ -93,30 +391,172 @@ pub enum ProcessSort {
     Memory,
     Pid,
     Name,
+    /// Swapped-out size (Linux `VmSwap`).
+    Swap,
+}
+
+/// Process table column (configurable via `[display] process_columns`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessColumn {
+    State,
+    Pid,
+    User,
+    Name,
+    Cpu,
+    Mem,
+    Time,
+    Cgroup,
+    Fds,
+    Swap,
+    Nice,
+}
+
+impl ProcessColumn {
+    pub const DEFAULT: &[ProcessColumn] = &[
+        ProcessColumn::State,
+        ProcessColumn::Pid,
+        ProcessColumn::User,
+        ProcessColumn::Name,
+        ProcessColumn::Cpu,
+        ProcessColumn::Mem,
+        ProcessColumn::Time,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "state" => Some(Self::State),
+            "pid" => Some(Self::Pid),
+            "user" => Some(Self::User),
+            "name" | "cmd" => Some(Self::Name),
+            "cpu" => Some(Self::Cpu),
+            "mem" | "memory" => Some(Self::Mem),
+            "time" => Some(Self::Time),
+            "cgroup" | "container" | "unit" => Some(Self::Cgroup),
+            "fds" | "fd" => Some(Self::Fds),
+            "swap" => Some(Self::Swap),
+            "nice" | "ni" => Some(Self::Nice),
+            _ => None,
+        }
+    }
+
+    /// Sort key backed by this column, if any.
+    pub fn sort(&self) -> Option<ProcessSort> {
+        match self {
+            Self::Pid => Some(ProcessSort::Pid),
+            Self::Name => Some(ProcessSort::Name),
+            Self::Cpu => Some(ProcessSort::Cpu),
+            Self::Mem => Some(ProcessSort::Memory),
+            Self::Swap => Some(ProcessSort::Swap),
+            _ => None,
+        }
+    }
+
+    /// Starting sort: CPU when that column is shown, otherwise the first
+    /// visible sortable column (CPU again if none is sortable), so the table
+    /// isn't ordered by a column the user can't see.
+    pub fn initial_sort(columns: &[Self]) -> ProcessSort {
+        if columns.contains(&Self::Cpu) {
+            return ProcessSort::Cpu;
+        }
+        columns
+            .iter()
+            .find_map(Self::sort)
+            .unwrap_or(ProcessSort::Cpu)
+    }
+
+    /// Resolve configured column names in order. Unknown names and duplicates
+    /// are skipped with a warning; an empty result falls back to the default set.
+    pub fn parse_list(names: &[String]) -> Vec<Self> {
+        let mut cols: Vec<Self> = Vec::new();
+        for name in names {
+            match Self::from_name(name) {
+                Some(col) if !cols.contains(&col) => cols.push(col),
+                Some(_) => tracing::warn!("duplicate process column {name:?}, skipping"),
+                None => tracing::warn!("unknown process column {name:?}, skipping"),
+            }
+        }
+        if cols.is_empty() {
+            Self::DEFAULT.to_vec()
+        } else {
+            cols
+        }
+    }
+}
+
+/// `--debug` cache inspector: file listing plus an optional raw JSON view.
+pub struct CacheDebugView {
+    pub files: Vec<CacheFileInfo>,
+    pub selected: usize,
+    /// Contents of the selected file when the raw popup is open.
+    pub raw: Option<String>,
+    /// First raw line shown; clamped on draw so the last page stays full.
+    pub raw_scroll: u16,
 }
 
 /// Application state.
 pub struct App {
     pub cfg: TuiConfig,
     pub active_tab: Tab,
+    /// Tab bar, Tab/Shift-Tab and number-key order (`general.tab_order`).
+    pub tab_order: Vec<Tab>,
     pub term_width: u16,
     pub term_height: u16,
     pub show_help: bool,
-    pub help_tab: usize, // 0=TUI, 1=Shell, 2=Lab, 3=Starship
+    pub help_tab: usize,     // 0=TUI, 1=Shell, 2=Lab, 3=Starship
+    pub show_about: bool,    // 'v' version popup
+    pub chrome_hidden: bool, // 'H' hides tab bar + help bar
     pub frozen: bool,
+    pub colors_enabled: bool, // false under NO_COLOR / --no-color (monochrome)
 
     // Process filter (btm-style '/' search).
     pub process_filter: String,
     pub filter_mode: bool,
+    // Name of the `[filters] saved` entry currently in `process_filter`.
+    pub active_saved_filter: Option<String>,
 
-    // Adjustable refresh interval (500ms to 5000ms).
+    // Adjustable refresh interval, kept within general.min/max_refresh_ms.
     pub refresh_ms: u64,
+    pub refresh_bounds: (u64, u64),
 
     // Process expanded command toggle ('e' key).
     pub show_cmd: bool,
 
     // Process tree view toggle ('t' key).
     pub tree_mode: bool,
+    // One row per executable ('X' key): count, summed CPU and memory.
+    pub group_by_exe: bool,
+    // Change feed ('N' key): only rows that appeared, exited or moved since
+    // the previous sample, with each shown row's change by PID. The sample
+    // is None until the first refresh after 'N' seeds it (the table on
+    // screen is already cut to max_processes, so it can't be the baseline).
+    pub diff_mode: bool,
+    pub process_changes: HashMap<u32, ProcessChange>,
+    prev_process_sample: Option<HashMap<u32, ProcessInfo>>,
+
+    // Process table columns in display order (from config).
+    pub process_columns: Vec<ProcessColumn>,
+
+    // History graph style (display.sparkline_style).
+    pub sparkline_style: SparklineStyle,
+    // History graph direction (display.sparkline_newest).
+    pub sparkline_newest: SparklineNewest,
+    // Per-core CPU widget style (display.cpu_view).
+    pub cpu_view: CpuView,
+    // Future-timestamp tolerance for daemon reports (general.clock_skew_secs).
+    pub clock_skew_secs: u64,
+    // Memory widget warning basis (display.mem_warn_mode).
+    pub mem_warn_mode: MemWarnMode,
+    // Selected-row style for tables and lists (display.selection_style).
+    pub selection_style: SelectionStyle,
+    // Dashboard pressure gauge inputs (display.pressure_weights).
+    pub pressure_weights: PressureWeights,
+    // Composite pressure score (0-100), recomputed on each system refresh.
+    pub pressure_score: f64,
+    // Process CPU% scale (display.cpu_normalize).
+    pub cpu_normalize: CpuNormalize,
+    // Usage and temperature color gradients (theme.gradient / theme.temp_gradient).
+    pub gradient: Gradient,
+    pub temp_gradient: Gradient,
 
     // Live system data (collected in-process).
     pub sys: SysMetrics,
@@ -124,22 +564,132 @@ pub struct App {
     // Historical data for sparklines (newest at back).
     pub cpu_history: VecDeque<f64>,
     pub cpu_per_core_history: Vec<VecDeque<f64>>,
+    pub cpu_freq_peak: Vec<u64>, // highest observed MHz per core (throttle hint)
     pub mem_history: VecDeque<f64>,
     pub swap_history: VecDeque<f64>,
     pub net_rx_history: VecDeque<f64>,
     pub net_tx_history: VecDeque<f64>,
+    /// Per-interface (rx, tx) byte counters when the TUI started (or when
+    /// the interface first appeared, or its counters last reset), for the
+    /// Network tab's session total.
+    pub session_net_baseline: HashMap<String, (u64, u64)>,
     pub load_history: VecDeque<f64>,
     pub temp_history: VecDeque<f64>, // max temperature over last 60s
 
     // Process kill: double-d (btm-style) confirmation.
     pub pending_kill: Option<Instant>, // timestamp of first 'd' press
+    // User the TUI runs as ("" if unknown), and the PID of another user's
+    // process we've warned about, so repeating the kill goes ahead.
+    pub current_user: String,
+    pub foreign_kill_warned: Option<u32>,
+
+    // Signal picker popup ('s' key): selected row in SIGNAL_MENU when open,
+    // and the process it was opened on.
+    pub signal_menu: Option<usize>,
+    pub signal_target: Option<SignalTarget>,
+    // Right-click process menu, and its last drawn area (mouse hit-testing).
+    pub process_menu: Option<ProcessMenu>,
+    pub process_menu_rect: Option<Rect>,
+    // Last drawn process-table body and the process index of its first row.
+    pub process_rows_rect: Option<(Rect, usize)>,
+    // Text for the main loop to put on the clipboard (OSC 52).
+    pub pending_clipboard: Option<String>,
+
+    // Transient status-line message (e.g. signal delivery result).
+    pub status_msg: Option<(String, Instant)>,
+
+    // Session kill audit log ('L' popup): (when, pid, name, was SIGKILL).
+    pub kill_log: Vec<(Instant, u32, String, bool)>,
+    pub show_kill_log: bool,
+    // Color legend popup ('l'): what the gradient colors mean.
+    pub show_legend: bool,
+
+    // Process detail popup ('o'): the PID it shows (kept after closing so
+    // reopening the same process continues its graph) and that process's
+    // CPU samples, appended each refresh while the popup is open. A grouped
+    // row ('X') samples the group's summed CPU instead of the single PID.
+    pub detail_pid: Option<u32>,
+    pub detail_grouped: bool,
+    pub show_process_detail: bool,
+    pub detail_cpu_history: VecDeque<f64>,
+
+    // `--prometheus <path>`: textfile-collector output rewritten on every
+    // metrics refresh; the flag keeps a failing write from flooding the log.
+    pub prometheus_path: Option<PathBuf>,
+    pub prometheus_failing: bool,
+
+    // First launch: when the "Press ? for help" hint disappears (None once
+    // dismissed by a key, or on later launches).
+    pub first_run_hint_until: Option<Instant>,
+
+    // Terminal window title (general.set_terminal_title): the last title
+    // written and when, so ticks only rewrite it every TITLE_INTERVAL.
+    pub set_title: bool,
+    last_title: String,
+    last_title_update: Instant,
+
+    // Cache inspector ('`' with --debug): None when closed.
+    pub debug_mode: bool,
+    pub cache_debug: Option<CacheDebugView>,
+    // Layout dump (F12 with --debug): Some while the next frame records
+    // each guarded widget's name and Rect for the log.
+    pub layout_dump: Option<Vec<(&'static str, Rect)>>,
+
+    // Quit: set when the event loop should exit; pending_quit tracks the
+    // first 'q' press when confirm_quit is enabled.
+    pub should_quit: bool,
+    pub pending_quit: Option<Instant>,
 
     // Top processes by CPU usage.
     pub processes: Vec<ProcessInfo>,
     pub process_sort: ProcessSort,
     pub sort_reverse: bool,
     pub process_scroll: usize,
-    pub total_process_count: usize, // unfiltered count for title display
+    pub process_visible_rows: usize, // table rows on screen, published by draw (0 = not drawn yet)
+    pub total_process_count: usize,  // unfiltered count for title display
+    pub show_kernel_threads: bool,   // 'K' toggle (Linux only)
+    pub kernel_threads_hidden: usize, // kernel threads excluded on the last refresh
+
+    // Processes that appeared after launch: PIDs present at startup, and when
+    // each later PID was first seen (pruned once the process exits).
+    pub baseline_pids: HashSet<u32>,
+    pub first_seen: HashMap<u32, Instant>,
+
+    // Container/unit label per PID, keyed by start time so a reused PID is re-read.
+    pub cgroup_cache: HashMap<u32, (u64, String)>,
+    // Open file descriptor counts (Linux), read only for rows on screen and
+    // re-read after FD_REFRESH; None = unreadable (another user's process).
+    pub fd_cache: HashMap<u32, (Instant, Option<usize>)>,
+    // Swapped-out bytes per process (Linux VmSwap), read for rows on screen
+    // (every row while sorting by swap) and re-read after SWAP_REFRESH.
+    pub swap_cache: HashMap<u32, (Instant, Option<u64>)>,
+    // Nice values (Linux), read for rows on screen and re-read after
+    // NICE_REFRESH or a renice.
+    pub nice_cache: HashMap<u32, (Instant, Option<i32>)>,
+
+    // Tailscale peer names: full MagicDNS name instead of short hostname ('F' key).
+    pub ts_show_fqdn: bool,
+    // Tailscale RX/TX as exact grouped byte counts instead of 1.2G ('B' key).
+    pub ts_raw_bytes: bool,
+    // Address family for local/Tailscale IPs (display.ip_version, '6' toggles).
+    pub ip_version: IpVersion,
+
+    // Network table: fold idle virtual interfaces into one summary row ('I' key).
+    pub hide_idle_interfaces: bool,
+    // Dashboard CPU: aggregate gauge + history instead of per-core bars ('U' key).
+    pub cpu_aggregate_view: bool,
+    // Dashboard waifu vs cluster widgets ('W', only when waifu is enabled).
+    pub dashboard_right_mode: DashboardRightMode,
+    // Network rates below this (bytes/s) render as "idle" (display.net_idle_threshold).
+    pub net_idle_threshold: u64,
+    // Disk gauge label template and size decimals (display.disk_label / disk_precision).
+    pub disk_label: String,
+    pub disk_precision: usize,
+
+    // Terminal focus (crossterm focus events): drawing pauses while false.
+    // Starts true, and stays true if display.pause_when_unfocused is turned
+    // off or the terminal never reports focus.
+    pub focused: bool,
 
     // Cached data from Go daemon.
     pub tailscale: Option<TailscaleStatus>,
@@ -152,16 +702,29 @@ pub struct App {
 
     // Waifu in-memory gallery (live-fetched, no disk cache).
     pub waifu_gallery: Vec<WaifuEntry>,
+    waifu_max_images: usize, // gallery cap (collectors.waifu.max_images)
     pub waifu_index: i32,
     pub waifu_show_info: bool,
     pub waifu_name: String,
-    pub waifu_fetching: bool, // true while an async fetch is in flight
+    pub waifu_fetching: bool,     // true while an async fetch is in flight
+    pub spinner_frame: usize,     // advances every tick; see spinner_char()
+    pub waifu_rect: Option<Rect>, // last drawn waifu area (for mouse hit-testing)
+    pub mouse_pos: Option<Position>, // last pointer position (CPU heatmap hover)
+    pub waifu_frame: usize,       // current animation frame of the shown image
+    pub waifu_frame_at: Instant,  // when the current frame was shown
+    // The shown image's animation frames, each scaled on first display and
+    // kept until the next load (new image or resize).
+    waifu_scaled_frames: Vec<Option<image::DynamicImage>>,
 
     // Claude personal plan usage (read from daemon state file).
     pub claude_personal: Option<ClaudePersonalReport>,
+    pub claude_personal_read_at: Instant, // when the report was computed (for live countdown)
+
+    // Expand mode: fullscreen single widget (--expand <id>, or Enter on a tab).
+    pub expanded: Option<WidgetId>,
 
-    // Expand mode: fullscreen single widget (e.g. --expand waifu).
-    pub expanded: bool,
+    // Last resize event time; the waifu re-scale waits until resizing settles.
+    pub pending_resize: Option<Instant>,
 
     // Image picker for protocol detection.
     pub picker: Picker,
@@ -171,7 +734,18 @@ pub struct App {
     users: sysinfo::Users,
 
     cache_reader: CacheReader,
+    cache_watcher: Option<CacheWatcher>, // None = fall back to polling
+    cache_watch_pending: bool,           // cache dir missing at startup: watch it once it appears
     last_cache_read: Instant,
+    /// When a daemon cache file was last seen rewritten and parsed
+    /// successfully (None = never); drives the heartbeat dot in the tab bar.
+    pub last_successful_read: Option<Instant>,
+    /// Each source's file mtime as of its last read, so re-reading an
+    /// unchanged file doesn't count as a heartbeat.
+    cache_mtimes: HashMap<String, SystemTime>,
+    /// Since when every daemon cache read has come back empty (None while
+    /// any source has data); drives the "daemon silent" placeholders.
+    pub daemon_down_since: Option<Instant>,
     last_sys_refresh: Instant,
 
     // Build/component version info (read once at startup).
@@ -188,8 +762,20 @@ impl App {
         picker: Picker,
         expand_widget: Option<String>,
     ) -> Result<Self> {
-        let cache_reader = CacheReader::new(cfg.cache_dir());
+        let cache_reader = CacheReader::new(cfg.cache_dir())
+            .with_max_age(cfg.cache_max_ages())
+            .with_personal_limits(cfg.personal_limits())
+            .with_extra_dirs(cfg.extra_cache_dirs())
+            .with_account_caps(cfg.claude_account_caps());
+        let cache_watcher = cache_reader.watcher();
+        let cache_watch_pending = cache_watcher.is_none() && !cache_reader.dir_exists();
         let sys = SysMetrics::collect();
+        let session_net_baseline = sys
+            .snapshot()
+            .networks
+            .iter()
+            .map(|n| (n.name.clone(), (n.rx_bytes, n.tx_bytes)))
+            .collect();
 
         // Initial cache read.
         let tailscale = cache_reader.read_tailscale();
@@ -197,6 +783,17 @@ impl App {
         let billing = cache_reader.read_billing();
         let k8s = cache_reader.read_k8s();
         let claude_personal = cache_reader.read_claude_personal();
+        let any_read = tailscale.is_some()
+            || claude.is_some()
+            || billing.is_some()
+            || k8s.is_some()
+            || claude_personal.is_some();
+        let last_successful_read = any_read.then(Instant::now);
+        let cache_mtimes = DAEMON_SOURCES
+            .into_iter()
+            .filter_map(|key| Some((key.to_string(), cache_reader.modified(key)?)))
+            .collect();
+        let daemon_down_since = (!any_read).then(Instant::now);
 
         // Waifu gallery starts empty — images are fetched live from the web service.
         let waifu_gallery: Vec<WaifuEntry> = Vec::new();
@@ -204,8 +801,15 @@ impl App {
         let waifu_index: i32 = -1;
         let waifu_name = String::new();
 
-        // Expand mode from CLI flag.
-        let expanded = expand_widget.as_deref() == Some("waifu");
+        // Expand mode from CLI flag; start on the widget's own tab so
+        // Tab/Shift-Tab cycle through its neighbours.
+        let expanded = expand_widget.as_deref().and_then(|id| {
+            let widget = WidgetId::parse(id);
+            if widget.is_none() {
+                tracing::warn!("unknown --expand widget {id:?}");
+            }
+            widget
+        });
 
         // Collect build/component version info (once at startup).
         let component_versions = data::buildinfo::collect_versions(&cfg);
@@ -216,53 +820,182 @@ impl App {
         // Initialize process system with CPU refresh for usage tracking.
         let mut proc_sys = sysinfo::System::new();
         proc_sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        let baseline_pids: HashSet<u32> = proc_sys
+            .processes()
+            .keys()
+            .map(|pid| pid.as_u32())
+            .collect();
         let users = sysinfo::Users::new_with_refreshed_list();
+        let current_user = sysinfo::get_current_pid()
+            .ok()
+            .and_then(|pid| proc_sys.process(pid))
+            .and_then(|p| p.user_id())
+            .and_then(|uid| users.iter().find(|u| u.id() == uid))
+            .map(|u| u.name().to_string())
+            .unwrap_or_default();
+
+        let process_columns = ProcessColumn::parse_list(&cfg.display.process_columns);
+        let process_sort = ProcessColumn::initial_sort(&process_columns);
+        let show_kernel_threads = cfg.display.show_kernel_threads;
+        let hide_idle_interfaces = cfg.display.hide_idle_interfaces;
+        let tab_order = cfg.tab_order();
+        let default_tab = cfg.default_tab().unwrap_or(tab_order[0]);
+        let net_idle_threshold = cfg.net_idle_threshold();
+        let disk_label = cfg.disk_label();
+        let disk_precision = cfg.disk_precision();
+        let cpu_aggregate_view = cfg.display.cpu_aggregate;
+        let sparkline_style = cfg.sparkline_style();
+        let sparkline_newest = cfg.sparkline_newest();
+        let mem_warn_mode = cfg.mem_warn_mode();
+        let clock_skew_secs = cfg.clock_skew_secs();
+        let cpu_view = cfg.cpu_view();
+        let colors_enabled = colors_from_env();
+        let selection_style = if colors_enabled {
+            cfg.selection_style()
+        } else {
+            SelectionStyle::Reverse
+        };
+        let ip_version = cfg.ip_version();
+        let refresh_bounds = cfg.refresh_bounds();
+        let waifu_max_images = cfg.waifu_max_images();
+        let pressure_weights = cfg.pressure_weights();
+        let pressure_score = sys.snapshot().pressure_score(&pressure_weights);
+        let set_title = cfg.set_terminal_title();
+        let first_run_hint_until =
+            take_first_run(&cfg.state_dir()).then(|| Instant::now() + FIRST_RUN_HINT);
+        let cpu_normalize = cfg.cpu_normalize();
+        let gradient = Gradient::new(cfg.gradient_stops().unwrap_or(PCT_STOPS));
+        let temp_gradient = Gradient::new(cfg.temp_gradient_stops().unwrap_or(TEMP_STOPS));
 
         let mut result = Ok(Self {
             cfg,
-            active_tab: Tab::Dashboard,
+            active_tab: expanded.map_or(default_tab, WidgetId::home_tab),
+            tab_order,
             term_width: 0,
             term_height: 0,
             show_help: false,
             help_tab: 0,
+            show_about: false,
+            chrome_hidden: false,
             frozen: false,
+            colors_enabled,
             process_filter: String::new(),
             filter_mode: false,
-            refresh_ms: 1000,
+            active_saved_filter: None,
+            refresh_ms: 1000u64.clamp(refresh_bounds.0, refresh_bounds.1),
+            refresh_bounds,
             show_cmd: false,
             tree_mode: false,
+            group_by_exe: false,
+            diff_mode: false,
+            process_changes: HashMap::new(),
+            prev_process_sample: None,
+            process_columns,
+            sparkline_style,
+            sparkline_newest,
+            mem_warn_mode,
+            clock_skew_secs,
+            cpu_view,
+            selection_style,
+            pressure_weights,
+            pressure_score,
+            cpu_normalize,
+            gradient,
+            temp_gradient,
             sys,
             cpu_history: VecDeque::with_capacity(HISTORY_LEN),
             cpu_per_core_history: Vec::new(),
+            cpu_freq_peak: Vec::new(),
             mem_history: VecDeque::with_capacity(HISTORY_LEN),
             swap_history: VecDeque::with_capacity(HISTORY_LEN),
             net_rx_history: VecDeque::with_capacity(HISTORY_LEN),
             net_tx_history: VecDeque::with_capacity(HISTORY_LEN),
+            session_net_baseline,
             load_history: VecDeque::with_capacity(HISTORY_LEN),
             temp_history: VecDeque::with_capacity(HISTORY_LEN),
             pending_kill: None,
+            current_user,
+            foreign_kill_warned: None,
+            signal_menu: None,
+            signal_target: None,
+            process_menu: None,
+            process_menu_rect: None,
+            process_rows_rect: None,
+            pending_clipboard: None,
+            status_msg: None,
+            kill_log: Vec::new(),
+            show_kill_log: false,
+            show_legend: false,
+            detail_pid: None,
+            detail_grouped: false,
+            show_process_detail: false,
+            detail_cpu_history: VecDeque::with_capacity(HISTORY_LEN),
+            prometheus_path: None,
+            prometheus_failing: false,
+            first_run_hint_until,
+            set_title,
+            last_title: String::new(),
+            last_title_update: Instant::now(),
+            debug_mode: false,
+            cache_debug: None,
+            layout_dump: None,
+            should_quit: false,
+            pending_quit: None,
             processes: Vec::new(),
-            process_sort: ProcessSort::Cpu,
+            process_sort,
             sort_reverse: false,
             process_scroll: 0,
+            process_visible_rows: 0,
             total_process_count: 0,
+            show_kernel_threads,
+            kernel_threads_hidden: 0,
+            baseline_pids,
+            first_seen: HashMap::new(),
+            cgroup_cache: HashMap::new(),
+            fd_cache: HashMap::new(),
+            swap_cache: HashMap::new(),
+            nice_cache: HashMap::new(),
+            ts_show_fqdn: false,
+            ts_raw_bytes: false,
+            ip_version,
+            hide_idle_interfaces,
+            net_idle_threshold,
+            disk_label,
+            disk_precision,
+            cpu_aggregate_view,
+            dashboard_right_mode: DashboardRightMode::Waifu,
+            focused: true,
             tailscale,
             claude,
             billing,
             k8s,
             waifu_state,
             waifu_gallery,
+            waifu_max_images,
             waifu_index,
             waifu_show_info: false,
             waifu_name,
             waifu_fetching: false,
+            spinner_frame: 0,
+            waifu_rect: None,
+            mouse_pos: None,
+            waifu_frame: 0,
+            waifu_frame_at: Instant::now(),
+            waifu_scaled_frames: Vec::new(),
             claude_personal,
+            claude_personal_read_at: Instant::now(),
             expanded,
+            pending_resize: None,
             picker,
             proc_sys,
             users,
             cache_reader,
+            cache_watcher,
+            cache_watch_pending,
             last_cache_read: Instant::now(),
+            last_successful_read,
+            cache_mtimes,
+            daemon_down_since,
             last_sys_refresh: Instant::now(),
             component_versions,
             waifu_fetch_rx,
@@ -282,8 +1015,12 @@ impl App {
     pub fn handle_key(&mut self, key: KeyEvent) {
         use crossterm::event::KeyCode;
 
+        // Any key dismisses the first-run help hint.
+        self.first_run_hint_until = None;
+
         // Process filter input mode: capture typed characters.
         if self.filter_mode {
+            self.active_saved_filter = None;
             match key.code {
                 KeyCode::Esc => {
                     self.filter_mode = false;
@@ -304,6 +1041,88 @@ impl App {
             return;
         }
 
+        // Signal menu captures all keys while open.
+        if let Some(sel) = self.signal_menu {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.signal_menu = Some((sel + 1) % SIGNAL_MENU.len());
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.signal_menu = Some((sel + SIGNAL_MENU.len() - 1) % SIGNAL_MENU.len());
+                }
+                KeyCode::Enter => {
+                    self.signal_menu = None;
+                    if let Some(target) = self.signal_target.take() {
+                        self.signal_process(&target, SIGNAL_MENU[sel].0);
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('q') => {
+                    self.signal_menu = None;
+                    self.signal_target = None;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Process right-click menu captures all keys while open.
+        if let Some(menu) = self.process_menu {
+            let sel = menu.selected;
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.select_process_menu((sel + 1) % PROCESS_MENU.len());
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.select_process_menu((sel + PROCESS_MENU.len() - 1) % PROCESS_MENU.len());
+                }
+                KeyCode::Enter => self.run_process_action(PROCESS_MENU[sel].0),
+                KeyCode::Esc | KeyCode::Char('q') => self.process_menu = None,
+                _ => {}
+            }
+            return;
+        }
+
+        // Cache inspector captures all keys while open.
+        if self.cache_debug.is_some() {
+            self.handle_cache_debug_key(key);
+            return;
+        }
+
+        // About popup: any key dismisses.
+        if self.show_about {
+            self.show_about = false;
+            return;
+        }
+        // Kill log popup: any key dismisses.
+        if self.show_kill_log {
+            self.show_kill_log = false;
+            return;
+        }
+        // Color legend popup: any key dismisses.
+        if self.show_legend {
+            self.show_legend = false;
+            return;
+        }
+        // Process detail popup: any key dismisses.
+        if self.show_process_detail {
+            self.show_process_detail = false;
+            return;
+        }
+
+        // Esc restores hidden chrome before it can mean quit.
+        if self.chrome_hidden && key.code == KeyCode::Esc {
+            self.chrome_hidden = false;
+            return;
+        }
+
+        // Quit: q anywhere, Esc only outside expand mode (where it exits expand).
+        if key.code == KeyCode::Char('q') || (key.code == KeyCode::Esc && self.expanded.is_none()) {
+            self.request_quit();
+            return;
+        }
+        // Any other key cancels a pending quit confirmation.
+        self.pending_quit = None;
+
         // Toggle help overlay.
         if key.code == KeyCode::Char('?') {
             self.show_help = !self.show_help;
@@ -326,120 +1145,107 @@ impl App {
             return;
         }
 
-        // Expand mode: Esc exits, waifu keys work, everything else ignored.
-        if self.expanded {
-            match key.code {
-                KeyCode::Esc => self.expanded = false,
-                KeyCode::Char('n') => self.waifu_navigate(1),
-                KeyCode::Char('p') => self.waifu_navigate(-1),
-                KeyCode::Char('r') => self.waifu_random(),
-                KeyCode::Char('i') => self.waifu_show_info = !self.waifu_show_info,
-                KeyCode::Char('f') => self.waifu_fetch_live(),
-                _ => {}
-            }
-            return;
-        }
-
-        // Dashboard tab: waifu keys when waifu area is visible.
-        if self.active_tab == Tab::Dashboard && self.wants_waifu() {
+        // Expand mode: Esc returns to the grid, Tab/Shift-Tab cycle the
+        // tab's widgets, and the zoomed widget keeps its own keys.
+        if let Some(widget) = self.expanded {
             match key.code {
-                KeyCode::Char('n') if self.has_waifu() => {
-                    self.waifu_navigate(1);
-                    return;
+                KeyCode::Esc => self.expanded = None,
+                KeyCode::Tab => self.cycle_expanded(1),
+                KeyCode::BackTab => self.cycle_expanded(-1),
+                // The zoomed table shows the filter prompt in its title.
+                KeyCode::Char('/') if widget == WidgetId::Processes => {
+                    self.filter_mode = true;
+                    self.process_filter.clear();
                 }
-                KeyCode::Char('p') if self.has_waifu() => {
-                    self.waifu_navigate(-1);
-                    return;
+                _ if widget == WidgetId::Processes => {
+                    self.handle_process_key(key);
                 }
-                KeyCode::Char('r') if self.has_waifu() => {
-                    self.waifu_random();
-                    return;
+                _ if matches!(widget, WidgetId::Tailscale | WidgetId::Network) => {
+                    self.handle_tailscale_key(key);
                 }
-                KeyCode::Char('i') if self.has_waifu() => {
+                KeyCode::Char('n') if widget == WidgetId::Waifu => self.waifu_navigate(1),
+                KeyCode::Char('p') if widget == WidgetId::Waifu => self.waifu_navigate(-1),
+                KeyCode::Char('r') if widget == WidgetId::Waifu => self.waifu_random(),
+                KeyCode::Char('i') if widget == WidgetId::Waifu => {
                     self.waifu_show_info = !self.waifu_show_info;
-                    return;
-                }
-                KeyCode::Char('f') => {
-                    self.waifu_fetch_live();
-                    return;
                 }
+                KeyCode::Char('f') if widget == WidgetId::Waifu => self.waifu_fetch_live(),
                 _ => {}
             }
+            return;
+        }
+
+        // Tab-owned keys: route to the focused widget first.
+        if self.handle_tab_key(key) {
+            return;
         }
 
         match key.code {
             // Freeze toggle (pause data collection).
             KeyCode::Char(' ') => self.frozen = !self.frozen,
+            // About/version popup.
+            KeyCode::Char('v') => self.show_about = true,
+            // Session kill log popup.
+            KeyCode::Char('L') => self.show_kill_log = true,
+            // Re-read the daemon cache only (no process re-enumeration).
+            KeyCode::Char('R') => self.force_cache_reread(),
+            // Color legend popup.
+            KeyCode::Char('l') => self.show_legend = true,
+            // Cache inspector (only with --debug).
+            KeyCode::Char('`') if self.debug_mode => self.open_cache_debug(),
+            // Log every widget's Rect on the next frame (only with --debug).
+            KeyCode::F(12) if self.debug_mode => self.layout_dump = Some(Vec::new()),
+            // Hide/show the tab bar and help bar (number keys still switch tabs).
+            KeyCode::Char('H') => self.chrome_hidden = !self.chrome_hidden,
+            // Dashboard CPU: per-core bars vs aggregate gauge + history.
+            KeyCode::Char('U') if self.active_tab == Tab::Dashboard => {
+                self.cpu_aggregate_view = !self.cpu_aggregate_view;
+            }
+            // Dashboard: swap the waifu for the cluster widgets and back.
+            KeyCode::Char('W') if self.active_tab == Tab::Dashboard && self.wants_waifu() => {
+                self.dashboard_right_mode = match self.dashboard_right_mode {
+                    DashboardRightMode::Waifu => DashboardRightMode::Cluster,
+                    DashboardRightMode::Cluster => DashboardRightMode::Waifu,
+                };
+            }
             // Process filter (btm-style '/' search).
             KeyCode::Char('/') => {
                 self.filter_mode = true;
                 self.process_filter.clear();
             }
+            // Zoom the focused widget to full screen (the tab's first widget
+            // when the focus fallback isn't on this tab).
+            KeyCode::Enter => {
+                let widgets = WidgetId::for_tab(self.active_tab, self.dashboard_shows_waifu());
+                let focused = self.focus_target().widget();
+                self.expanded = if widgets.contains(&focused) {
+                    Some(focused)
+                } else {
+                    widgets.first().copied()
+                };
+            }
+            // Saved process filters ([filters] saved), in name order.
+            KeyCode::F(n @ 1..=9) => self.apply_saved_filter(n as usize - 1),
             KeyCode::Tab | KeyCode::Right => self.next_tab(),
             KeyCode::BackTab | KeyCode::Left => self.prev_tab(),
-            KeyCode::Char('1') => self.active_tab = Tab::Dashboard,
-            KeyCode::Char('2') => self.active_tab = Tab::System,
-            KeyCode::Char('3') => self.active_tab = Tab::Network,
-            KeyCode::Char('4') => self.active_tab = Tab::Billing,
-            KeyCode::Char('5') => self.active_tab = Tab::Build,
-            // Process table navigation (System tab).
-            KeyCode::Char('j') | KeyCode::Down => {
-                if !self.processes.is_empty() {
-                    self.process_scroll =
-                        (self.process_scroll + 1).min(self.processes.len().saturating_sub(1));
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                self.process_scroll = self.process_scroll.saturating_sub(1);
-            }
-            KeyCode::Char('g') | KeyCode::Home => {
-                self.process_scroll = 0;
-            }
-            KeyCode::Char('G') | KeyCode::End => {
-                self.process_scroll = self.processes.len().saturating_sub(1);
-            }
-            // Sort toggle: c=CPU, m=Memory, p=PID, n=Name.
-            // n/p/r are context-sensitive: on Dashboard with waifu they're handled above.
-            KeyCode::Char('c') => self.process_sort = ProcessSort::Cpu,
-            KeyCode::Char('m') => self.process_sort = ProcessSort::Memory,
-            KeyCode::Char('p') => self.process_sort = ProcessSort::Pid,
-            KeyCode::Char('n') => self.process_sort = ProcessSort::Name,
-            KeyCode::Char('r') => self.sort_reverse = !self.sort_reverse,
-            // Page up/down for process table.
-            KeyCode::PageDown => {
-                if !self.processes.is_empty() {
-                    self.process_scroll =
-                        (self.process_scroll + 10).min(self.processes.len().saturating_sub(1));
-                }
-            }
-            KeyCode::PageUp => {
-                self.process_scroll = self.process_scroll.saturating_sub(10);
-            }
-            // Toggle full command display for processes.
-            KeyCode::Char('e') => self.show_cmd = !self.show_cmd,
-            // Toggle tree view for processes.
-            KeyCode::Char('t') => self.tree_mode = !self.tree_mode,
-            // Process kill: 'dd' sends SIGTERM (btm-style double-key).
-            KeyCode::Char('d') => {
-                if let Some(first_press) = self.pending_kill {
-                    if first_press.elapsed().as_millis() < 500 {
-                        self.kill_selected_process(false);
-                    }
-                    self.pending_kill = None;
-                } else {
-                    self.pending_kill = Some(Instant::now());
+            // Number keys follow the configured tab order.
+            KeyCode::Char(c @ '1'..='9') => {
+                let idx = c as usize - '1' as usize;
+                if let Some(&tab) = self.tab_order.get(idx) {
+                    self.active_tab = tab;
                 }
             }
-            // 'D' (shift-d) sends SIGKILL immediately.
-            KeyCode::Char('D') => {
-                self.kill_selected_process(true);
-            }
             // Adjustable refresh rate.
             KeyCode::Char('+') | KeyCode::Char('=') => {
-                self.refresh_ms = (self.refresh_ms.saturating_sub(250)).max(250);
+                let step = refresh_step(self.refresh_ms.saturating_sub(1));
+                self.refresh_ms = self
+                    .refresh_ms
+                    .saturating_sub(step)
+                    .max(self.refresh_bounds.0);
             }
             KeyCode::Char('-') => {
-                self.refresh_ms = (self.refresh_ms + 250).min(5000);
+                let step = refresh_step(self.refresh_ms);
+                self.refresh_ms = (self.refresh_ms + step).min(self.refresh_bounds.1);
             }
             _ => {
                 // Any other key cancels pending kill.
@@ -448,60 +1254,485 @@ impl App {
         }
     }
 
-    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
-        use crossterm::event::MouseEventKind;
-        match mouse.kind {
-            MouseEventKind::ScrollDown => {
-                if self.active_tab == Tab::System && !self.processes.is_empty() {
-                    self.process_scroll =
-                        (self.process_scroll + 3).min(self.processes.len().saturating_sub(1));
-                }
-            }
-            MouseEventKind::ScrollUp => {
-                if self.active_tab == Tab::System {
-                    self.process_scroll = self.process_scroll.saturating_sub(3);
+    /// Export the snapshot for node_exporter's textfile collector, when enabled.
+    fn write_prometheus(&mut self, snap: &data::sysmetrics::SysSnapshot) {
+        let Some(path) = &self.prometheus_path else {
+            return;
+        };
+        match data::prometheus::write_textfile(path, snap) {
+            Ok(()) => self.prometheus_failing = false,
+            Err(e) => {
+                if !self.prometheus_failing {
+                    tracing::warn!("prometheus textfile {}: {e}", path.display());
                 }
+                self.prometheus_failing = true;
             }
-            // Click in the top 3 rows = tab bar region.
-            MouseEventKind::Down(crossterm::event::MouseButton::Left) => {
-                if mouse.row < 3 {
-                    // Rough tab hit detection: divide width evenly.
-                    let tab_count = Tab::ALL.len() as u16;
-                    let tab_width = self.term_width / tab_count.max(1);
-                    let idx = (mouse.column / tab_width.max(1)) as usize;
-                    if idx < Tab::ALL.len() {
-                        self.active_tab = Tab::ALL[idx];
-                    }
-                }
+        }
+    }
+
+    /// Re-read open-FD counts for `pids` whose cached value is missing or
+    /// older than FD_REFRESH. Called by the process table for visible rows only.
+    pub fn refresh_fd_counts(&mut self, pids: &[u32]) {
+        let now = Instant::now();
+        for &pid in pids {
+            let fresh = self
+                .fd_cache
+                .get(&pid)
+                .is_some_and(|(at, _)| now.duration_since(*at) < FD_REFRESH);
+            if !fresh {
+                self.fd_cache.insert(pid, (now, read_fd_count(pid)));
             }
-            _ => {}
         }
     }
 
-    pub fn on_resize(&mut self, w: u16, h: u16) {
-        let old_w = self.term_width;
-        let old_h = self.term_height;
-        self.term_width = w;
-        self.term_height = h;
+    /// Cached open-FD count for a process (None = not read or unreadable).
+    pub fn fd_count(&self, pid: u32) -> Option<usize> {
+        self.fd_cache.get(&pid).and_then(|(_, count)| *count)
+    }
 
-        // Re-create waifu protocol when terminal size changes substantially,
-        // so the image is pre-scaled to fill the new widget area.
-        if self.waifu_index >= 0 && (old_w != w || old_h != h) {
-            self.waifu_load_at(self.waifu_index as usize);
+    /// Re-read swapped-out sizes for `pids` whose cached value is missing or
+    /// older than SWAP_REFRESH.
+    pub fn refresh_swap(&mut self, pids: &[u32]) {
+        let now = Instant::now();
+        for &pid in pids {
+            let fresh = self
+                .swap_cache
+                .get(&pid)
+                .is_some_and(|(at, _)| now.duration_since(*at) < SWAP_REFRESH);
+            if !fresh {
+                self.swap_cache.insert(pid, (now, read_vm_swap(pid)));
+            }
         }
     }
 
-    /// Called every tick (~250ms). Refresh real-time system data and
-    /// periodically re-read daemon cache files.
+    /// Cached swapped-out bytes for a process (None = not read or unreadable).
+    pub fn swap_bytes(&self, pid: u32) -> Option<u64> {
+        self.swap_cache.get(&pid).and_then(|(_, bytes)| *bytes)
+    }
+
+    /// Re-read nice values for `pids` whose cached value is missing or older
+    /// than NICE_REFRESH.
+    pub fn refresh_nice(&mut self, pids: &[u32]) {
+        let now = Instant::now();
+        for &pid in pids {
+            let fresh = self
+                .nice_cache
+                .get(&pid)
+                .is_some_and(|(at, _)| now.duration_since(*at) < NICE_REFRESH);
+            if !fresh {
+                self.nice_cache.insert(pid, (now, read_nice(pid)));
+            }
+        }
+    }
+
+    /// Cached nice value for a process (None = not read or unreadable).
+    pub fn nice(&self, pid: u32) -> Option<i32> {
+        self.nice_cache.get(&pid).and_then(|(_, nice)| *nice)
+    }
+
+    /// Whether the first-run "Press ? for help" hint is still showing.
+    pub fn first_run_hint(&self) -> bool {
+        self.first_run_hint_until
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Window title text: "prompt-pulse — CPU 23% MEM 61%".
+    pub fn title_text(&self) -> String {
+        let mem = self.mem_history.back().copied().unwrap_or(0.0);
+        format!(
+            "prompt-pulse \u{2014} CPU {:.0}% MEM {mem:.0}%",
+            self.cpu_display_total()
+        )
+    }
+
+    /// Rewrite the terminal title (OSC 0) when enabled, at most every
+    /// TITLE_INTERVAL and only when the text changed.
+    pub fn update_terminal_title(&mut self) {
+        if !self.set_title || self.last_title_update.elapsed() < TITLE_INTERVAL {
+            return;
+        }
+        self.last_title_update = Instant::now();
+        let title = self.title_text();
+        if title == self.last_title {
+            return;
+        }
+        if let Err(e) =
+            crossterm::execute!(std::io::stdout(), crossterm::terminal::SetTitle(&title))
+        {
+            tracing::debug!("set terminal title: {e}");
+        }
+        self.last_title = title;
+    }
+
+    /// Rows per PgUp/PgDn: what the table last showed, or 10 before the first draw.
+    fn process_page(&self) -> usize {
+        match self.process_visible_rows {
+            0 => 10,
+            rows => rows,
+        }
+    }
+
+    /// Zoom the next (`step` 1) or previous (-1) widget of the active tab.
+    fn cycle_expanded(&mut self, step: isize) {
+        let list = WidgetId::for_tab(self.active_tab, self.dashboard_shows_waifu());
+        if list.is_empty() {
+            return;
+        }
+        let next = match self
+            .expanded
+            .and_then(|w| list.iter().position(|&l| l == w))
+        {
+            Some(i) => (i as isize + step).rem_euclid(list.len() as isize) as usize,
+            None => 0,
+        };
+        self.expanded = Some(list[next]);
+    }
+
+    /// Monochrome mode (`--no-color`). A highlighted selection would lose
+    /// its background, so selections switch to reverse video.
+    pub fn disable_colors(&mut self) {
+        self.colors_enabled = false;
+        self.selection_style = SelectionStyle::Reverse;
+    }
+
+    /// Quit immediately, or arm/confirm the double-press when `confirm_quit` is set.
+    fn request_quit(&mut self) {
+        if !self.cfg.general.confirm_quit {
+            self.should_quit = true;
+            return;
+        }
+        match self.pending_quit {
+            Some(first_press) if first_press.elapsed() < QUIT_CONFIRM_WINDOW => {
+                self.should_quit = true;
+            }
+            _ => self.pending_quit = Some(Instant::now()),
+        }
+    }
+
+    /// Whether a quit confirmation is waiting for the second press.
+    pub fn quit_pending(&self) -> bool {
+        self.pending_quit
+            .is_some_and(|t| t.elapsed() < QUIT_CONFIRM_WINDOW)
+    }
+
+    /// Which widget receives tab-specific keys on the active tab.
+    pub fn focus_target(&self) -> FocusTarget {
+        match self.active_tab {
+            Tab::Dashboard if self.dashboard_shows_waifu() => FocusTarget::Waifu,
+            Tab::Network => FocusTarget::Tailscale,
+            _ => FocusTarget::Processes,
+        }
+    }
+
+    /// Dispatch a key to the active tab's focused widget.
+    /// Returns true if the key was consumed.
+    fn handle_tab_key(&mut self, key: KeyEvent) -> bool {
+        match self.focus_target() {
+            // Waifu owns n/p/r/i/f; the process table keeps its other keys.
+            FocusTarget::Waifu => self.handle_waifu_key(key) || self.handle_process_key(key),
+            FocusTarget::Tailscale => {
+                self.handle_tailscale_key(key) || self.handle_process_key(key)
+            }
+            FocusTarget::Processes => self.handle_process_key(key),
+        }
+    }
+
+    /// Waifu gallery keys (Dashboard tab with waifu area visible).
+    fn handle_waifu_key(&mut self, key: KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Char('n') if self.has_waifu() => self.waifu_navigate(1),
+            KeyCode::Char('p') if self.has_waifu() => self.waifu_navigate(-1),
+            KeyCode::Char('r') if self.has_waifu() => self.waifu_random(),
+            KeyCode::Char('i') if self.has_waifu() => {
+                self.waifu_show_info = !self.waifu_show_info;
+            }
+            KeyCode::Char('f') => self.waifu_fetch_live(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Network tab keys: Tailscale peer table and interface list.
+    fn handle_tailscale_key(&mut self, key: KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            // Toggle MagicDNS FQDN vs short hostname.
+            KeyCode::Char('F') => self.ts_show_fqdn = !self.ts_show_fqdn,
+            // Exact byte counts in the RX/TX columns.
+            KeyCode::Char('B') => self.ts_raw_bytes = !self.ts_raw_bytes,
+            // Show IPv6 instead of IPv4 addresses (or back).
+            KeyCode::Char('6') => self.ip_version = self.ip_version.toggle(),
+            // Collapse idle virtual interfaces.
+            KeyCode::Char('I') => self.hide_idle_interfaces = !self.hide_idle_interfaces,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Process table keys: scroll, sort, tree/cmd toggles, kill.
+    fn handle_process_key(&mut self, key: KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        // Type-to-jump: letters select processes by name instead of running commands.
+        if self.cfg.keys.type_to_jump && self.active_tab == Tab::System {
+            if let KeyCode::Char(c) = key.code {
+                if c.is_alphabetic() {
+                    self.jump_to_process(c);
+                    return true;
+                }
+            }
+        }
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                if !self.processes.is_empty() {
+                    self.process_scroll =
+                        (self.process_scroll + 1).min(self.processes.len().saturating_sub(1));
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.process_scroll = self.process_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('g') | KeyCode::Home => {
+                self.process_scroll = 0;
+            }
+            KeyCode::Char('G') | KeyCode::End => {
+                self.process_scroll = self.processes.len().saturating_sub(1);
+            }
+            // Sort toggle: c=CPU, m=Memory, p=PID, n=Name.
+            // n/p/r are context-sensitive: with waifu focused they're handled first.
+            KeyCode::Char('c') => self.process_sort = ProcessSort::Cpu,
+            KeyCode::Char('m') => self.process_sort = ProcessSort::Memory,
+            KeyCode::Char('p') => self.process_sort = ProcessSort::Pid,
+            KeyCode::Char('n') => self.process_sort = ProcessSort::Name,
+            // VmSwap is Linux-only; elsewhere every process would tie at zero.
+            KeyCode::Char('w') if cfg!(target_os = "linux") => {
+                self.process_sort = ProcessSort::Swap;
+            }
+            KeyCode::Char('r') => self.sort_reverse = !self.sort_reverse,
+            // Renice (keys.allow_renice): '<' raises priority, '>' lowers it.
+            KeyCode::Char('<') => self.renice_selected_process(-1),
+            KeyCode::Char('>') => self.renice_selected_process(1),
+            // Jump to the heaviest process (any sort order): C=CPU, M=memory.
+            KeyCode::Char('C') => self.jump_to_max(|p| p.cpu_usage as f64),
+            KeyCode::Char('M') => self.jump_to_max(|p| p.memory_bytes as f64),
+            // Page up/down for process table, by the visible page size.
+            KeyCode::PageDown => {
+                if !self.processes.is_empty() {
+                    self.process_scroll = (self.process_scroll + self.process_page())
+                        .min(self.processes.len().saturating_sub(1));
+                }
+            }
+            KeyCode::PageUp => {
+                self.process_scroll = self.process_scroll.saturating_sub(self.process_page());
+            }
+            // Detail popup with a CPU graph for the selected process.
+            KeyCode::Char('o') => self.open_process_detail(),
+            // Toggle full command display for processes.
+            KeyCode::Char('e') => self.show_cmd = !self.show_cmd,
+            // Toggle tree view for processes.
+            KeyCode::Char('t') => self.tree_mode = !self.tree_mode,
+            // Group processes by executable.
+            KeyCode::Char('X') => self.group_by_exe = !self.group_by_exe,
+            // Change feed: only new, exited or changed processes.
+            KeyCode::Char('N') => self.toggle_diff_mode(),
+            // Toggle kernel thread visibility (Linux).
+            KeyCode::Char('K') => self.show_kernel_threads = !self.show_kernel_threads,
+            // Process kill: 'dd' sends SIGTERM (btm-style double-key).
+            KeyCode::Char('d') => {
+                if let Some(first_press) = self.pending_kill {
+                    if first_press.elapsed().as_millis() < 500 {
+                        self.kill_selected_process(false);
+                    }
+                    self.pending_kill = None;
+                } else {
+                    self.pending_kill = Some(Instant::now());
+                }
+            }
+            // 'D' (shift-d) sends SIGKILL immediately.
+            KeyCode::Char('D') => {
+                self.kill_selected_process(true);
+            }
+            // 's' opens the signal picker for the selected process.
+            KeyCode::Char('s') => match self.selected_signal_target() {
+                Some(target) => {
+                    self.signal_target = Some(target);
+                    self.signal_menu = Some(0);
+                }
+                None => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    /// Move the selection to the next process (in current sort order) whose
+    /// name starts with `c`, wrapping around so repeated presses cycle.
+    fn jump_to_process(&mut self, c: char) {
+        let n = self.processes.len();
+        if n == 0 {
+            return;
+        }
+        let c = c.to_lowercase().to_string();
+        let hit = (1..=n)
+            .map(|offset| (self.process_scroll + offset) % n)
+            .find(|&i| self.processes[i].name.to_lowercase().starts_with(&c));
+        if let Some(i) = hit {
+            self.process_scroll = i;
+        }
+    }
+
+    /// Select the visible (filtered) process with the largest `metric`;
+    /// ties go to the row nearest the top.
+    fn jump_to_max(&mut self, metric: impl Fn(&ProcessInfo) -> f64) {
+        let best = self
+            .processes
+            .iter()
+            .enumerate()
+            .max_by(|(ia, a), (ib, b)| {
+                metric(a)
+                    .partial_cmp(&metric(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(ib.cmp(ia))
+            })
+            .map(|(i, _)| i);
+        if let Some(i) = best {
+            self.process_scroll = i;
+        }
+    }
+
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        use crossterm::event::{MouseButton, MouseEventKind};
+
+        let pos = Position::new(mouse.column, mouse.row);
+        self.mouse_pos = Some(pos);
+
+        // Right-click menu: hover highlights, a click picks an entry, and a
+        // click anywhere else closes it.
+        if self.process_menu.is_some() {
+            let entry = self.process_menu_entry_at(pos);
+            match mouse.kind {
+                MouseEventKind::Moved => {
+                    if let Some(i) = entry {
+                        self.select_process_menu(i);
+                    }
+                }
+                MouseEventKind::Down(_) => match entry {
+                    Some(i) => self.run_process_action(PROCESS_MENU[i].0),
+                    None => self.process_menu = None,
+                },
+                _ => {}
+            }
+            return;
+        }
+
+        // Wheel over the waifu flips images (Dashboard or expand mode only).
+        let over_waifu = (self.expanded.is_some() || self.active_tab == Tab::Dashboard)
+            && self.waifu_rect.is_some_and(|r| r.contains(pos));
+        if over_waifu {
+            match mouse.kind {
+                MouseEventKind::ScrollDown => {
+                    self.waifu_navigate(1);
+                    return;
+                }
+                MouseEventKind::ScrollUp => {
+                    self.waifu_navigate(-1);
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                if self.active_tab == Tab::System && !self.processes.is_empty() {
+                    self.process_scroll =
+                        (self.process_scroll + 3).min(self.processes.len().saturating_sub(1));
+                }
+            }
+            MouseEventKind::ScrollUp => {
+                if self.active_tab == Tab::System {
+                    self.process_scroll = self.process_scroll.saturating_sub(3);
+                }
+            }
+            // Click a process row to select it; right-click for its menu.
+            MouseEventKind::Down(button @ (MouseButton::Left | MouseButton::Right))
+                if self.process_row_at(pos).is_some() =>
+            {
+                self.process_scroll = self.process_row_at(pos).unwrap_or_default();
+                if button == MouseButton::Right {
+                    self.process_menu = Some(ProcessMenu {
+                        selected: 0,
+                        anchor: pos,
+                    });
+                }
+            }
+            // Click in the top 3 rows = tab bar region (when it is shown),
+            // inside the display.margin inset.
+            MouseEventKind::Down(MouseButton::Left)
+                if !self.chrome_hidden
+                    && self.expanded.is_none()
+                    && self.tab_bar_rect().contains(pos) =>
+            {
+                // Rough tab hit detection: divide width evenly.
+                let bar = self.tab_bar_rect();
+                let tab_count = self.tab_order.len() as u16;
+                let tab_width = bar.width / tab_count.max(1);
+                let idx = ((mouse.column - bar.x) / tab_width.max(1)) as usize;
+                if let Some(&tab) = self.tab_order.get(idx) {
+                    self.active_tab = tab;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn on_resize(&mut self, w: u16, h: u16) {
+        let old_w = self.term_width;
+        let old_h = self.term_height;
+        self.term_width = w;
+        self.term_height = h;
+
+        // Re-create waifu protocol when terminal size changes, so the image is
+        // pre-scaled to fill the new widget area. Deferred to tick() so a
+        // drag-resize doesn't re-scale on every intermediate event.
+        if self.waifu_index >= 0 && (old_w != w || old_h != h) {
+            self.pending_resize = Some(Instant::now());
+        }
+    }
+
+    /// Re-scale the waifu image once resize events have settled.
+    fn apply_pending_resize(&mut self) {
+        let settled = self
+            .pending_resize
+            .is_some_and(|t| t.elapsed() >= RESIZE_DEBOUNCE);
+        if settled {
+            self.pending_resize = None;
+            if self.waifu_index >= 0 {
+                self.waifu_load_at(self.waifu_index as usize);
+            }
+        }
+    }
+
+    /// Called every tick (~250ms). Refresh real-time system data and
+    /// periodically re-read daemon cache files.
     pub async fn tick(&mut self) {
+        self.spinner_frame = self.spinner_frame.wrapping_add(1);
+
         // Always poll for async fetch results, even when frozen.
         self.poll_waifu_fetch();
+        self.apply_pending_resize();
 
         // Skip all data collection when frozen.
         if self.frozen {
             return;
         }
 
+        self.advance_waifu_frame();
+
         let now = Instant::now();
 
         // Refresh system metrics at adjustable rate.
@@ -510,6 +1741,8 @@ impl App {
 
             // Record history for sparklines.
             let snap = self.sys.snapshot();
+            self.write_prometheus(&snap);
+            self.pressure_score = snap.pressure_score(&self.pressure_weights);
             if self.cpu_history.len() >= HISTORY_LEN {
                 self.cpu_history.pop_front();
             }
@@ -526,11 +1759,16 @@ impl App {
                 }
                 self.cpu_per_core_history[i].push_back(usage as f64);
             }
+            self.cpu_freq_peak.resize(snap.cpu_freqs.len(), 0);
+            for (peak, &freq) in self.cpu_freq_peak.iter_mut().zip(&snap.cpu_freqs) {
+                *peak = (*peak).max(freq);
+            }
 
             if self.mem_history.len() >= HISTORY_LEN {
                 self.mem_history.pop_front();
             }
             self.mem_history.push_back(snap.mem_percent);
+            self.update_terminal_title();
 
             // Swap history.
             let swap_pct = if snap.swap_total > 0 {
@@ -571,22 +1809,81 @@ impl App {
                 self.net_tx_history.pop_front();
             }
             self.net_tx_history.push_back(total_tx as f64);
+            self.update_session_net_baseline(&snap.networks);
 
             // Refresh process list and collect top 50 (scrollable).
             self.proc_sys
                 .refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+            // Note PIDs that appeared since launch; forget ones that exited.
+            let live: HashSet<u32> = self
+                .proc_sys
+                .processes()
+                .keys()
+                .map(|pid| pid.as_u32())
+                .collect();
+            self.track_new_pids(&live, now);
+            self.cgroup_cache.retain(|pid, _| live.contains(pid));
+            self.fd_cache.retain(|pid, _| live.contains(pid));
+            self.swap_cache.retain(|pid, _| live.contains(pid));
+            self.nice_cache.retain(|pid, _| live.contains(pid));
+
+            // Kernel threads (Linux only) are hidden unless toggled on.
+            let hide_kthreads = cfg!(target_os = "linux") && !self.show_kernel_threads;
+            let ppids: HashMap<u32, u32> = if hide_kthreads {
+                self.proc_sys
+                    .processes()
+                    .values()
+                    .map(|p| {
+                        (
+                            p.pid().as_u32(),
+                            p.parent().map(|p| p.as_u32()).unwrap_or(0),
+                        )
+                    })
+                    .collect()
+            } else {
+                HashMap::new()
+            };
+            let is_kthread = |p: &sysinfo::Process| {
+                hide_kthreads
+                    && is_kernel_thread(p.pid().as_u32(), &p.name().to_string_lossy(), &ppids)
+            };
+            self.kernel_threads_hidden = self
+                .proc_sys
+                .processes()
+                .values()
+                .filter(|p| p.cpu_usage() > 0.0 && is_kthread(p))
+                .count();
             self.total_process_count = self
                 .proc_sys
                 .processes()
                 .values()
-                .filter(|p| p.cpu_usage() > 0.0)
+                .filter(|p| p.cpu_usage() > 0.0 && !is_kthread(p))
                 .count();
+            // Normalize here so the table, sort, and totals all agree.
+            let cpu_scale = match self.cpu_normalize {
+                CpuNormalize::Core => 1.0,
+                CpuNormalize::Total => 1.0 / snap.cpu_count.max(1) as f32,
+            };
+            // Detail popup graph: read the PID directly so it keeps
+            // updating even when the row drops out of the table (0% CPU).
+            if self.show_process_detail && !self.detail_grouped {
+                let live = self
+                    .detail_pid
+                    .and_then(|pid| self.proc_sys.process(sysinfo::Pid::from_u32(pid)));
+                if let Some(p) = live {
+                    if self.detail_cpu_history.len() >= HISTORY_LEN {
+                        self.detail_cpu_history.pop_front();
+                    }
+                    self.detail_cpu_history
+                        .push_back((p.cpu_usage() * cpu_scale) as f64);
+                }
+            }
             let filter_lower = self.process_filter.to_lowercase();
             let mut procs: Vec<ProcessInfo> = self
                 .proc_sys
                 .processes()
                 .values()
-                .filter(|p| p.cpu_usage() > 0.0)
+                .filter(|p| p.cpu_usage() > 0.0 && !is_kthread(p))
                 .filter(|p| {
                     if filter_lower.is_empty() {
                         true
@@ -628,40 +1925,73 @@ impl App {
                                 .map(|u| u.name().to_string())
                         })
                         .unwrap_or_default();
+                    let pid = p.pid().as_u32();
+                    let exe = p
+                        .exe()
+                        .map(|e| e.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let cgroup = match self.cgroup_cache.get(&pid) {
+                        Some((started, label)) if *started == p.start_time() => label.clone(),
+                        _ => {
+                            let label = read_cgroup_label(pid);
+                            self.cgroup_cache
+                                .insert(pid, (p.start_time(), label.clone()));
+                            label
+                        }
+                    };
                     ProcessInfo {
                         pid: p.pid().as_u32(),
                         ppid: p.parent().map(|p| p.as_u32()).unwrap_or(0),
                         name: p.name().to_string_lossy().to_string(),
                         cmd,
                         user,
-                        cpu_usage: p.cpu_usage(),
+                        cpu_usage: p.cpu_usage() * cpu_scale,
                         memory_bytes: p.memory(),
                         state,
                         run_time_secs: p.run_time(),
                         tree_depth: 0,
+                        cgroup,
+                        exe,
+                        group_count: 1,
                     }
                 })
                 .collect();
-            match self.process_sort {
-                ProcessSort::Cpu => procs.sort_by(|a, b| {
-                    b.cpu_usage
-                        .partial_cmp(&a.cpu_usage)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                }),
-                ProcessSort::Memory => procs.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes)),
-                ProcessSort::Pid => procs.sort_by(|a, b| a.pid.cmp(&b.pid)),
-                ProcessSort::Name => {
-                    procs.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+            // Group before sorting so the sort ranks the aggregates.
+            if self.group_by_exe {
+                procs = Self::group_by_exe(procs);
+            }
+            if self.show_process_detail && self.detail_grouped {
+                // A group missing from the table has no busy members.
+                let cpu = procs
+                    .iter()
+                    .find(|p| Some(p.pid) == self.detail_pid)
+                    .map_or(0.0, |p| p.cpu_usage as f64);
+                if self.detail_cpu_history.len() >= HISTORY_LEN {
+                    self.detail_cpu_history.pop_front();
                 }
+                self.detail_cpu_history.push_back(cpu);
             }
+            // Sorting by swap needs every row's VmSwap, not just the visible ones.
+            if self.process_sort == ProcessSort::Swap {
+                let pids: Vec<u32> = procs.iter().map(|p| p.pid).collect();
+                self.refresh_swap(&pids);
+            }
+            Self::sort_processes(&mut procs, self.process_sort, &self.swap_cache);
             if self.sort_reverse {
                 procs.reverse();
             }
-            // Tree view: reorder by parent-child depth-first.
-            if self.tree_mode {
+            // Tree view: reorder by parent-child depth-first (grouped rows
+            // have no single parent, so grouping wins; the change feed drops
+            // parents, so it does too).
+            if self.tree_mode && !self.group_by_exe && !self.diff_mode {
                 procs = Self::build_tree(procs);
             }
-            procs.truncate(100);
+            if self.diff_mode {
+                procs = self.diff_processes(procs);
+            }
+            if let Some(max) = self.cfg.max_processes() {
+                procs.truncate(max);
+            }
             self.processes = procs;
             // Clamp scroll to valid range.
             if self.process_scroll >= self.processes.len() {
@@ -671,56 +2001,246 @@ impl App {
             self.last_sys_refresh = now;
         }
 
-        // Re-read daemon cache every 5 seconds.
-        if now.duration_since(self.last_cache_read).as_secs() >= 5 {
-            self.tailscale = self.cache_reader.read_tailscale();
-            self.claude = self.cache_reader.read_claude();
-            self.billing = self.cache_reader.read_billing();
-            self.k8s = self.cache_reader.read_k8s();
+        // Re-read daemon cache: changed files as the watcher reports them,
+        // everything on the poll (or sweep) interval.
+        let changed = self
+            .cache_watcher
+            .as_ref()
+            .map(|w| w.poll())
+            .unwrap_or_default();
+        for key in &changed {
+            self.reload_cache_source(key);
+        }
+        if now.duration_since(self.last_cache_read) >= self.cache_read_interval() {
+            if self.cache_watch_pending && self.cache_reader.dir_exists() {
+                self.cache_watch_pending = false;
+                self.cache_watcher = self.cache_reader.watcher();
+            }
+            self.reread_daemon_cache(now);
+        } else if self
+            .claude_personal
+            .as_ref()
+            .is_some_and(|r| r.next_slot_secs > 0)
+            && self.claude_personal_countdown() == 0
+        {
+            // Countdown reached zero: refresh from authoritative data early.
             self.claude_personal = self.cache_reader.read_claude_personal();
-            self.last_cache_read = now;
+            self.claude_personal_read_at = now;
         }
     }
 
-    /// Check if a waifu image is loaded (for layout decisions).
-    pub fn has_waifu(&self) -> bool {
-        self.waifu_state.is_some()
+    /// Re-read a single daemon cache file by name (e.g. "billing"); true
+    /// when it had data. Only a file the daemon rewrote since the last read
+    /// moves the heartbeat: a fresh-but-unchanged one stays readable for
+    /// its whole max age after the daemon dies.
+    fn reload_cache_source(&mut self, key: &str) -> bool {
+        let modified = self.cache_reader.modified(key);
+        let ok = match key {
+            "tailscale" => {
+                self.tailscale = self.cache_reader.read_tailscale();
+                self.tailscale.is_some()
+            }
+            "claude" => {
+                self.claude = self.cache_reader.read_claude();
+                self.claude.is_some()
+            }
+            "billing" => {
+                self.billing = self.cache_reader.read_billing();
+                self.billing.is_some()
+            }
+            "k8s" => {
+                self.k8s = self.cache_reader.read_k8s();
+                self.k8s.is_some()
+            }
+            "claude-personal" => {
+                self.claude_personal = self.cache_reader.read_claude_personal();
+                self.claude_personal_read_at = Instant::now();
+                self.claude_personal.is_some()
+            }
+            _ => false,
+        };
+        let rewritten =
+            modified.is_some_and(|m| self.cache_mtimes.insert(key.to_string(), m) != Some(m));
+        if ok {
+            if rewritten {
+                self.last_successful_read = Some(Instant::now());
+            }
+            self.daemon_down_since = None;
+        }
+        ok
     }
 
-    /// Whether the waifu widget area should be shown in the layout.
-    /// True when waifu is enabled AND a live endpoint is configured.
-    pub fn wants_waifu(&self) -> bool {
-        self.cfg.image.waifu_enabled && self.cfg.waifu_endpoint().is_some()
+    /// Re-read every daemon cache file and restart the re-read timer.
+    /// Returns the sources that came back with data.
+    fn reread_daemon_cache(&mut self, now: Instant) -> Vec<&'static str> {
+        let mut found = Vec::new();
+        for key in DAEMON_SOURCES {
+            if self.reload_cache_source(key) {
+                found.push(key);
+            }
+        }
+        self.last_cache_read = now;
+        self.update_daemon_down(now);
+        found
     }
 
-    /// Navigate to a waifu image by relative offset (1 = next, -1 = prev).
-    /// Also triggers a background fetch to grow the gallery on demand.
-    pub fn waifu_navigate(&mut self, delta: i32) {
-        let n = self.waifu_gallery.len() as i32;
-        if n == 0 {
+    /// 'R': re-read the daemon cache now, leaving system metrics and the
+    /// process list alone, and report which sources the daemon rewrote since
+    /// their last read, which still hold the same data, and which are missing.
+    fn force_cache_reread(&mut self) {
+        let before = self.cache_mtimes.clone();
+        let found = self.reread_daemon_cache(Instant::now());
+        if found.is_empty() {
+            self.set_status("daemon cache re-read: no data (is the daemon running?)");
             return;
         }
-        let base = if self.waifu_index >= 0 {
-            self.waifu_index
+        let (updated, unchanged): (Vec<&str>, Vec<&str>) = found
+            .iter()
+            .partition(|k| self.cache_mtimes.get(**k) != before.get(**k));
+        let missing: Vec<&str> = DAEMON_SOURCES
+            .into_iter()
+            .filter(|k| !found.contains(k))
+            .collect();
+        let mut parts = vec![if updated.is_empty() {
+            "no updates".to_string()
         } else {
-            0
-        };
-        let new_idx = ((base + delta) % n + n) % n;
-        self.waifu_load_at(new_idx as usize);
+            format!("updated {}", updated.join(", "))
+        }];
+        for (label, keys) in [("unchanged", &unchanged), ("missing", &missing)] {
+            if !keys.is_empty() {
+                parts.push(format!("{label} {}", keys.join(", ")));
+            }
+        }
+        self.set_status(format!("daemon cache re-read: {}", parts.join("; ")));
+    }
 
-        // Auto-fetch more images as the user navigates.
-        self.waifu_fetch_live();
+    /// Full cache re-read period: the safety sweep with a file watcher,
+    /// otherwise the poll interval.
+    fn cache_read_interval(&self) -> Duration {
+        if self.cache_watcher.is_some() {
+            CACHE_SWEEP_INTERVAL
+        } else {
+            CACHE_POLL_INTERVAL
+        }
     }
 
-    /// Navigate to a random waifu image.
-    /// Also triggers a background fetch to grow the gallery.
-    pub fn waifu_random(&mut self) {
-        let n = self.waifu_gallery.len();
-        if n == 0 {
-            return;
+    /// Start the daemon-silence clock once a full re-read finds no data at
+    /// all; any data stops it.
+    fn update_daemon_down(&mut self, now: Instant) {
+        let any_data = self.tailscale.is_some()
+            || self.claude.is_some()
+            || self.billing.is_some()
+            || self.k8s.is_some()
+            || self.claude_personal.is_some();
+        if any_data {
+            self.daemon_down_since = None;
+        } else {
+            self.daemon_down_since.get_or_insert(now);
         }
-        // Simple pseudo-random using system time nanos.
-        let nanos = std::time::SystemTime::now()
+    }
+
+    /// While the daemon is silent: how long for, and time until the next
+    /// full cache re-read.
+    pub fn daemon_silence(&self) -> Option<(Duration, Duration)> {
+        let since = self.daemon_down_since?;
+        let retry_in = self
+            .cache_read_interval()
+            .saturating_sub(self.last_cache_read.elapsed());
+        Some((since.elapsed(), retry_in))
+    }
+
+    /// Seconds until the next Claude personal message slot, ticking down
+    /// live from the last cache read.
+    pub fn claude_personal_countdown(&self) -> i64 {
+        match &self.claude_personal {
+            Some(report) => {
+                let elapsed = self.claude_personal_read_at.elapsed().as_secs() as i64;
+                (report.next_slot_secs - elapsed).max(0)
+            }
+            None => 0,
+        }
+    }
+
+    /// Apply the `index`-th saved filter; applying the active one again clears it.
+    fn apply_saved_filter(&mut self, index: usize) {
+        let Some((name, pattern)) = self.cfg.filters.saved.iter().nth(index) else {
+            return;
+        };
+        if self.active_saved_filter.as_deref() == Some(name.as_str()) {
+            self.active_saved_filter = None;
+            self.process_filter.clear();
+        } else {
+            self.active_saved_filter = Some(name.clone());
+            self.process_filter = pattern.clone();
+        }
+        self.process_scroll = 0;
+    }
+
+    /// Latest memory usage and its trend when at or above `MEM_PRESSURE_PCT`.
+    pub fn memory_pressure(&self) -> Option<(f64, Trend)> {
+        let pct = *self.mem_history.back()?;
+        (pct >= MEM_PRESSURE_PCT).then(|| (pct, trend(&self.mem_history, 5, 1.0)))
+    }
+
+    /// Headline CPU percentage: a `display.cpu_smoothing`-sample moving
+    /// average of `cpu_history`, or the raw snapshot before any history exists.
+    /// Sparklines keep plotting the raw samples.
+    pub fn cpu_display_total(&self) -> f32 {
+        smoothed(&self.cpu_history, self.cfg.display.cpu_smoothing)
+            .map(|v| v as f32)
+            .unwrap_or_else(|| self.sys.snapshot().cpu_total)
+    }
+
+    /// Current spinner glyph for in-flight async work (waifu fetch, etc.).
+    pub fn spinner_char(&self) -> char {
+        SPINNER_FRAMES[self.spinner_frame % SPINNER_FRAMES.len()]
+    }
+
+    /// Check if a waifu image is loaded (for layout decisions).
+    pub fn has_waifu(&self) -> bool {
+        self.waifu_state.is_some()
+    }
+
+    /// Whether the waifu widget area should be shown in the layout.
+    /// True when waifu is enabled AND a live endpoint is configured.
+    pub fn wants_waifu(&self) -> bool {
+        self.cfg.image.waifu_enabled && self.cfg.waifu_endpoint().is_some()
+    }
+
+    /// Whether the Dashboard currently lays out the waifu: it is wanted and
+    /// not swapped out for the cluster widgets with `W`.
+    pub fn dashboard_shows_waifu(&self) -> bool {
+        self.wants_waifu() && self.dashboard_right_mode == DashboardRightMode::Waifu
+    }
+
+    /// Navigate to a waifu image by relative offset (1 = next, -1 = prev).
+    /// Also triggers a background fetch to grow the gallery on demand.
+    pub fn waifu_navigate(&mut self, delta: i32) {
+        let n = self.waifu_gallery.len() as i32;
+        if n == 0 {
+            return;
+        }
+        let base = if self.waifu_index >= 0 {
+            self.waifu_index
+        } else {
+            0
+        };
+        let new_idx = ((base + delta) % n + n) % n;
+        self.waifu_load_at(new_idx as usize);
+
+        // Auto-fetch more images as the user navigates.
+        self.waifu_fetch_live();
+    }
+
+    /// Navigate to a random waifu image.
+    /// Also triggers a background fetch to grow the gallery.
+    pub fn waifu_random(&mut self) {
+        let n = self.waifu_gallery.len();
+        if n == 0 {
+            return;
+        }
+        // Simple pseudo-random using system time nanos.
+        let nanos = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_nanos())
             .unwrap_or(0) as usize;
@@ -758,8 +2278,23 @@ impl App {
         });
     }
 
+    /// On quit: give an in-flight live fetch up to `limit` to complete
+    /// (the result is discarded). Returns at once when nothing is fetching.
+    pub async fn drain_waifu_fetch(&mut self, limit: Duration) {
+        if !self.waifu_fetching {
+            return;
+        }
+        if tokio::time::timeout(limit, self.waifu_fetch_rx.recv())
+            .await
+            .is_err()
+        {
+            tracing::debug!("waifu fetch still running after {limit:?}, dropping it");
+        }
+        self.waifu_fetching = false;
+    }
+
     /// Minimum gallery size for auto-fetch on launch.
-    const GALLERY_PREFETCH: usize = 3;
+    const GALLERY_PREFETCH: usize = WAIFU_PREFETCH;
 
     /// Poll for completed live fetch results (called from tick).
     fn poll_waifu_fetch(&mut self) {
@@ -777,23 +2312,12 @@ impl App {
                 }
             };
 
-            // Decode image from raw bytes.
-            let image = match data::waifu::decode_image_bytes(&result.data) {
-                Ok(img) => img,
-                Err(e) => {
-                    tracing::warn!("waifu decode failed: {}", e);
-                    continue;
-                }
-            };
-
-            // Dedup by hash: skip if already in gallery.
-            if self.waifu_gallery.iter().any(|e| e.hash == result.hash) {
+            // Dedup on the encoded bytes, before paying for a decode: skip
+            // if already in gallery.
+            let hash = data::waifu::content_hash(&result.data);
+            if self.waifu_gallery.iter().any(|e| e.hash == hash) {
                 // Already have this image; just navigate to it.
-                if let Some(idx) = self
-                    .waifu_gallery
-                    .iter()
-                    .position(|e| e.hash == result.hash)
-                {
+                if let Some(idx) = self.waifu_gallery.iter().position(|e| e.hash == hash) {
                     self.waifu_load_at(idx);
                 }
                 // Still chain prefetch — the dupe doesn't count toward our target.
@@ -803,24 +2327,37 @@ impl App {
                 continue;
             }
 
+            // Decode image from raw bytes.
+            let image = match data::waifu::decode_image_bytes(&result.data) {
+                Ok(img) => img,
+                Err(e) => {
+                    tracing::warn!("waifu decode failed: {}", e);
+                    continue;
+                }
+            };
+            let frames = if self.cfg.image.animate {
+                data::waifu::decode_animation(&result.data)
+            } else {
+                Vec::new()
+            };
+
             // Add to gallery.
             let name = data::waifu::format_image_name(&result.name);
             let gallery_was_small = self.waifu_gallery.len() < Self::GALLERY_PREFETCH;
             let entry = WaifuEntry {
-                image: image.clone(),
+                image,
                 name: name.clone(),
-                hash: result.hash,
+                hash,
+                frames,
             };
             self.waifu_gallery.push(entry);
+            self.evict_waifu_overflow();
 
             // Auto-display during initial prefetch (gallery building up).
             // After prefetch, silently add to gallery — don't stomp user's navigation.
             if gallery_was_small || self.waifu_index < 0 {
                 let idx = self.waifu_gallery.len() - 1;
-                let scaled = self.prepare_waifu_image(&image);
-                self.waifu_state = Some(self.picker.new_resize_protocol(scaled));
-                self.waifu_index = idx as i32;
-                self.waifu_name = name;
+                self.waifu_load_at(idx);
             }
 
             // Auto-fetch more until gallery reaches prefetch target.
@@ -830,6 +2367,26 @@ impl App {
         }
     }
 
+    /// Drop the oldest non-current gallery entries until the gallery fits
+    /// `collectors.waifu.max_images`, keeping `waifu_index` on the same image.
+    fn evict_waifu_overflow(&mut self) {
+        let cap = self.waifu_max_images;
+        while self.waifu_gallery.len() > cap {
+            let victim = if self.waifu_index == 0 { 1 } else { 0 };
+            let entry = self.waifu_gallery.remove(victim);
+            if (victim as i32) < self.waifu_index {
+                self.waifu_index -= 1;
+            }
+            let held: usize = self.waifu_gallery.iter().map(waifu_entry_bytes).sum();
+            tracing::info!(
+                "waifu gallery over cap ({cap}): evicted {:?} (~{} KiB), ~{} KiB held",
+                entry.name,
+                waifu_entry_bytes(&entry) / 1024,
+                held / 1024
+            );
+        }
+    }
+
     /// Load the waifu image at the given gallery index.
     /// Pre-scales the image to fill the widget area (cover mode).
     pub(crate) fn waifu_load_at(&mut self, idx: usize) {
@@ -841,16 +2398,61 @@ impl App {
         self.waifu_state = Some(self.picker.new_resize_protocol(scaled));
         self.waifu_index = idx as i32;
         self.waifu_name = entry.name.clone();
+        self.waifu_frame = 0;
+        self.waifu_frame_at = Instant::now();
+        self.waifu_scaled_frames = vec![None; entry.frames.len()];
+    }
+
+    /// Delay of the current animation frame, if the shown waifu is animating.
+    fn waifu_frame_delay(&self) -> Option<Duration> {
+        if !self.cfg.image.animate || self.waifu_index < 0 {
+            return None;
+        }
+        let entry = self.waifu_gallery.get(self.waifu_index as usize)?;
+        entry.frames.get(self.waifu_frame).map(|f| f.delay)
+    }
+
+    /// Time until the next animation frame is due (None = nothing animating).
+    /// The event loop uses this to wake up early for smooth playback.
+    pub fn next_frame_in(&self) -> Option<Duration> {
+        if self.frozen || self.waifu_rect.is_none() {
+            return None;
+        }
+        let delay = self.waifu_frame_delay()?;
+        Some(delay.saturating_sub(self.waifu_frame_at.elapsed()))
+    }
+
+    /// Show the next animation frame once the current frame's delay has
+    /// elapsed. Paused while the waifu isn't on screen.
+    fn advance_waifu_frame(&mut self) {
+        let Some(delay) = self.waifu_frame_delay() else {
+            return;
+        };
+        if self.waifu_rect.is_none() || self.waifu_frame_at.elapsed() < delay {
+            return;
+        }
+        let entry = &self.waifu_gallery[self.waifu_index as usize];
+        let next = (self.waifu_frame + 1) % entry.frames.len();
+        let scaled = match self.waifu_scaled_frames.get(next) {
+            Some(Some(scaled)) => scaled.clone(),
+            _ => {
+                let scaled = self.prepare_waifu_image(&entry.frames[next].image);
+                if let Some(slot) = self.waifu_scaled_frames.get_mut(next) {
+                    *slot = Some(scaled.clone());
+                }
+                scaled
+            }
+        };
+        self.waifu_state = Some(self.picker.new_resize_protocol(scaled));
+        self.waifu_frame = next;
+        self.waifu_frame_at = Instant::now();
     }
 
     /// Pre-scale image to fill the widget area (CSS object-fit: cover).
     /// Scales the image so its cell dimensions >= the widget area,
     /// ensuring Resize::Crop fills the widget with no empty space.
     fn prepare_waifu_image(&self, image: &image::DynamicImage) -> image::DynamicImage {
-        let (fw, fh) = self.picker.font_size();
-        if fw == 0 || fh == 0 {
-            return image.clone();
-        }
+        let (fw, fh) = effective_font_size(self.picker.font_size());
 
         // Estimate widget area in cells. Waifu gets ~40% width, full height minus chrome.
         let cols = if self.term_width > 0 {
@@ -879,16 +2481,521 @@ impl App {
 
     /// Kill the currently selected process.
     fn kill_selected_process(&mut self, force: bool) {
-        if let Some(proc_info) = self.processes.get(self.process_scroll) {
-            let pid = sysinfo::Pid::from_u32(proc_info.pid);
-            if let Some(process) = self.proc_sys.process(pid) {
-                if force {
-                    process.kill(); // SIGKILL
+        let Some(target) = self
+            .processes
+            .get(self.process_scroll)
+            .map(|p| (p.pid, p.name.clone()))
+        else {
+            return;
+        };
+        let signal = if force {
+            sysinfo::Signal::Kill
+        } else {
+            sysinfo::Signal::Term
+        };
+        if self.signal_selected_process(signal) {
+            self.record_kill(target.0, target.1, force);
+        }
+    }
+
+    /// Open the cache inspector with a fresh file listing.
+    fn open_cache_debug(&mut self) {
+        self.cache_debug = Some(CacheDebugView {
+            files: self.cache_reader.list_cache_files(),
+            selected: 0,
+            raw: None,
+            raw_scroll: 0,
+        });
+    }
+
+    /// Cache inspector keys: j/k select (or scroll the raw view), Enter shows
+    /// the raw JSON, r re-scans, Esc/q backs out one level.
+    fn handle_cache_debug_key(&mut self, key: KeyEvent) {
+        use crossterm::event::KeyCode;
+        if key.code == KeyCode::Char('r') {
+            self.open_cache_debug();
+            return;
+        }
+        let Some(view) = self.cache_debug.as_mut() else {
+            return;
+        };
+        if view.raw.is_some() {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    view.raw_scroll = view.raw_scroll.saturating_add(1);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    view.raw_scroll = view.raw_scroll.saturating_sub(1);
+                }
+                KeyCode::PageDown => view.raw_scroll = view.raw_scroll.saturating_add(10),
+                KeyCode::PageUp => view.raw_scroll = view.raw_scroll.saturating_sub(10),
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => view.raw = None,
+                _ => {}
+            }
+            return;
+        }
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                if view.selected + 1 < view.files.len() {
+                    view.selected += 1;
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => view.selected = view.selected.saturating_sub(1),
+            KeyCode::Enter => {
+                if let Some(file) = view.files.get(view.selected) {
+                    let text = data::cache::read_cache_text(&file.path)
+                        .map(|data| {
+                            // Pretty-print when possible; show the raw bytes otherwise.
+                            serde_json::from_str::<serde_json::Value>(&data)
+                                .and_then(|v| serde_json::to_string_pretty(&v))
+                                .unwrap_or(data)
+                        })
+                        .unwrap_or_else(|e| format!("cannot read {}: {e}", file.path.display()));
+                    view.raw = Some(text);
+                    view.raw_scroll = 0;
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('`') => self.cache_debug = None,
+            _ => {}
+        }
+    }
+
+    /// Append to the session kill log, dropping the oldest past `KILL_LOG_LEN`.
+    fn record_kill(&mut self, pid: u32, name: String, force: bool) {
+        if self.kill_log.len() >= KILL_LOG_LEN {
+            self.kill_log.remove(0);
+        }
+        self.kill_log.push((Instant::now(), pid, name, force));
+    }
+
+    /// Whether `user` is someone else, so signalling their process will
+    /// likely fail (never true when running as root or the owner is unknown).
+    fn owned_by_other_user(&self, user: &str) -> bool {
+        !self.current_user.is_empty()
+            && self.current_user != "root"
+            && !user.is_empty()
+            && user != self.current_user
+    }
+
+    /// The selected process as a signal target (None for grouped rows).
+    fn selected_signal_target(&mut self) -> Option<SignalTarget> {
+        if self.selected_is_group() {
+            return None;
+        }
+        self.processes
+            .get(self.process_scroll)
+            .map(|p| SignalTarget {
+                pid: p.pid,
+                name: p.name.clone(),
+                user: p.user.clone(),
+            })
+    }
+
+    /// Send a signal to the currently selected process. See `signal_process`.
+    fn signal_selected_process(&mut self, signal: sysinfo::Signal) -> bool {
+        match self.selected_signal_target() {
+            Some(target) => self.signal_process(&target, signal),
+            None => false,
+        }
+    }
+
+    /// Send a signal to `target` and report the outcome in the status line.
+    /// Returns whether the signal was delivered. Another user's process gets
+    /// a warning first; repeating the command on the same PID sends anyway.
+    /// PID 1 is never signalled, and the TUI itself quits cleanly instead.
+    /// A PID that has exited (or now runs something else) is left alone.
+    fn signal_process(&mut self, target: &SignalTarget, signal: sysinfo::Signal) -> bool {
+        let (pid, proc_name) = (target.pid, &target.name);
+        // Killing or stopping init takes the whole system down; refuse outright.
+        if pid == 1 {
+            let msg = format!(
+                "refusing to send {} to PID 1 ({proc_name})",
+                signal_name(signal)
+            );
+            self.set_status(msg);
+            return false;
+        }
+        // Stopping or killing ourselves would leave the terminal in raw mode:
+        // quit cleanly. Other signals (USR1, WINCH, ...) aren't a request to
+        // quit, and several would kill us uncleanly, so refuse them.
+        if pid == std::process::id() {
+            use sysinfo::Signal::{Hangup, Interrupt, Kill, Stop, Term};
+            if matches!(signal, Term | Kill | Interrupt | Hangup | Stop) {
+                self.request_quit();
+            } else {
+                let msg = format!("refusing to send {} to ourselves", signal_name(signal));
+                self.set_status(msg);
+            }
+            return false;
+        }
+        let foreign = self.owned_by_other_user(&target.user);
+        if foreign && self.foreign_kill_warned != Some(pid) {
+            let msg = format!(
+                "{proc_name} ({pid}) belongs to {}: repeat to try anyway",
+                target.user
+            );
+            self.foreign_kill_warned = Some(pid);
+            self.set_status(msg);
+            return false;
+        }
+        self.foreign_kill_warned = None;
+        let name = signal_name(signal);
+        let result = self
+            .proc_sys
+            .process(sysinfo::Pid::from_u32(pid))
+            .filter(|p| p.name().to_string_lossy() == *proc_name)
+            .map(|p| p.kill_with(signal));
+        let msg = match result {
+            Some(Some(true)) => format!("sent {name} to {proc_name} ({pid})"),
+            Some(Some(false)) => {
+                // sysinfo only reports a bool; errno from the kill(2) it just made says why.
+                let denied = foreign
+                    || std::io::Error::last_os_error().kind()
+                        == std::io::ErrorKind::PermissionDenied;
+                if denied {
+                    format!("permission denied sending {name} to {pid}")
                 } else {
-                    process.kill_with(sysinfo::Signal::Term); // SIGTERM
+                    format!("failed to send {name} to {pid}")
+                }
+            }
+            Some(None) => format!("{name} is not supported on this platform"),
+            None => format!("process {pid} no longer exists"),
+        };
+        self.set_status(msg);
+        result == Some(Some(true))
+    }
+
+    /// Move the selected process's nice value by `delta` (within -20..=19)
+    /// and report the outcome in the status line. Needs `keys.allow_renice`;
+    /// lowering niceness usually needs root.
+    fn renice_selected_process(&mut self, delta: i32) {
+        if !self.cfg.keys.allow_renice {
+            self.set_status("renice is off (set keys.allow_renice = true)");
+            return;
+        }
+        if self.selected_is_group() {
+            return;
+        }
+        let Some(proc_info) = self.processes.get(self.process_scroll) else {
+            return;
+        };
+        let (pid, name) = (proc_info.pid, proc_info.name.clone());
+        let Some(current) = read_nice(pid) else {
+            self.set_status(format!("can't read the nice value of {pid}"));
+            return;
+        };
+        let target = (current + delta).clamp(-20, 19);
+        let msg = match set_nice(pid, target) {
+            Ok(()) => format!("reniced {name} ({pid}) to {target}"),
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                format!("permission denied renicing {pid} to {target}")
+            }
+            Err(e) => format!("failed to renice {pid}: {e}"),
+        };
+        self.nice_cache.remove(&pid);
+        self.set_status(msg);
+    }
+
+    /// Where the tab bar is drawn: the top 3 rows of the screen inside
+    /// the `display.margin` inset. Empty in the mini layout, which drops it.
+    fn tab_bar_rect(&self) -> Rect {
+        let screen = Rect::new(0, 0, self.term_width, self.term_height);
+        let area = crate::ui::inset(screen, self.cfg.display.margin);
+        let height = if area.height < crate::ui::layout::MINI_HEIGHT {
+            0
+        } else {
+            area.height.min(3)
+        };
+        Rect { height, ..area }
+    }
+
+    /// Index of the process under `pos` in the last drawn process table.
+    fn process_row_at(&self, pos: Position) -> Option<usize> {
+        let (rect, offset) = self.process_rows_rect?;
+        if !rect.contains(pos) {
+            return None;
+        }
+        let idx = offset + (pos.y - rect.y) as usize;
+        (idx < self.processes.len()).then_some(idx)
+    }
+
+    /// Menu entry under `pos` (rows inside the popup border).
+    fn process_menu_entry_at(&self, pos: Position) -> Option<usize> {
+        let rect = self.process_menu_rect?;
+        if !rect.contains(pos) || pos.y == rect.y {
+            return None;
+        }
+        let idx = (pos.y - rect.y - 1) as usize;
+        (idx < PROCESS_MENU.len()).then_some(idx)
+    }
+
+    fn select_process_menu(&mut self, selected: usize) {
+        if let Some(menu) = self.process_menu.as_mut() {
+            menu.selected = selected;
+        }
+    }
+
+    /// Run a right-click menu action on the selected process and close the menu.
+    fn run_process_action(&mut self, action: ProcessAction) {
+        self.process_menu = None;
+        match action {
+            ProcessAction::Terminate => self.kill_selected_process(false),
+            ProcessAction::Kill => self.kill_selected_process(true),
+            ProcessAction::CopyPid => {
+                if self.selected_is_group() {
+                    return;
+                }
+                if let Some(pid) = self.processes.get(self.process_scroll).map(|p| p.pid) {
+                    self.pending_clipboard = Some(pid.to_string());
+                    self.set_status(format!("copied PID {pid}"));
+                }
+            }
+            ProcessAction::Detail => self.open_process_detail(),
+        }
+    }
+
+    /// Open the detail popup for the selected process. Its CPU graph starts
+    /// over when the selection is a different process (or group) than last
+    /// time.
+    fn open_process_detail(&mut self) {
+        let Some(proc_info) = self.processes.get(self.process_scroll) else {
+            return;
+        };
+        let grouped = proc_info.group_count > 1;
+        if self.detail_pid != Some(proc_info.pid) || self.detail_grouped != grouped {
+            self.detail_pid = Some(proc_info.pid);
+            self.detail_grouped = grouped;
+            self.detail_cpu_history.clear();
+            self.detail_cpu_history
+                .push_back(proc_info.cpu_usage as f64);
+        }
+        self.show_process_detail = true;
+    }
+
+    /// Show a transient message in the status line.
+    pub fn set_status(&mut self, msg: impl Into<String>) {
+        self.status_msg = Some((msg.into(), Instant::now()));
+    }
+
+    /// Current status-line message, if it hasn't expired.
+    pub fn status_message(&self) -> Option<&str> {
+        self.status_msg
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < STATUS_MSG_TTL)
+            .map(|(msg, _)| msg.as_str())
+    }
+
+    /// Record first-seen times for PIDs not present at launch, and drop
+    /// entries for processes that have exited.
+    fn track_new_pids(&mut self, live: &HashSet<u32>, now: Instant) {
+        self.first_seen.retain(|pid, _| live.contains(pid));
+        for &pid in live {
+            if !self.baseline_pids.contains(&pid) {
+                self.first_seen.entry(pid).or_insert(now);
+            }
+        }
+    }
+
+    /// Whether a process appeared after launch within the last `NEW_PROCESS_WINDOW`.
+    pub fn is_new_process(&self, pid: u32) -> bool {
+        self.first_seen
+            .get(&pid)
+            .is_some_and(|t| t.elapsed() < NEW_PROCESS_WINDOW)
+    }
+
+    /// Sort processes by the given column, breaking ties on PID so rows with
+    /// equal values (e.g. 0% CPU) keep a stable order across refreshes.
+    fn sort_processes(
+        procs: &mut [ProcessInfo],
+        sort: ProcessSort,
+        swap: &HashMap<u32, (Instant, Option<u64>)>,
+    ) {
+        match sort {
+            ProcessSort::Cpu => procs.sort_by(|a, b| {
+                b.cpu_usage
+                    .partial_cmp(&a.cpu_usage)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(a.pid.cmp(&b.pid))
+            }),
+            ProcessSort::Memory => {
+                procs.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes).then(a.pid.cmp(&b.pid)))
+            }
+            ProcessSort::Pid => procs.sort_by_key(|p| p.pid),
+            ProcessSort::Name => procs.sort_by(|a, b| {
+                a.name
+                    .to_lowercase()
+                    .cmp(&b.name.to_lowercase())
+                    .then(a.pid.cmp(&b.pid))
+            }),
+            ProcessSort::Swap => procs.sort_by_key(|p| {
+                // Only the representative PID is sampled, so groups rank as unknown.
+                let bytes = match p.group_count {
+                    1 => swap.get(&p.pid).and_then(|(_, b)| *b).unwrap_or(0),
+                    _ => 0,
+                };
+                (std::cmp::Reverse(bytes), p.pid)
+            }),
+        }
+    }
+
+    /// Turn the change feed on or off. Turning it on drops any old baseline;
+    /// the next refresh's full sample becomes the new one.
+    fn toggle_diff_mode(&mut self) {
+        self.diff_mode = !self.diff_mode;
+        self.process_changes.clear();
+        self.prev_process_sample = None;
+    }
+
+    /// Keep only processes that are new, changed by `DIFF_CPU_POINTS` CPU or
+    /// `DIFF_MEM_RATIO` memory, or gone since the previous sample (exited
+    /// ones are appended for this one refresh). `procs` becomes the next
+    /// comparison baseline. Either way across the CPU > 0 cut counts as a
+    /// move from or to 0%: a process that went idle shows as changed at 0%,
+    /// and one that woke up as changed (only PIDs first seen this sample are
+    /// new), each only when that move reaches `DIFF_CPU_POINTS`.
+    fn diff_processes(&mut self, procs: Vec<ProcessInfo>) -> Vec<ProcessInfo> {
+        let sample: HashMap<u32, ProcessInfo> = procs.iter().map(|p| (p.pid, p.clone())).collect();
+        self.process_changes.clear();
+        // First sample after 'N': nothing to compare against yet.
+        let Some(prev) = self.prev_process_sample.replace(sample) else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        for p in procs {
+            let change = match prev.get(&p.pid) {
+                None if self.started_since_last_sample(p.pid) => Some(ProcessChange::New),
+                // Idle last sample (below the CPU > 0 cut): compare to 0%.
+                None => {
+                    let idle = ProcessInfo {
+                        cpu_usage: 0.0,
+                        ..p.clone()
+                    };
+                    process_moved(&idle, &p).then_some(ProcessChange::Changed)
+                }
+                Some(old) if process_moved(old, &p) => Some(ProcessChange::Changed),
+                Some(_) => None,
+            };
+            if let Some(change) = change {
+                self.process_changes.insert(p.pid, change);
+                out.push(p);
+            }
+        }
+        let mut vanished: Vec<ProcessInfo> = prev
+            .into_values()
+            .filter(|old| {
+                !self
+                    .prev_process_sample
+                    .as_ref()
+                    .is_some_and(|s| s.contains_key(&old.pid))
+            })
+            .collect();
+        vanished.sort_by_key(|p| p.pid);
+        for mut old in vanished {
+            let alive = self
+                .proc_sys
+                .process(sysinfo::Pid::from_u32(old.pid))
+                .is_some();
+            let change = if !alive {
+                ProcessChange::Gone
+            } else if old.cpu_usage >= DIFF_CPU_POINTS {
+                old.cpu_usage = 0.0;
+                ProcessChange::Changed
+            } else {
+                continue;
+            };
+            self.process_changes.insert(old.pid, change);
+            out.push(old);
+        }
+        out
+    }
+
+    /// Interfaces that came up mid-session count from first sight. A
+    /// counter that went backwards (driver reload, interface re-created)
+    /// restarted from zero, so its baseline drops to zero too; otherwise the
+    /// session total would hide traffic until it passed the old value.
+    fn update_session_net_baseline(&mut self, networks: &[NetInfo]) {
+        for n in networks {
+            let base = self
+                .session_net_baseline
+                .entry(n.name.clone())
+                .or_insert((n.rx_bytes, n.tx_bytes));
+            if n.rx_bytes < base.0 {
+                base.0 = 0;
+            }
+            if n.tx_bytes < base.1 {
+                base.1 = 0;
+            }
+        }
+    }
+
+    /// Whether `pid` was first seen after the previous process sample (see
+    /// `track_new_pids`; PIDs alive at launch never count).
+    fn started_since_last_sample(&self, pid: u32) -> bool {
+        self.first_seen
+            .get(&pid)
+            .is_some_and(|&t| t > self.last_sys_refresh)
+    }
+
+    /// Fold processes sharing an executable into one row: summed CPU and
+    /// memory, the lowest PID as its representative, the longest runtime,
+    /// and `group_count` members. Processes without a readable exe group by
+    /// name.
+    fn group_by_exe(procs: Vec<ProcessInfo>) -> Vec<ProcessInfo> {
+        let mut groups: HashMap<String, ProcessInfo> = HashMap::new();
+        for p in procs {
+            let key = if p.exe.is_empty() {
+                p.name.clone()
+            } else {
+                p.exe.clone()
+            };
+            match groups.entry(key) {
+                std::collections::hash_map::Entry::Vacant(slot) => {
+                    let name = std::path::Path::new(&p.exe)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| p.name.clone());
+                    let cmd = if p.exe.is_empty() {
+                        p.name.clone()
+                    } else {
+                        p.exe.clone()
+                    };
+                    slot.insert(ProcessInfo {
+                        ppid: 0,
+                        name,
+                        cmd,
+                        cgroup: String::new(),
+                        ..p
+                    });
+                }
+                std::collections::hash_map::Entry::Occupied(mut slot) => {
+                    let g = slot.get_mut();
+                    g.group_count += 1;
+                    g.cpu_usage += p.cpu_usage;
+                    g.memory_bytes += p.memory_bytes;
+                    g.run_time_secs = g.run_time_secs.max(p.run_time_secs);
+                    if g.user != p.user {
+                        g.user = "*".into();
+                    }
+                    if matches!(p.state, ProcessState::Run) {
+                        g.state = ProcessState::Run;
+                    }
+                    g.pid = g.pid.min(p.pid);
                 }
             }
         }
+        groups.into_values().collect()
+    }
+
+    /// Grouped rows stand for many PIDs; refuse per-process actions on them.
+    fn selected_is_group(&mut self) -> bool {
+        let grouped = self
+            .processes
+            .get(self.process_scroll)
+            .is_some_and(|p| p.group_count > 1);
+        if grouped {
+            self.set_status("grouped row: press X to ungroup first");
+        }
+        grouped
     }
 
     /// Build a depth-first tree ordering of processes.
@@ -961,6 +3068,9 @@ impl App {
                 state: procs[idx].state,
                 run_time_secs: procs[idx].run_time_secs,
                 tree_depth: depth,
+                cgroup: std::mem::take(&mut procs[idx].cgroup),
+                exe: std::mem::take(&mut procs[idx].exe),
+                group_count: procs[idx].group_count,
             })
             .collect();
 
@@ -968,13 +3078,13 @@ impl App {
     }
 
     fn next_tab(&mut self) {
-        let tabs = Tab::ALL;
+        let tabs = &self.tab_order;
         let idx = tabs.iter().position(|t| *t == self.active_tab).unwrap_or(0);
         self.active_tab = tabs[(idx + 1) % tabs.len()];
     }
 
     fn prev_tab(&mut self) {
-        let tabs = Tab::ALL;
+        let tabs = &self.tab_order;
         let idx = tabs.iter().position(|t| *t == self.active_tab).unwrap_or(0);
         self.active_tab = tabs[(idx + tabs.len() - 1) % tabs.len()];
     }
@@ -986,51 +3096,158 @@ impl App {
     /// All data fields are empty/default. Use builder-style methods to set state.
     pub fn test_new(cfg: TuiConfig) -> Self {
         let (waifu_fetch_tx, waifu_fetch_rx) = mpsc::channel(4);
+        let process_columns = ProcessColumn::parse_list(&cfg.display.process_columns);
+        let process_sort = ProcessColumn::initial_sort(&process_columns);
+        let show_kernel_threads = cfg.display.show_kernel_threads;
+        let hide_idle_interfaces = cfg.display.hide_idle_interfaces;
+        let tab_order = cfg.tab_order();
+        let default_tab = cfg.default_tab().unwrap_or(tab_order[0]);
+        let net_idle_threshold = cfg.net_idle_threshold();
+        let disk_label = cfg.disk_label();
+        let disk_precision = cfg.disk_precision();
+        let cpu_aggregate_view = cfg.display.cpu_aggregate;
+        let sparkline_style = cfg.sparkline_style();
+        let sparkline_newest = cfg.sparkline_newest();
+        let mem_warn_mode = cfg.mem_warn_mode();
+        let clock_skew_secs = cfg.clock_skew_secs();
+        let cpu_view = cfg.cpu_view();
+        let selection_style = cfg.selection_style();
+        let ip_version = cfg.ip_version();
+        let refresh_bounds = cfg.refresh_bounds();
+        let waifu_max_images = cfg.waifu_max_images();
+        let pressure_weights = cfg.pressure_weights();
+        let cpu_normalize = cfg.cpu_normalize();
+        let gradient = Gradient::new(cfg.gradient_stops().unwrap_or(PCT_STOPS));
+        let temp_gradient = Gradient::new(cfg.temp_gradient_stops().unwrap_or(TEMP_STOPS));
         Self {
             cfg,
-            active_tab: Tab::Dashboard,
+            active_tab: default_tab,
+            tab_order,
             term_width: 160,
             term_height: 50,
             show_help: false,
             help_tab: 0,
+            show_about: false,
+            chrome_hidden: false,
             frozen: false,
+            colors_enabled: true,
             process_filter: String::new(),
             filter_mode: false,
-            refresh_ms: 1000,
+            active_saved_filter: None,
+            refresh_ms: 1000u64.clamp(refresh_bounds.0, refresh_bounds.1),
+            refresh_bounds,
             show_cmd: false,
             tree_mode: false,
+            group_by_exe: false,
+            diff_mode: false,
+            process_changes: HashMap::new(),
+            prev_process_sample: None,
+            process_columns,
+            sparkline_style,
+            sparkline_newest,
+            mem_warn_mode,
+            clock_skew_secs,
+            cpu_view,
+            selection_style,
+            pressure_weights,
+            pressure_score: 0.0,
+            cpu_normalize,
+            gradient,
+            temp_gradient,
             sys: SysMetrics::empty(),
             cpu_history: VecDeque::new(),
             cpu_per_core_history: Vec::new(),
+            cpu_freq_peak: Vec::new(),
             mem_history: VecDeque::new(),
             swap_history: VecDeque::new(),
             net_rx_history: VecDeque::new(),
             net_tx_history: VecDeque::new(),
+            session_net_baseline: HashMap::new(),
             load_history: VecDeque::new(),
             temp_history: VecDeque::new(),
             pending_kill: None,
+            current_user: String::new(),
+            foreign_kill_warned: None,
+            signal_menu: None,
+            signal_target: None,
+            process_menu: None,
+            process_menu_rect: None,
+            process_rows_rect: None,
+            pending_clipboard: None,
+            status_msg: None,
+            kill_log: Vec::new(),
+            show_kill_log: false,
+            show_legend: false,
+            detail_pid: None,
+            detail_grouped: false,
+            show_process_detail: false,
+            detail_cpu_history: VecDeque::with_capacity(HISTORY_LEN),
+            prometheus_path: None,
+            prometheus_failing: false,
+            first_run_hint_until: None,
+            set_title: false,
+            last_title: String::new(),
+            last_title_update: Instant::now(),
+            debug_mode: false,
+            cache_debug: None,
+            layout_dump: None,
+            should_quit: false,
+            pending_quit: None,
             processes: Vec::new(),
-            process_sort: ProcessSort::Cpu,
+            process_sort,
             sort_reverse: false,
             process_scroll: 0,
+            process_visible_rows: 0,
             total_process_count: 0,
+            show_kernel_threads,
+            kernel_threads_hidden: 0,
+            baseline_pids: HashSet::new(),
+            first_seen: HashMap::new(),
+            cgroup_cache: HashMap::new(),
+            fd_cache: HashMap::new(),
+            swap_cache: HashMap::new(),
+            nice_cache: HashMap::new(),
+            ts_show_fqdn: false,
+            ts_raw_bytes: false,
+            ip_version,
+            hide_idle_interfaces,
+            net_idle_threshold,
+            disk_label,
+            disk_precision,
+            cpu_aggregate_view,
+            dashboard_right_mode: DashboardRightMode::Waifu,
+            focused: true,
             tailscale: None,
             claude: None,
             billing: None,
             k8s: None,
             waifu_state: None,
             waifu_gallery: Vec::new(),
+            waifu_max_images,
             waifu_index: -1,
             waifu_show_info: false,
             waifu_name: String::new(),
             waifu_fetching: false,
+            spinner_frame: 0,
+            waifu_rect: None,
+            mouse_pos: None,
+            waifu_frame: 0,
+            waifu_frame_at: Instant::now(),
+            waifu_scaled_frames: Vec::new(),
             claude_personal: None,
-            expanded: false,
+            claude_personal_read_at: Instant::now(),
+            expanded: None,
+            pending_resize: None,
             picker: Picker::from_fontsize((8, 16)),
             proc_sys: sysinfo::System::new(),
             users: sysinfo::Users::new_with_refreshed_list(),
             cache_reader: CacheReader::new(std::path::PathBuf::from("/nonexistent")),
+            cache_watcher: None,
+            cache_watch_pending: false,
             last_cache_read: Instant::now(),
+            last_successful_read: None,
+            cache_mtimes: HashMap::new(),
+            daemon_down_since: None,
             last_sys_refresh: Instant::now(),
             component_versions: Default::default(),
             waifu_fetch_rx,
@@ -1062,10 +3279,256 @@ impl App {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Cell size assumed when the terminal doesn't report one (common 8x16 font).
+const FALLBACK_FONT_SIZE: (u16, u16) = (8, 16);
+
+/// The picker's font size, or `FALLBACK_FONT_SIZE` when the terminal reported
+/// none, so images are still downscaled to a sane pixel budget rather than
+/// handed to the protocol at full resolution. Warns once per session.
+fn effective_font_size(reported: (u16, u16)) -> (u16, u16) {
+    static WARN_ONCE: std::sync::Once = std::sync::Once::new();
+    if reported.0 > 0 && reported.1 > 0 {
+        return reported;
+    }
+    WARN_ONCE.call_once(|| {
+        tracing::warn!(
+            "terminal did not report a font size; assuming {}x{} px cells for images",
+            FALLBACK_FONT_SIZE.0,
+            FALLBACK_FONT_SIZE.1
+        );
+    });
+    FALLBACK_FONT_SIZE
+}
+
+/// Mean of the last `n` samples (`n` of 0 or 1 = latest value), or None if empty.
+pub fn smoothed(history: &VecDeque<f64>, n: usize) -> Option<f64> {
+    let n = n.max(1).min(history.len());
+    if n == 0 {
+        return None;
+    }
+    Some(history.iter().rev().take(n).sum::<f64>() / n as f64)
+}
+
+/// Direction a history series is moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+impl Trend {
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Trend::Rising => "\u{2191}",
+            Trend::Falling => "\u{2193}",
+            Trend::Steady => "\u{2192}",
+        }
+    }
+}
+
+/// Compare the mean of the last `window` samples with the `window` before
+/// them; moves smaller than `threshold` count as steady.
+pub fn trend(history: &VecDeque<f64>, window: usize, threshold: f64) -> Trend {
+    let window = window.max(1).min(history.len() / 2);
+    if window == 0 {
+        return Trend::Steady;
+    }
+    let mut recent = history.iter().rev();
+    let now: f64 = recent.by_ref().take(window).sum::<f64>() / window as f64;
+    let before: f64 = recent.take(window).sum::<f64>() / window as f64;
+    if now - before > threshold {
+        Trend::Rising
+    } else if before - now > threshold {
+        Trend::Falling
+    } else {
+        Trend::Steady
+    }
+}
+
+/// Colors are on unless `NO_COLOR` is set to a non-empty value (no-color.org).
+fn colors_from_env() -> bool {
+    !std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Whether a Linux process is a kernel thread: a bracketed name, or
+/// ancestry that leads to kthreadd (PID 2).
+fn is_kernel_thread(pid: u32, name: &str, ppids: &HashMap<u32, u32>) -> bool {
+    if name.starts_with('[') && name.ends_with(']') {
+        return true;
+    }
+    let mut cur = pid;
+    // Bounded walk guards against PPID cycles from racing refreshes.
+    for _ in 0..64 {
+        if cur == 2 {
+            return true;
+        }
+        match ppids.get(&cur) {
+            Some(&ppid) if ppid != 0 && ppid != cur => cur = ppid,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Number of entries in `/proc/<pid>/fd` (Linux; None elsewhere or when the
+/// directory can't be listed, e.g. another user's process).
+fn read_fd_count(pid: u32) -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_dir(format!("/proc/{pid}/fd"))
+            .ok()
+            .map(|entries| entries.count())
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Nice value from `/proc/<pid>/stat` (Linux; None elsewhere or when the
+/// process has gone).
+fn read_nice(pid: u32) -> Option<i32> {
+    #[cfg(target_os = "linux")]
+    {
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+        parse_stat_nice(&stat)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Field 19 (nice) of a `/proc/<pid>/stat` line. The command name (field 2)
+/// may contain spaces and parentheses, so fields are counted from its
+/// closing parenthesis.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_stat_nice(stat: &str) -> Option<i32> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    rest.split_whitespace().nth(16)?.parse().ok()
+}
+
+/// Set a process's nice value with setpriority(2).
+fn set_nice(pid: u32, nice: i32) -> std::io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        // SAFETY: setpriority takes plain integers and touches no memory of ours.
+        let rc = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid, nice) };
+        if rc == 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (pid, nice);
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// Swapped-out size from the `VmSwap` line of `/proc/<pid>/status` (Linux;
+/// None elsewhere, for kernel threads, or when the file can't be read).
+fn read_vm_swap(pid: u32) -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        parse_vm_swap(&status)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Bytes from a `VmSwap:     1234 kB` status line.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_vm_swap(status: &str) -> Option<u64> {
+    let line = status.lines().find_map(|l| l.strip_prefix("VmSwap:"))?;
+    let kb: u64 = line.split_whitespace().next()?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Whether CPU or memory moved enough between two samples to show in the
+/// change feed.
+fn process_moved(old: &ProcessInfo, new: &ProcessInfo) -> bool {
+    let mem_delta = old.memory_bytes.abs_diff(new.memory_bytes) as f64;
+    (new.cpu_usage - old.cpu_usage).abs() >= DIFF_CPU_POINTS
+        || mem_delta >= old.memory_bytes.max(1) as f64 * DIFF_MEM_RATIO
+}
+
+/// Container or unit label for a process from `/proc/<pid>/cgroup` (Linux;
+/// empty elsewhere or when the process has gone).
+fn read_cgroup_label(pid: u32) -> String {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string(format!("/proc/{pid}/cgroup"))
+            .map(|contents| cgroup_label(&contents))
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        String::new()
+    }
+}
+
+/// Short label from a cgroup file: a 12-char container ID for Docker,
+/// Podman, containerd and CRI-O scopes, otherwise the innermost systemd
+/// `.service` / `.scope` unit. Prefers the cgroup v2 (`0::`) line.
+fn cgroup_label(contents: &str) -> String {
+    let path = contents
+        .lines()
+        .find_map(|l| l.strip_prefix("0::"))
+        .or_else(|| {
+            contents
+                .lines()
+                .last()
+                .and_then(|l| l.splitn(3, ':').nth(2))
+        })
+        .unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    for (i, seg) in segments.iter().enumerate().rev() {
+        let unit = seg.strip_suffix(".scope").unwrap_or(seg);
+        let id = ["docker-", "libpod-", "cri-containerd-", "crio-"]
+            .iter()
+            .find_map(|prefix| unit.strip_prefix(prefix))
+            // cgroup v1 / cgroupfs driver: /docker/<id>
+            .or_else(|| (i > 0 && segments[i - 1] == "docker").then_some(*seg));
+        if let Some(id) =
+            id.filter(|id| id.len() >= 12 && id.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return id[..12].to_string();
+        }
+    }
+    segments
+        .iter()
+        .rev()
+        .find(|s| s.ends_with(".service") || s.ends_with(".scope"))
+        .map(|s| s.to_string())
+        .unwrap_or_default()
+}
+
+/// Approximate decoded size of a gallery entry (all frames).
+fn waifu_entry_bytes(entry: &WaifuEntry) -> usize {
+    entry.image.as_bytes().len()
+        + entry
+            .frames
+            .iter()
+            .map(|f| f.image.as_bytes().len())
+            .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
     use crate::config::TuiConfig;
+    use crate::ui::testing::{iface, process};
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     fn key(code: KeyCode) -> KeyEvent {
@@ -1078,18 +3541,7 @@ mod tests {
 
     fn make_procs(n: usize) -> Vec<ProcessInfo> {
         (0..n)
-            .map(|i| ProcessInfo {
-                pid: i as u32,
-                ppid: 0,
-                name: format!("p{i}"),
-                cmd: String::new(),
-                user: String::new(),
-                cpu_usage: 0.0,
-                memory_bytes: 0,
-                state: ProcessState::Run,
-                run_time_secs: 0,
-                tree_depth: 0,
-            })
+            .map(|i| process(i as u32, &format!("p{i}")))
             .collect()
     }
 
@@ -1122,6 +3574,29 @@ mod tests {
         assert_eq!(app.active_tab, Tab::Dashboard);
     }
 
+    #[test]
+    fn test_configured_tab_order() {
+        let mut cfg = TuiConfig::default();
+        cfg.general.tab_order = vec!["system".into(), "dashboard".into(), "network".into()];
+        let mut app = App::test_new(cfg);
+        // No default_tab: start on the first configured tab.
+        assert_eq!(app.active_tab, Tab::System);
+        app.handle_key(key(KeyCode::Tab));
+        assert_eq!(app.active_tab, Tab::Dashboard);
+        app.handle_key(char_key('1'));
+        assert_eq!(app.active_tab, Tab::System);
+        app.handle_key(key(KeyCode::BackTab));
+        assert_eq!(app.active_tab, Tab::Build);
+        // Unlisted tabs follow in built-in order.
+        app.handle_key(char_key('4'));
+        assert_eq!(app.active_tab, Tab::Billing);
+
+        let mut cfg = TuiConfig::default();
+        cfg.general.tab_order = vec!["system".into()];
+        cfg.general.default_tab = "network".into();
+        assert_eq!(App::test_new(cfg).active_tab, Tab::Network);
+    }
+
     #[test]
     fn test_tab_prev_wraps() {
         let mut app = App::test_new(TuiConfig::default());
@@ -1176,6 +3651,56 @@ mod tests {
         assert_eq!(app.process_filter, "a");
     }
 
+    // --- Quit ---
+
+    #[test]
+    fn test_q_quits_immediately_by_default() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.handle_key(char_key('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_confirm_quit_requires_double_q() {
+        let mut cfg = TuiConfig::default();
+        cfg.general.confirm_quit = true;
+        let mut app = App::test_new(cfg);
+        app.handle_key(char_key('q'));
+        assert!(!app.should_quit);
+        assert!(app.quit_pending());
+        app.handle_key(char_key('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_confirm_quit_cancelled_by_other_key() {
+        let mut cfg = TuiConfig::default();
+        cfg.general.confirm_quit = true;
+        let mut app = App::test_new(cfg);
+        app.handle_key(char_key('q'));
+        app.handle_key(char_key('j'));
+        assert!(!app.quit_pending());
+        app.handle_key(char_key('q'));
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_filter_mode_consumes_q() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.handle_key(char_key('/'));
+        app.handle_key(char_key('q'));
+        assert!(!app.should_quit);
+        assert_eq!(app.process_filter, "q");
+    }
+
+    #[test]
+    fn test_esc_in_expand_mode_does_not_quit() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.expanded = Some(WidgetId::Waifu);
+        app.handle_key(key(KeyCode::Esc));
+        assert!(!app.should_quit);
+    }
+
     // --- Help Overlay ---
 
     #[test]
@@ -1217,30 +3742,75 @@ mod tests {
     #[test]
     fn test_expanded_mode_esc_exits() {
         let mut app = App::test_new(TuiConfig::default());
-        app.expanded = true;
+        app.expanded = Some(WidgetId::Waifu);
         app.handle_key(key(KeyCode::Esc));
-        assert!(!app.expanded);
+        assert!(app.expanded.is_none());
     }
 
     #[test]
-    fn test_expanded_mode_ignores_tab() {
+    fn test_expanded_tab_key_keeps_active_tab() {
         let mut app = App::test_new(TuiConfig::default());
-        app.expanded = true;
+        app.expanded = Some(WidgetId::Waifu);
         app.handle_key(key(KeyCode::Tab));
-        assert!(app.expanded);
+        assert!(app.expanded.is_some());
         assert_eq!(app.active_tab, Tab::Dashboard);
     }
 
     #[test]
     fn test_expanded_mode_waifu_keys() {
         let mut app = App::test_new(TuiConfig::default());
-        app.expanded = true;
+        app.expanded = Some(WidgetId::Waifu);
         app.handle_key(char_key('i'));
         assert!(app.waifu_show_info);
         app.handle_key(char_key('i'));
         assert!(!app.waifu_show_info);
     }
 
+    #[test]
+    fn test_enter_zooms_and_cycles_tab_widgets() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(5));
+        app.active_tab = Tab::System;
+        // Enter zooms the focused widget, not the tab's first one.
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.expanded, Some(WidgetId::Processes));
+        // The zoomed process table keeps its scroll keys.
+        app.handle_key(char_key('j'));
+        assert_eq!(app.process_scroll, 1);
+        app.handle_key(key(KeyCode::Tab));
+        assert_eq!(app.expanded, Some(WidgetId::Network));
+        app.handle_key(key(KeyCode::Tab));
+        assert_eq!(app.expanded, Some(WidgetId::CpuCores));
+        app.handle_key(key(KeyCode::Esc));
+        assert!(app.expanded.is_none());
+        assert!(!app.should_quit);
+
+        // No focusable widget on Billing: its first widget.
+        app.active_tab = Tab::Billing;
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.expanded, Some(WidgetId::ClaudePersonal));
+    }
+
+    #[test]
+    fn test_zoomed_tailscale_keeps_its_keys() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::Network;
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.expanded, Some(WidgetId::Tailscale));
+        app.handle_key(char_key('F'));
+        assert!(app.ts_show_fqdn);
+        app.handle_key(char_key('B'));
+        assert!(app.ts_raw_bytes);
+    }
+
+    #[test]
+    fn test_widget_ids_round_trip() {
+        for &w in WidgetId::ALL {
+            assert_eq!(WidgetId::parse(w.id()), Some(w));
+            assert!(WidgetId::for_tab(w.home_tab(), true).contains(&w));
+        }
+        assert_eq!(WidgetId::parse("nope"), None);
+    }
+
     // --- wants_waifu / has_waifu ---
 
     #[test]
@@ -1292,6 +3862,32 @@ mod tests {
         assert!(app.waifu_fetching);
     }
 
+    #[tokio::test]
+    async fn test_drain_waifu_fetch() {
+        let mut app = App::test_new(TuiConfig::default());
+        // Nothing in flight: no wait at all.
+        let start = Instant::now();
+        app.drain_waifu_fetch(Duration::from_secs(5)).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        // A fetch that finishes inside the window ends the wait early.
+        app.waifu_fetching = true;
+        let tx = app.waifu_fetch_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let _ = tx.send(None).await;
+        });
+        let start = Instant::now();
+        app.drain_waifu_fetch(Duration::from_secs(5)).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(!app.waifu_fetching);
+
+        // A stuck fetch is abandoned after the limit.
+        app.waifu_fetching = true;
+        app.drain_waifu_fetch(Duration::from_millis(20)).await;
+        assert!(!app.waifu_fetching);
+    }
+
     #[test]
     fn test_system_tab_n_is_sort() {
         let mut cfg = TuiConfig::default();
@@ -1312,6 +3908,123 @@ mod tests {
         assert_eq!(app.process_sort, ProcessSort::Name);
     }
 
+    // --- Process Columns ---
+
+    #[test]
+    fn test_process_columns_default_when_empty() {
+        assert_eq!(
+            ProcessColumn::parse_list(&[]),
+            ProcessColumn::DEFAULT.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_process_columns_order_and_unknown() {
+        let names: Vec<String> = ["cpu", "bogus", "pid", "cpu"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            ProcessColumn::parse_list(&names),
+            vec![ProcessColumn::Cpu, ProcessColumn::Pid]
+        );
+    }
+
+    #[test]
+    fn test_process_columns_all_unknown_falls_back() {
+        let names = vec!["nope".to_string()];
+        assert_eq!(
+            ProcessColumn::parse_list(&names),
+            ProcessColumn::DEFAULT.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_initial_sort_follows_visible_columns() {
+        use ProcessColumn::*;
+        assert_eq!(
+            ProcessColumn::initial_sort(ProcessColumn::DEFAULT),
+            ProcessSort::Cpu
+        );
+        assert_eq!(
+            ProcessColumn::initial_sort(&[State, Mem, Pid]),
+            ProcessSort::Memory
+        );
+        assert_eq!(ProcessColumn::initial_sort(&[User, Time]), ProcessSort::Cpu);
+
+        let mut cfg = TuiConfig::default();
+        cfg.display.process_columns = vec!["pid".into(), "name".into(), "mem".into()];
+        assert_eq!(App::test_new(cfg).process_sort, ProcessSort::Pid);
+    }
+
+    #[test]
+    fn test_session_net_baseline_rebases_after_reset() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.update_session_net_baseline(&[iface("eth0", 5000, 800)]);
+        app.update_session_net_baseline(&[iface("eth0", 6000, 900), iface("wg0", 70, 80)]);
+        assert_eq!(app.session_net_baseline["eth0"], (5000, 800));
+        assert_eq!(app.session_net_baseline["wg0"], (70, 80));
+
+        // eth0's rx counter reset: traffic since then counts again.
+        app.update_session_net_baseline(&[iface("eth0", 300, 950)]);
+        assert_eq!(app.session_net_baseline["eth0"], (0, 800));
+    }
+
+    // --- Focus Routing ---
+
+    #[test]
+    fn test_focus_target_per_tab() {
+        let mut app = App::test_new(TuiConfig::default()).with_waifu_enabled();
+        assert_eq!(app.focus_target(), FocusTarget::Waifu);
+        app.active_tab = Tab::System;
+        assert_eq!(app.focus_target(), FocusTarget::Processes);
+        app.active_tab = Tab::Network;
+        assert_eq!(app.focus_target(), FocusTarget::Tailscale);
+    }
+
+    #[test]
+    fn test_network_tab_fqdn_toggle() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(3));
+        app.active_tab = Tab::Network;
+        app.handle_key(char_key('F'));
+        assert!(app.ts_show_fqdn);
+        // Process keys still fall through.
+        app.handle_key(char_key('j'));
+        assert_eq!(app.process_scroll, 1);
+        app.handle_key(char_key('F'));
+        assert!(!app.ts_show_fqdn);
+        app.handle_key(char_key('B'));
+        assert!(app.ts_raw_bytes);
+    }
+
+    #[tokio::test]
+    async fn test_ip_version_toggle_on_network_tab() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::Network;
+        assert_eq!(app.ip_version, IpVersion::V4);
+        app.handle_key(char_key('6'));
+        assert_eq!(app.ip_version, IpVersion::V6);
+        app.handle_key(char_key('6'));
+        assert_eq!(app.ip_version, IpVersion::V4);
+    }
+
+    #[test]
+    fn test_focus_target_dashboard_without_waifu() {
+        let app = App::test_new(TuiConfig::default());
+        assert_eq!(app.focus_target(), FocusTarget::Processes);
+    }
+
+    #[test]
+    fn test_waifu_focus_falls_through_to_process_keys() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_processes(make_procs(5));
+        app.handle_key(char_key('m'));
+        assert_eq!(app.process_sort, ProcessSort::Memory);
+        app.handle_key(char_key('j'));
+        assert_eq!(app.process_scroll, 1);
+    }
+
     // --- Process Scroll & Sort ---
 
     #[test]
@@ -1337,32 +4050,36 @@ mod tests {
         assert_eq!(app.refresh_ms, 5000);
     }
 
+    #[test]
+    fn test_refresh_rate_configured_bounds() {
+        let mut cfg = TuiConfig::default();
+        cfg.general.min_refresh_ms = Some(2000);
+        cfg.general.max_refresh_ms = Some(30_000);
+        let mut app = App::test_new(cfg);
+        assert_eq!(app.refresh_ms, 2000, "start clamped to the floor");
+        app.handle_key(char_key('+'));
+        assert_eq!(app.refresh_ms, 2000);
+        for _ in 0..12 {
+            app.handle_key(char_key('-'));
+        }
+        assert_eq!(app.refresh_ms, 5000);
+        app.handle_key(char_key('-'));
+        assert_eq!(app.refresh_ms, 10_000);
+        for _ in 0..10 {
+            app.handle_key(char_key('-'));
+        }
+        assert_eq!(app.refresh_ms, 30_000);
+        app.handle_key(char_key('+'));
+        assert_eq!(app.refresh_ms, 25_000);
+    }
+
     #[test]
     fn test_build_tree_parent_child() {
         let procs = vec![
+            process(1, "init"),
             ProcessInfo {
-                pid: 1,
-                ppid: 0,
-                name: "init".into(),
-                cmd: String::new(),
-                user: String::new(),
-                cpu_usage: 0.0,
-                memory_bytes: 0,
-                state: ProcessState::Run,
-                run_time_secs: 0,
-                tree_depth: 0,
-            },
-            ProcessInfo {
-                pid: 2,
                 ppid: 1,
-                name: "child".into(),
-                cmd: String::new(),
-                user: String::new(),
-                cpu_usage: 0.0,
-                memory_bytes: 0,
-                state: ProcessState::Run,
-                run_time_secs: 0,
-                tree_depth: 0,
+                ..process(2, "child")
             },
         ];
         let tree = App::build_tree(procs);
@@ -1380,86 +4097,1089 @@ mod tests {
                 image: image::DynamicImage::new_rgb8(1, 1),
                 name: format!("waifu_{i}"),
                 hash: format!("hash_{i}"),
+                frames: Vec::new(),
             })
             .collect()
     }
 
+    fn make_animated_gallery() -> Vec<WaifuEntry> {
+        let frames = (0..3)
+            .map(|_| data::waifu::WaifuFrame {
+                image: image::DynamicImage::new_rgb8(1, 1),
+                delay: Duration::from_millis(50),
+            })
+            .collect();
+        vec![WaifuEntry {
+            image: image::DynamicImage::new_rgb8(1, 1),
+            name: "animated".into(),
+            hash: "anim".into(),
+            frames,
+        }]
+    }
+
     #[tokio::test]
-    async fn test_navigate_empty_noop() {
-        let mut app = App::test_new(TuiConfig::default());
-        app.waifu_navigate(1);
-        assert_eq!(app.waifu_index, -1);
+    async fn test_waifu_animation_advances_frames() {
+        let mut cfg = TuiConfig::default();
+        cfg.image.animate = true;
+        let mut app = App::test_new(cfg).with_waifu_gallery(make_animated_gallery());
+        app.waifu_load_at(0);
+        assert_eq!(app.waifu_frame, 0);
+        // Off screen: no wakeups and no frame changes.
+        assert!(app.next_frame_in().is_none());
+        app.waifu_frame_at = Instant::now() - Duration::from_millis(60);
+        app.advance_waifu_frame();
+        assert_eq!(app.waifu_frame, 0);
+
+        app.waifu_rect = Some(Rect::new(0, 0, 20, 10));
+        assert!(app.next_frame_in().is_some());
+        app.advance_waifu_frame();
+        assert_eq!(app.waifu_frame, 1);
+        // Each frame is scaled once and reused on the next loop.
+        assert!(app.waifu_scaled_frames[1].is_some());
+        assert!(app.waifu_scaled_frames[2].is_none());
+
+        // Not due yet: stays on the same frame.
+        app.advance_waifu_frame();
+        assert_eq!(app.waifu_frame, 1);
+
+        // Wraps around after the last frame.
+        app.waifu_frame = 2;
+        app.waifu_frame_at = Instant::now() - Duration::from_millis(60);
+        app.advance_waifu_frame();
+        assert_eq!(app.waifu_frame, 0);
     }
 
     #[tokio::test]
-    async fn test_navigate_forward_through_gallery() {
-        let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(3));
-        assert_eq!(app.waifu_index, 0);
-        app.waifu_navigate(1);
-        assert_eq!(app.waifu_index, 1);
-        assert_eq!(app.waifu_name, "waifu_1");
-        app.waifu_navigate(1);
-        assert_eq!(app.waifu_index, 2);
-        assert_eq!(app.waifu_name, "waifu_2");
+    async fn test_waifu_animation_disabled_by_config() {
+        let mut app =
+            App::test_new(TuiConfig::default()).with_waifu_gallery(make_animated_gallery());
+        assert!(app.next_frame_in().is_none());
+        app.waifu_frame_at = Instant::now() - Duration::from_millis(60);
+        app.advance_waifu_frame();
+        assert_eq!(app.waifu_frame, 0);
     }
 
     #[tokio::test]
-    async fn test_navigate_wraps_forward() {
-        let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(3));
-        app.waifu_index = 2;
-        app.waifu_navigate(1);
-        assert_eq!(app.waifu_index, 0);
-        assert_eq!(app.waifu_name, "waifu_0");
+    async fn test_waifu_duplicate_fetch_skips_decoding() {
+        // Not a decodable image: only the byte hash can match it.
+        let bytes = b"same bytes as before".to_vec();
+        let mut gallery = make_gallery(3);
+        gallery[0].hash = data::waifu::content_hash(&bytes);
+        let mut app = App::test_new(TuiConfig::default()).with_waifu_gallery(gallery);
+        app.waifu_load_at(2);
+
+        app.waifu_fetch_tx
+            .try_send(Some(FetchResult {
+                data: bytes,
+                name: "again".into(),
+            }))
+            .unwrap();
+        app.poll_waifu_fetch();
+        assert_eq!(app.waifu_gallery.len(), 3);
+        assert_eq!(app.waifu_index, 0, "jumped to the existing copy");
     }
 
     #[tokio::test]
-    async fn test_navigate_wraps_backward() {
-        let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(3));
+    async fn test_waifu_gallery_eviction_keeps_current() {
+        let mut cfg = TuiConfig::default();
+        cfg.collectors.waifu.max_images = 3;
+        let mut app = App::test_new(cfg).with_waifu_gallery(make_gallery(3));
+        app.waifu_load_at(2);
+
+        app.waifu_gallery
+            .extend(make_gallery(5).into_iter().skip(3));
+        app.evict_waifu_overflow();
+        assert_eq!(app.waifu_gallery.len(), 3);
+        // Oldest entries went first; the current image is still selected.
+        assert_eq!(app.waifu_gallery[app.waifu_index as usize].name, "waifu_2");
         assert_eq!(app.waifu_index, 0);
-        app.waifu_navigate(-1);
-        assert_eq!(app.waifu_index, 2);
-        assert_eq!(app.waifu_name, "waifu_2");
     }
 
     #[tokio::test]
-    async fn test_navigate_single_item_stays() {
-        let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(1));
-        assert_eq!(app.waifu_index, 0);
-        app.waifu_navigate(1);
+    async fn test_waifu_gallery_eviction_skips_current_oldest() {
+        let mut cfg = TuiConfig::default();
+        cfg.collectors.waifu.max_images = 3;
+        let mut app = App::test_new(cfg).with_waifu_gallery(make_gallery(4));
         assert_eq!(app.waifu_index, 0);
-        app.waifu_navigate(-1);
+        app.evict_waifu_overflow();
+        assert_eq!(app.waifu_gallery.len(), 3);
         assert_eq!(app.waifu_index, 0);
+        assert_eq!(app.waifu_gallery[0].name, "waifu_0");
+        assert_eq!(app.waifu_gallery[1].name, "waifu_2");
+    }
+
+    fn named_procs(names: &[&str]) -> Vec<ProcessInfo> {
+        let mut procs = make_procs(names.len());
+        for (p, name) in procs.iter_mut().zip(names) {
+            p.name = name.to_string();
+        }
+        procs
     }
 
     #[tokio::test]
-    async fn test_waifu_key_n_navigates_on_dashboard() {
-        let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(3));
-        // Load initial image so has_waifu() is true.
-        app.waifu_load_at(0);
-        app.active_tab = Tab::Dashboard;
-        assert!(app.has_waifu());
-        app.handle_key(char_key('n'));
-        assert_eq!(app.waifu_index, 1);
-        app.handle_key(char_key('p'));
-        assert_eq!(app.waifu_index, 0);
+    async fn test_type_to_jump_cycles_matches() {
+        let mut cfg = TuiConfig::default();
+        cfg.keys.type_to_jump = true;
+        let mut app = App::test_new(cfg)
+            .with_processes(named_procs(&["bash", "cargo", "Chrome", "zsh", "cron"]));
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('c')));
+        assert_eq!(app.process_scroll, 1);
+        app.handle_key(key(KeyCode::Char('c')));
+        assert_eq!(app.process_scroll, 2);
+        app.handle_key(key(KeyCode::Char('c')));
+        assert_eq!(app.process_scroll, 4);
+        // Wraps back to the first match.
+        app.handle_key(key(KeyCode::Char('c')));
+        assert_eq!(app.process_scroll, 1);
+        // Letters no longer change sort order.
+        assert_eq!(app.process_sort, ProcessSort::Cpu);
+        app.handle_key(key(KeyCode::Char('m')));
+        assert_eq!(app.process_sort, ProcessSort::Cpu);
+        // No match leaves selection alone.
+        app.handle_key(key(KeyCode::Char('x')));
+        assert_eq!(app.process_scroll, 1);
     }
 
     #[tokio::test]
-    async fn test_waifu_random_selects_from_gallery() {
+    async fn test_type_to_jump_off_by_default() {
         let mut app = App::test_new(TuiConfig::default())
-            .with_waifu_enabled()
-            .with_waifu_gallery(make_gallery(5));
-        app.waifu_load_at(0);
+            .with_processes(named_procs(&["bash", "cargo", "zsh"]));
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('m')));
+        assert_eq!(app.process_sort, ProcessSort::Memory);
+        assert_eq!(app.process_scroll, 0);
+    }
+
+    #[test]
+    fn test_is_kernel_thread() {
+        let ppids = HashMap::from([(1, 0), (2, 0), (40, 2), (41, 40), (500, 1), (501, 500)]);
+        assert!(is_kernel_thread(2, "kthreadd", &ppids));
+        assert!(is_kernel_thread(40, "kworker/0:1", &ppids));
+        assert!(is_kernel_thread(41, "kworker/u8:2", &ppids));
+        assert!(is_kernel_thread(999, "[migration/0]", &ppids));
+        assert!(!is_kernel_thread(1, "systemd", &ppids));
+        assert!(!is_kernel_thread(501, "bash", &ppids));
+        // Cycles terminate.
+        let cyclic = HashMap::from([(7, 8), (8, 7)]);
+        assert!(!is_kernel_thread(7, "loop", &cyclic));
+    }
+
+    #[tokio::test]
+    async fn test_kernel_thread_toggle() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(3));
+        app.active_tab = Tab::System;
+        assert!(!app.show_kernel_threads);
+        app.handle_key(key(KeyCode::Char('K')));
+        assert!(app.show_kernel_threads);
+    }
+
+    #[tokio::test]
+    async fn test_signal_menu_navigation() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(3));
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('s')));
+        assert_eq!(app.signal_menu, Some(0));
+        app.handle_key(key(KeyCode::Char('k')));
+        assert_eq!(app.signal_menu, Some(SIGNAL_MENU.len() - 1));
+        app.handle_key(key(KeyCode::Char('j')));
+        app.handle_key(key(KeyCode::Char('j')));
+        assert_eq!(app.signal_menu, Some(1));
+        // Menu swallows q instead of quitting.
+        app.handle_key(key(KeyCode::Char('q')));
+        assert_eq!(app.signal_menu, None);
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_signal_menu_reports_missing_process() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(3));
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('s')));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.signal_menu, None);
+        let msg = app.status_message().unwrap();
+        assert!(msg.contains("no longer exists"), "{msg}");
+    }
+
+    #[tokio::test]
+    async fn test_kill_warns_before_signalling_other_users() {
+        let mut procs = make_procs(2);
+        procs[0].user = "root".into();
+        procs[1].user = "alice".into();
+        // Stay clear of PID 1, which kill refuses outright.
+        procs[1].pid = 2;
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.current_user = "alice".into();
+        app.active_tab = Tab::System;
+
+        app.handle_key(key(KeyCode::Char('D')));
+        let msg = app.status_message().unwrap();
+        assert!(msg.contains("belongs to root"), "{msg}");
+        assert_eq!(app.foreign_kill_warned, Some(0));
+
+        // Repeating goes ahead (the test process list has no live PIDs).
+        app.handle_key(key(KeyCode::Char('D')));
+        assert!(app.status_message().unwrap().contains("no longer exists"));
+        assert_eq!(app.foreign_kill_warned, None);
+
+        // Own processes are signalled without a warning.
+        app.process_scroll = 1;
+        app.handle_key(key(KeyCode::Char('D')));
+        assert!(app.status_message().unwrap().contains("no longer exists"));
+    }
+
+    #[tokio::test]
+    async fn test_kill_refuses_init_and_quits_for_self() {
+        let mut procs = make_procs(2);
+        procs[0].pid = 1;
+        procs[1].pid = std::process::id();
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+
+        app.handle_key(key(KeyCode::Char('D')));
+        let msg = app.status_message().unwrap();
+        assert!(msg.contains("refusing to send SIGKILL to PID 1"), "{msg}");
+        assert!(app.kill_log.is_empty());
+        assert!(!app.should_quit);
+
+        app.process_scroll = 1;
+        app.handle_key(key(KeyCode::Char('D')));
+        assert!(app.should_quit);
+        assert!(app.kill_log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signal_menu_guards_init_and_self() {
+        let stop = SIGNAL_MENU
+            .iter()
+            .position(|(s, _)| *s == sysinfo::Signal::Stop)
+            .unwrap();
+        let mut procs = make_procs(2);
+        procs[0].pid = 1;
+        procs[1].pid = std::process::id();
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+
+        app.handle_key(key(KeyCode::Char('s')));
+        app.signal_menu = Some(stop);
+        app.handle_key(key(KeyCode::Enter));
+        let msg = app.status_message().unwrap();
+        assert!(msg.contains("refusing to send SIGSTOP to PID 1"), "{msg}");
+        assert!(!app.should_quit);
+
+        app.process_scroll = 1;
+        let usr1 = SIGNAL_MENU
+            .iter()
+            .position(|(s, _)| *s == sysinfo::Signal::User1)
+            .unwrap();
+        app.handle_key(key(KeyCode::Char('s')));
+        app.signal_menu = Some(usr1);
+        app.handle_key(key(KeyCode::Enter));
+        let msg = app.status_message().unwrap();
+        assert!(
+            msg.contains("refusing to send SIGUSR1 to ourselves"),
+            "{msg}"
+        );
+        assert!(!app.should_quit);
+
+        app.handle_key(key(KeyCode::Char('s')));
+        app.signal_menu = Some(stop);
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_signal_menu_keeps_its_target_across_resorts() {
+        let mut procs = make_procs(3);
+        for (i, p) in procs.iter_mut().enumerate() {
+            p.pid = 10 + i as u32;
+        }
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+        app.process_scroll = 1;
+        app.handle_key(key(KeyCode::Char('s')));
+        assert_eq!(app.signal_target.as_ref().map(|t| t.pid), Some(11));
+
+        // A refresh re-sorts the list under the open menu.
+        app.processes.reverse();
+        app.process_scroll = 0;
+        app.handle_key(key(KeyCode::Enter));
+        let msg = app.status_message().unwrap();
+        assert!(msg.contains("process 11 no longer exists"), "{msg}");
+        assert!(app.signal_target.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_signal_menu_needs_processes() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('s')));
+        assert_eq!(app.signal_menu, None);
+    }
+
+    #[test]
+    fn test_signal_names() {
+        assert_eq!(signal_name(sysinfo::Signal::Hangup), "SIGHUP");
+        assert_eq!(signal_name(sysinfo::Signal::Continue), "SIGCONT");
+    }
+
+    #[tokio::test]
+    async fn test_spinner_advances_on_tick() {
+        let mut app = App::test_new(TuiConfig::default());
+        let first = app.spinner_char();
+        app.tick().await;
+        assert_eq!(app.spinner_frame, 1);
+        assert_ne!(app.spinner_char(), first);
+        app.spinner_frame = SPINNER_FRAMES.len();
+        assert_eq!(app.spinner_char(), first);
+    }
+
+    #[test]
+    fn test_sort_processes_stable_ties() {
+        let tied = || {
+            let mut procs = make_procs(4);
+            for p in procs.iter_mut() {
+                p.cpu_usage = 1.5;
+                p.memory_bytes = 4096;
+                p.name = "worker".into();
+            }
+            procs
+        };
+        let pids = |v: &[ProcessInfo]| v.iter().map(|p| p.pid).collect::<Vec<_>>();
+        let no_swap = HashMap::new();
+        for sort in [
+            ProcessSort::Cpu,
+            ProcessSort::Memory,
+            ProcessSort::Name,
+            ProcessSort::Swap,
+        ] {
+            // Shuffled input (as from the HashMap) always comes out PID-ordered.
+            let mut a = tied();
+            a.reverse();
+            a.swap(0, 2);
+            let mut b = tied();
+            App::sort_processes(&mut a, sort, &no_swap);
+            App::sort_processes(&mut b, sort, &no_swap);
+            assert_eq!(pids(&a), pids(&b), "{sort:?}");
+            assert!(pids(&a).windows(2).all(|w| w[0] < w[1]), "{sort:?}");
+            // Re-sorting is a no-op.
+            App::sort_processes(&mut a, sort, &no_swap);
+            assert_eq!(pids(&a), pids(&b), "{sort:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_process_tracking() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.baseline_pids = HashSet::from([1, 2]);
+        let now = Instant::now();
+        app.track_new_pids(&HashSet::from([1, 2, 300]), now);
+        assert!(app.is_new_process(300));
+        assert!(!app.is_new_process(1));
+
+        // First-seen time isn't reset on later refreshes.
+        app.track_new_pids(&HashSet::from([1, 2, 300]), now + Duration::from_secs(5));
+        assert_eq!(app.first_seen[&300], now);
+
+        // Highlight expires after the window.
+        app.first_seen.insert(300, now - NEW_PROCESS_WINDOW);
+        assert!(!app.is_new_process(300));
+
+        // Exited processes are forgotten.
+        app.track_new_pids(&HashSet::from([1, 2]), now);
+        assert!(app.first_seen.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_about_popup_toggle() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::Billing;
+        app.handle_key(key(KeyCode::Char('v')));
+        assert!(app.show_about);
+        // Any key closes it without acting (q doesn't quit).
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(!app.show_about);
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_kill_log_capped_and_popup() {
+        let mut app = App::test_new(TuiConfig::default());
+        for pid in 0..(KILL_LOG_LEN as u32 + 5) {
+            app.record_kill(pid, format!("p{pid}"), pid % 2 == 0);
+        }
+        assert_eq!(app.kill_log.len(), KILL_LOG_LEN);
+        assert_eq!(app.kill_log[0].1, 5);
+        assert_eq!(
+            app.kill_log.last().unwrap().2,
+            format!("p{}", KILL_LOG_LEN + 4)
+        );
+
+        app.handle_key(key(KeyCode::Char('L')));
+        assert!(app.show_kill_log);
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(!app.show_kill_log);
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_daemon_down_since_tracks_empty_reads() {
+        let mut app = App::test_new(TuiConfig::default());
+        assert_eq!(app.daemon_silence(), None);
+        let start = Instant::now();
+        app.update_daemon_down(start);
+        assert_eq!(app.daemon_down_since, Some(start));
+        // Later empty sweeps keep the original start time.
+        app.update_daemon_down(start + Duration::from_secs(5));
+        assert_eq!(app.daemon_down_since, Some(start));
+        let (_, retry_in) = app.daemon_silence().unwrap();
+        assert!(retry_in <= CACHE_POLL_INTERVAL);
+
+        app.k8s = serde_json::from_str(r#"{"clusters": []}"#).ok();
+        app.update_daemon_down(start + Duration::from_secs(10));
+        assert_eq!(app.daemon_down_since, None);
+    }
+
+    #[tokio::test]
+    async fn test_force_cache_reread_reports_sources() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(3));
+        app.handle_key(char_key('R'));
+        let status = app.status_msg.as_ref().map(|(m, _)| m.clone());
+        assert_eq!(
+            status.as_deref(),
+            Some("daemon cache re-read: no data (is the daemon running?)")
+        );
+        assert!(app.daemon_down_since.is_some());
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("k8s.json"), r#"{"clusters": []}"#).unwrap();
+        app.cache_reader = CacheReader::new(tmp.path().to_path_buf());
+        app.handle_key(char_key('R'));
+        let status = app.status_msg.as_ref().map(|(m, _)| m.clone()).unwrap();
+        assert_eq!(
+            status,
+            "daemon cache re-read: updated k8s; missing tailscale, claude, billing, claude-personal"
+        );
+        assert!(app.k8s.is_some());
+        // Re-reading the same file again is not news.
+        app.handle_key(char_key('R'));
+        let status = app.status_msg.as_ref().map(|(m, _)| m.clone()).unwrap();
+        assert_eq!(
+            status,
+            "daemon cache re-read: no updates; unchanged k8s; missing tailscale, claude, billing, claude-personal"
+        );
+        assert!(app.daemon_down_since.is_none());
+        // The process list is left as it was.
+        assert_eq!(app.processes.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_needs_a_rewritten_file() {
+        let mut app = App::test_new(TuiConfig::default());
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("k8s.json");
+        std::fs::write(&path, r#"{"clusters": []}"#).unwrap();
+        let touch = |age| {
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(SystemTime::now() - Duration::from_secs(age))
+                .unwrap();
+        };
+        touch(20);
+        app.cache_reader = CacheReader::new(tmp.path().to_path_buf());
+
+        assert!(app.reload_cache_source("k8s"));
+        assert!(app.last_successful_read.is_some());
+
+        // Same file, read again: data, but no heartbeat.
+        app.last_successful_read = None;
+        assert!(app.reload_cache_source("k8s"));
+        assert!(app.last_successful_read.is_none());
+
+        // The daemon rewrites it.
+        touch(10);
+        assert!(app.reload_cache_source("k8s"));
+        assert!(app.last_successful_read.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cache_watcher_starts_once_dir_appears() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path().join("prompt-pulse");
+        let mut app = App::test_new(TuiConfig::default());
+        app.cache_reader = CacheReader::new(dir.clone());
+        app.cache_watch_pending = true;
+
+        // Still missing: keep polling and keep waiting.
+        app.last_cache_read = Instant::now() - CACHE_POLL_INTERVAL;
+        app.tick().await;
+        assert!(app.cache_watch_pending);
+        assert!(app.cache_watcher.is_none());
+
+        std::fs::create_dir(&dir).unwrap();
+        app.last_cache_read = Instant::now() - CACHE_POLL_INTERVAL;
+        app.tick().await;
+        assert!(!app.cache_watch_pending);
+        let backend = CacheWatcher::new(&dir, &[]).is_some();
+        assert_eq!(app.cache_watcher.is_some(), backend);
+    }
+
+    #[tokio::test]
+    async fn test_legend_popup_toggle() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.handle_key(char_key('l'));
+        assert!(app.show_legend);
+        app.handle_key(char_key('q'));
+        assert!(!app.show_legend);
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_cache_debug_gated_and_navigable() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("billing.json"), r#"{"providers":[]}"#).unwrap();
+        std::fs::write(tmp.path().join("k8s.json"), "oops").unwrap();
+        let file = std::fs::File::create(tmp.path().join("tailscale.json.gz")).unwrap();
+        let mut gz = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        std::io::Write::write_all(&mut gz, br#"{"online":true}"#).unwrap();
+        gz.finish().unwrap();
+        let mut app = App::test_new(TuiConfig::default());
+        app.cache_reader = CacheReader::new(tmp.path().to_path_buf());
+
+        // Hidden unless --debug.
+        app.handle_key(key(KeyCode::Char('`')));
+        assert!(app.cache_debug.is_none());
+
+        app.debug_mode = true;
+        app.handle_key(key(KeyCode::Char('`')));
+        assert_eq!(app.cache_debug.as_ref().unwrap().files.len(), 3);
+        app.handle_key(key(KeyCode::Char('j')));
+        app.handle_key(key(KeyCode::Enter));
+        let view = app.cache_debug.as_ref().unwrap();
+        assert_eq!(view.selected, 1);
+        assert_eq!(view.raw.as_deref(), Some("oops"));
+
+        // Gzipped sources are shown decompressed, under their real name.
+        app.handle_key(key(KeyCode::Esc));
+        app.handle_key(key(KeyCode::Char('j')));
+        app.handle_key(key(KeyCode::Enter));
+        let view = app.cache_debug.as_ref().unwrap();
+        assert_eq!(view.files[2].file_name(), "tailscale.json.gz");
+        assert!(view.raw.as_deref().unwrap().contains(r#""online": true"#));
+
+        // Esc closes the raw view, then the inspector; q never quits here.
+        app.handle_key(key(KeyCode::Esc));
+        assert!(app.cache_debug.as_ref().unwrap().raw.is_none());
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(app.cache_debug.is_none());
+        assert!(!app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_cpu_aggregate_view_toggle() {
+        let mut cfg = TuiConfig::default();
+        cfg.display.cpu_aggregate = true;
+        let mut app = App::test_new(cfg);
+        assert!(app.cpu_aggregate_view);
+        app.handle_key(key(KeyCode::Char('U')));
+        assert!(!app.cpu_aggregate_view);
+        // Dashboard only.
+        app.active_tab = Tab::Network;
+        app.handle_key(key(KeyCode::Char('U')));
+        assert!(!app.cpu_aggregate_view);
+    }
+
+    #[tokio::test]
+    async fn test_hide_idle_interfaces_toggle() {
+        let mut cfg = TuiConfig::default();
+        cfg.display.hide_idle_interfaces = true;
+        let mut app = App::test_new(cfg);
+        assert!(app.hide_idle_interfaces);
+        app.active_tab = Tab::Network;
+        app.handle_key(key(KeyCode::Char('I')));
+        assert!(!app.hide_idle_interfaces);
+        // Only the Network tab owns the key.
+        app.active_tab = Tab::Billing;
+        app.handle_key(key(KeyCode::Char('I')));
+        assert!(!app.hide_idle_interfaces);
+    }
+
+    #[test]
+    fn test_effective_font_size_fallback() {
+        assert_eq!(effective_font_size((10, 20)), (10, 20));
+        assert_eq!(effective_font_size((0, 0)), FALLBACK_FONT_SIZE);
+        assert_eq!(effective_font_size((9, 0)), FALLBACK_FONT_SIZE);
+    }
+
+    #[tokio::test]
+    async fn test_jump_to_heaviest_process() {
+        let mut procs = named_procs(&["a", "b", "c", "d"]);
+        procs[0].cpu_usage = 5.0;
+        procs[1].memory_bytes = 900;
+        procs[2].cpu_usage = 80.0;
+        procs[3].cpu_usage = 80.0;
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+        app.process_sort = ProcessSort::Name;
+        app.handle_key(key(KeyCode::Char('C')));
+        assert_eq!(app.process_scroll, 2);
+        app.handle_key(key(KeyCode::Char('M')));
+        assert_eq!(app.process_scroll, 1);
+    }
+
+    #[test]
+    fn test_trend_direction() {
+        let rising: VecDeque<f64> = (0..10).map(|i| 70.0 + i as f64 * 2.0).collect();
+        assert_eq!(trend(&rising, 5, 1.0), Trend::Rising);
+        let falling: VecDeque<f64> = rising.iter().rev().copied().collect();
+        assert_eq!(trend(&falling, 5, 1.0), Trend::Falling);
+        let flat: VecDeque<f64> = VecDeque::from(vec![85.0, 85.2, 84.9, 85.1]);
+        assert_eq!(trend(&flat, 5, 1.0), Trend::Steady);
+        assert_eq!(trend(&VecDeque::from(vec![90.0]), 5, 1.0), Trend::Steady);
+    }
+
+    #[test]
+    fn test_take_first_run_writes_marker() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path().join("state");
+        assert!(take_first_run(&dir));
+        assert!(dir.join(FIRST_RUN_MARKER).exists());
+        assert!(!take_first_run(&dir), "only the first launch");
+    }
+
+    #[test]
+    fn test_first_run_hint_dismissed_by_any_key() {
+        let mut app = App::test_new(TuiConfig::default());
+        assert!(!app.first_run_hint());
+        app.first_run_hint_until = Some(Instant::now() + FIRST_RUN_HINT);
+        assert!(app.first_run_hint());
+        app.handle_key(char_key('j'));
+        assert!(!app.first_run_hint());
+    }
+
+    #[test]
+    fn test_title_text() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.cpu_history.push_back(23.4);
+        app.mem_history.push_back(61.0);
+        assert_eq!(app.title_text(), "prompt-pulse \u{2014} CPU 23% MEM 61%");
+        assert!(!app.set_title, "tests never write escape codes");
+    }
+
+    #[tokio::test]
+    async fn test_page_keys_use_visible_rows() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(100));
+        app.active_tab = Tab::System;
+        // Before the first draw the page is 10 rows.
+        app.handle_key(key(KeyCode::PageDown));
+        assert_eq!(app.process_scroll, 10);
+
+        app.process_visible_rows = 25;
+        app.handle_key(key(KeyCode::PageDown));
+        assert_eq!(app.process_scroll, 35);
+        app.handle_key(key(KeyCode::PageUp));
+        app.handle_key(key(KeyCode::PageUp));
+        assert_eq!(app.process_scroll, 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_fd_counts_cached_per_pid() {
+        let mut app = App::test_new(TuiConfig::default());
+        let me = std::process::id();
+        app.refresh_fd_counts(&[me, u32::MAX]);
+        assert!(app.fd_count(me).is_some_and(|n| n > 0));
+        assert_eq!(app.fd_count(u32::MAX), None, "no such process");
+        // Fresh entries aren't re-read.
+        app.fd_cache.insert(me, (Instant::now(), Some(7)));
+        app.refresh_fd_counts(&[me]);
+        assert_eq!(app.fd_count(me), Some(7));
+    }
+
+    #[test]
+    fn test_sort_by_swap() {
+        let mut procs = make_procs(3);
+        let now = Instant::now();
+        let swap = HashMap::from([
+            (procs[0].pid, (now, Some(1024))),
+            (procs[2].pid, (now, Some(8 << 20))),
+        ]);
+        let expected = vec![procs[2].pid, procs[0].pid, procs[1].pid];
+        App::sort_processes(&mut procs, ProcessSort::Swap, &swap);
+        assert_eq!(procs.iter().map(|p| p.pid).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_group_by_exe_aggregates() {
+        let mut procs = make_procs(5);
+        for (i, p) in procs.iter_mut().enumerate() {
+            p.pid = 100 + i as u32;
+            p.cpu_usage = 10.0;
+            p.memory_bytes = 1000;
+            p.user = "dev".into();
+            p.exe = if i < 3 {
+                "/opt/chrome/chrome".into()
+            } else {
+                String::new()
+            };
+        }
+        procs[2].user = "root".into();
+        let mut grouped = App::group_by_exe(procs);
+        App::sort_processes(&mut grouped, ProcessSort::Cpu, &HashMap::new());
+        assert_eq!(grouped.len(), 3);
+        let chrome = &grouped[0];
+        assert_eq!(chrome.name, "chrome");
+        assert_eq!(chrome.cmd, "/opt/chrome/chrome");
+        assert_eq!(chrome.group_count, 3);
+        assert_eq!(chrome.cpu_usage, 30.0);
+        assert_eq!(chrome.memory_bytes, 3000);
+        assert_eq!(chrome.pid, 100);
+        assert_eq!(chrome.user, "*");
+        // No exe: grouped by name, one each here.
+        assert!(grouped[1..].iter().all(|g| g.group_count == 1));
+    }
+
+    #[tokio::test]
+    async fn test_diff_mode_keeps_only_changes() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(4));
+        // Large PIDs so none of them exist on the test host.
+        for (i, p) in app.processes.iter_mut().enumerate() {
+            p.pid = 4_000_000 + i as u32;
+            p.cpu_usage = 10.0;
+            p.memory_bytes = 1000;
+        }
+        app.handle_key(char_key('N'));
+        assert!(app.diff_mode);
+        // The first sample only seeds the baseline.
+        assert!(app.diff_processes(app.processes.clone()).is_empty());
+
+        let mut next = make_procs(4);
+        for (i, p) in next.iter_mut().enumerate() {
+            p.pid = 4_000_000 + i as u32;
+            p.cpu_usage = 10.0;
+            p.memory_bytes = 1000;
+        }
+        next[0].cpu_usage = 11.0; // small wiggle: hidden
+        next[1].cpu_usage = 40.0; // CPU spike
+        next[2].memory_bytes = 1500; // memory growth
+        next[3].pid = 4_000_100; // pid 4_000_003 exited, this one is new
+
+        // Long-running but idle last sample: woke up, not new.
+        next.push(ProcessInfo {
+            pid: 4_000_200,
+            ..next[0].clone()
+        });
+        // Idle last sample and barely busy now: not worth a row.
+        next.push(ProcessInfo {
+            pid: 4_000_300,
+            cpu_usage: 0.1,
+            ..next[0].clone()
+        });
+        app.baseline_pids.extend([4_000_200, 4_000_300]);
+        let live: HashSet<u32> = next.iter().map(|p| p.pid).collect();
+        app.track_new_pids(&live, Instant::now() + Duration::from_millis(1));
+
+        let shown = app.diff_processes(next);
+        let pids: Vec<u32> = shown.iter().map(|p| p.pid).collect();
+        assert_eq!(
+            pids,
+            [4_000_001, 4_000_002, 4_000_100, 4_000_200, 4_000_003]
+        );
+        assert_eq!(app.process_changes[&4_000_001], ProcessChange::Changed);
+        assert_eq!(app.process_changes[&4_000_100], ProcessChange::New);
+        assert_eq!(app.process_changes[&4_000_200], ProcessChange::Changed);
+        assert_eq!(app.process_changes[&4_000_003], ProcessChange::Gone);
+
+        // The exited row is shown for one refresh only.
+        let again = app.diff_processes(Vec::new());
+        assert!(!again.iter().any(|p| p.pid == 4_000_003));
+
+        app.handle_key(char_key('N'));
+        assert!(!app.diff_mode);
+        assert!(app.process_changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_grouped_row_refuses_kill() {
+        let mut procs = make_procs(1);
+        procs[0].pid = 4000;
+        procs[0].group_count = 7;
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+        app.handle_key(key(KeyCode::Char('D')));
+        assert!(app.status_message().unwrap().contains("ungroup"));
+        assert!(app.kill_log.is_empty());
+        // The right-click menu's Copy PID is refused the same way.
+        app.status_msg = None;
+        app.run_process_action(ProcessAction::CopyPid);
+        assert!(app.pending_clipboard.is_none());
+        assert!(app.status_message().unwrap().contains("ungroup"));
+        app.handle_key(key(KeyCode::Char('X')));
+        assert!(app.group_by_exe);
+    }
+
+    #[test]
+    fn test_grouped_detail_restarts_graph_on_ungroup() {
+        let mut procs = make_procs(1);
+        procs[0].cpu_usage = 30.0;
+        procs[0].group_count = 3;
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+        app.handle_key(char_key('o'));
+        assert!(app.detail_grouped);
+        assert_eq!(app.detail_cpu_history, [30.0]);
+        app.handle_key(char_key('j'));
+
+        // Same representative PID, now a single process: its graph starts over.
+        app.processes[0].cpu_usage = 5.0;
+        app.processes[0].group_count = 1;
+        app.handle_key(char_key('o'));
+        assert!(!app.detail_grouped);
+        assert_eq!(app.detail_cpu_history, [5.0]);
+    }
+
+    #[test]
+    fn test_process_detail_popup() {
+        let mut procs = make_procs(2);
+        procs[1].cpu_usage = 12.5;
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.active_tab = Tab::System;
+        app.process_scroll = 1;
+        app.handle_key(char_key('o'));
+        assert!(app.show_process_detail);
+        assert_eq!(app.detail_pid, Some(1));
+        assert_eq!(app.detail_cpu_history, [12.5]);
+        // Any key closes; reopening the same process keeps its samples.
+        app.handle_key(char_key('j'));
+        assert!(!app.show_process_detail);
+        app.detail_cpu_history.push_back(20.0);
+        app.handle_key(char_key('o'));
+        assert_eq!(app.detail_cpu_history.len(), 2);
+        // A different process starts a fresh graph.
+        app.handle_key(char_key('x'));
+        app.handle_key(char_key('k'));
+        app.handle_key(char_key('o'));
+        assert_eq!(app.detail_pid, Some(0));
+        assert_eq!(app.detail_cpu_history, [0.0]);
+    }
+
+    #[test]
+    fn test_parse_stat_nice() {
+        let stat =
+            "1234 (tmux: server) S 1 1234 1234 0 -1 4194560 1 0 0 0 5 3 0 0 20 5 1 0 100 0 0";
+        assert_eq!(parse_stat_nice(stat), Some(5));
+        assert_eq!(parse_stat_nice("garbage"), None);
+    }
+
+    #[test]
+    fn test_renice_off_by_default() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(1));
+        app.active_tab = Tab::System;
+        app.handle_key(char_key('>'));
+        assert!(app.status_message().unwrap().contains("allow_renice"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_renice_child_process() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .unwrap();
+        let mut procs = make_procs(1);
+        procs[0].pid = child.id();
+        let mut cfg = TuiConfig::default();
+        cfg.keys.allow_renice = true;
+        let mut app = App::test_new(cfg).with_processes(procs);
+        app.active_tab = Tab::System;
+        let before = read_nice(child.id()).unwrap();
+        app.handle_key(char_key('>'));
+        let msg = app.status_message().unwrap().to_string();
+        child.kill().unwrap();
+        child.wait().unwrap();
+        if before < 19 {
+            assert!(msg.starts_with("reniced"), "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_parse_vm_swap() {
+        let status = "Name:\tfirefox\nVmRSS:\t  812340 kB\nVmSwap:\t    2048 kB\n";
+        assert_eq!(parse_vm_swap(status), Some(2048 * 1024));
+        // Kernel threads have no Vm* lines.
+        assert_eq!(parse_vm_swap("Name:\tkthreadd\n"), None);
+    }
+
+    #[test]
+    fn test_cgroup_label() {
+        let docker = "0::/system.slice/docker-4f9a1c2b3d4e5f60718293a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4.scope\n";
+        assert_eq!(cgroup_label(docker), "4f9a1c2b3d4e");
+        let podman = "0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-0123456789abcdef0123.scope/container\n";
+        assert_eq!(cgroup_label(podman), "0123456789ab");
+        let v1 = "12:pids:/docker/abcdef0123456789abcdef\n1:name=systemd:/docker/abcdef0123456789abcdef\n";
+        assert_eq!(cgroup_label(v1), "abcdef012345");
+        let unit = "0::/system.slice/nginx.service\n";
+        assert_eq!(cgroup_label(unit), "nginx.service");
+        assert_eq!(cgroup_label("0::/\n"), "");
+        assert_eq!(cgroup_label(""), "");
+    }
+
+    #[tokio::test]
+    async fn test_saved_filter_keys() {
+        let mut cfg = TuiConfig::default();
+        cfg.filters.saved.insert("node".into(), "node".into());
+        cfg.filters.saved.insert("docker".into(), "dockerd".into());
+        let mut app = App::test_new(cfg);
+
+        app.handle_key(key(KeyCode::F(2)));
+        assert_eq!(app.process_filter, "node");
+        assert_eq!(app.active_saved_filter.as_deref(), Some("node"));
+
+        app.handle_key(key(KeyCode::F(1)));
+        assert_eq!(app.process_filter, "dockerd");
+        assert_eq!(app.active_saved_filter.as_deref(), Some("docker"));
+
+        // Same key again clears; unbound keys do nothing.
+        app.handle_key(key(KeyCode::F(1)));
+        assert!(app.process_filter.is_empty());
+        assert!(app.active_saved_filter.is_none());
+        app.handle_key(key(KeyCode::F(9)));
+        assert!(app.process_filter.is_empty());
+
+        // Typing a filter by hand drops the saved-filter name.
+        app.handle_key(key(KeyCode::F(2)));
+        app.handle_key(key(KeyCode::Char('/')));
+        app.handle_key(key(KeyCode::Char('x')));
+        assert!(app.active_saved_filter.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_memory_pressure_threshold() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.mem_history.extend([60.0, 70.0, 79.0]);
+        assert!(app.memory_pressure().is_none());
+        app.mem_history.extend([82.0, 86.0, 90.0]);
+        let (pct, dir) = app.memory_pressure().unwrap();
+        assert_eq!(pct, 90.0);
+        assert_eq!(dir, Trend::Rising);
+    }
+
+    #[test]
+    fn test_smoothed_moving_average() {
+        let history: VecDeque<f64> = VecDeque::from(vec![10.0, 90.0, 20.0, 40.0]);
+        assert_eq!(smoothed(&history, 0), Some(40.0));
+        assert_eq!(smoothed(&history, 1), Some(40.0));
+        assert_eq!(smoothed(&history, 2), Some(30.0));
+        // Window larger than history averages everything.
+        assert_eq!(smoothed(&history, 10), Some(40.0));
+        assert_eq!(smoothed(&VecDeque::new(), 3), None);
+    }
+
+    #[tokio::test]
+    async fn test_cpu_display_total_uses_smoothing() {
+        let mut cfg = TuiConfig::default();
+        cfg.display.cpu_smoothing = 3;
+        let mut app = App::test_new(cfg);
+        app.cpu_history.extend([100.0, 10.0, 20.0, 30.0]);
+        assert_eq!(app.cpu_display_total(), 20.0);
+    }
+
+    #[tokio::test]
+    async fn test_chrome_hidden_toggle() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.handle_key(key(KeyCode::Char('H')));
+        assert!(app.chrome_hidden);
+        // Tab switching still works while hidden.
+        app.handle_key(key(KeyCode::Char('3')));
+        assert_eq!(app.active_tab, Tab::Network);
+        // Esc restores the chrome instead of quitting.
+        app.handle_key(key(KeyCode::Esc));
+        assert!(!app.chrome_hidden);
+        assert!(!app.should_quit);
+        app.handle_key(key(KeyCode::Char('H')));
+        app.handle_key(key(KeyCode::Char('H')));
+        assert!(!app.chrome_hidden);
+    }
+
+    #[tokio::test]
+    async fn test_navigate_empty_noop() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.waifu_navigate(1);
+        assert_eq!(app.waifu_index, -1);
+    }
+
+    #[tokio::test]
+    async fn test_navigate_forward_through_gallery() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(3));
+        assert_eq!(app.waifu_index, 0);
+        app.waifu_navigate(1);
+        assert_eq!(app.waifu_index, 1);
+        assert_eq!(app.waifu_name, "waifu_1");
+        app.waifu_navigate(1);
+        assert_eq!(app.waifu_index, 2);
+        assert_eq!(app.waifu_name, "waifu_2");
+    }
+
+    #[tokio::test]
+    async fn test_navigate_wraps_forward() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(3));
+        app.waifu_index = 2;
+        app.waifu_navigate(1);
+        assert_eq!(app.waifu_index, 0);
+        assert_eq!(app.waifu_name, "waifu_0");
+    }
+
+    #[tokio::test]
+    async fn test_navigate_wraps_backward() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(3));
+        assert_eq!(app.waifu_index, 0);
+        app.waifu_navigate(-1);
+        assert_eq!(app.waifu_index, 2);
+        assert_eq!(app.waifu_name, "waifu_2");
+    }
+
+    #[tokio::test]
+    async fn test_navigate_single_item_stays() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(1));
+        assert_eq!(app.waifu_index, 0);
+        app.waifu_navigate(1);
+        assert_eq!(app.waifu_index, 0);
+        app.waifu_navigate(-1);
+        assert_eq!(app.waifu_index, 0);
+    }
+
+    #[tokio::test]
+    async fn test_waifu_key_n_navigates_on_dashboard() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(3));
+        // Load initial image so has_waifu() is true.
+        app.waifu_load_at(0);
+        app.active_tab = Tab::Dashboard;
+        assert!(app.has_waifu());
+        app.handle_key(char_key('n'));
+        assert_eq!(app.waifu_index, 1);
+        app.handle_key(char_key('p'));
+        assert_eq!(app.waifu_index, 0);
+    }
+
+    #[tokio::test]
+    async fn test_waifu_random_selects_from_gallery() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(5));
+        app.waifu_load_at(0);
         app.waifu_random();
         // Random should select a valid index.
         assert!(app.waifu_index >= 0 && (app.waifu_index as usize) < 5);
@@ -1493,6 +5213,155 @@ mod tests {
         assert_eq!(app.process_scroll, 3);
     }
 
+    #[tokio::test]
+    async fn test_mouse_wheel_over_waifu_navigates() {
+        use crossterm::event::{MouseEvent, MouseEventKind};
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(3));
+        app.waifu_rect = Some(Rect::new(0, 3, 40, 20));
+        let wheel = |kind, column| MouseEvent {
+            kind,
+            column,
+            row: 10,
+            modifiers: KeyModifiers::NONE,
+        };
+        app.handle_mouse(wheel(MouseEventKind::ScrollDown, 5));
+        assert_eq!(app.waifu_index, 1);
+        app.handle_mouse(wheel(MouseEventKind::ScrollUp, 5));
+        assert_eq!(app.waifu_index, 0);
+        // Outside the waifu rect the wheel is ignored on the Dashboard.
+        app.handle_mouse(wheel(MouseEventKind::ScrollDown, 100));
+        assert_eq!(app.waifu_index, 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_right_click_menu() {
+        use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+        let mut app = App::test_new(TuiConfig::default()).with_processes(make_procs(10));
+        app.active_tab = Tab::System;
+        // Body rows start at y=5 and show processes from index 2.
+        app.process_rows_rect = Some((Rect::new(1, 5, 60, 5), 2));
+        let click = |kind, row| MouseEvent {
+            kind,
+            column: 10,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Right), 7));
+        assert_eq!(app.process_scroll, 4);
+        assert_eq!(app.process_menu.map(|m| m.selected), Some(0));
+
+        // Keyboard: down to "Copy PID", Enter.
+        app.handle_key(char_key('j'));
+        app.handle_key(char_key('j'));
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.process_menu.is_none());
+        assert_eq!(app.pending_clipboard.as_deref(), Some("4"));
+
+        // A click outside the drawn menu closes it without acting.
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Right), 5));
+        app.process_menu_rect = Some(Rect::new(10, 5, 24, 6));
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Left), 20));
+        assert!(app.process_menu.is_none());
+        assert_eq!(app.process_scroll, 2);
+
+        // Clicking an entry runs it: row 4 of the menu is "Show detail".
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Right), 5));
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Left), 9));
+        assert!(app.show_process_detail);
+
+        // Left-click below the last process does nothing.
+        app.show_process_detail = false;
+        app.process_rows_rect = Some((Rect::new(1, 5, 60, 20), 0));
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Left), 20));
+        assert_eq!(app.process_scroll, 2);
+        app.handle_mouse(click(MouseEventKind::Down(MouseButton::Left), 8));
+        assert_eq!(app.process_scroll, 3);
+    }
+
+    #[test]
+    fn test_tab_click_respects_margin() {
+        use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+        let mut cfg = TuiConfig::default();
+        cfg.display.margin = 2;
+        let mut app = App::test_new(cfg);
+        app.on_resize(124, 40);
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let tab_width = 120 / app.tab_order.len() as u16;
+
+        // Inside the margin: ignored.
+        app.handle_mouse(click(2 + tab_width, 1));
+        assert_eq!(app.active_tab, app.tab_order[0]);
+        // Second tab, measured from the inset edge.
+        app.handle_mouse(click(2 + tab_width, 3));
+        assert_eq!(app.active_tab, app.tab_order[1]);
+        app.handle_mouse(click(1, 3));
+        assert_eq!(app.active_tab, app.tab_order[1]);
+
+        // Short pane: the mini layout has no tab bar, so the top rows are content.
+        app.on_resize(124, 10);
+        app.handle_mouse(click(2, 3));
+        assert_eq!(app.active_tab, app.tab_order[1]);
+    }
+
+    // --- Resize Debounce ---
+
+    #[test]
+    fn test_resize_defers_waifu_rescale() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(2));
+        app.on_resize(100, 40);
+        assert_eq!(app.term_width, 100);
+        assert!(app.pending_resize.is_some());
+        // Not settled yet: nothing is re-scaled.
+        app.apply_pending_resize();
+        assert!(app.pending_resize.is_some());
+        assert!(!app.has_waifu());
+    }
+
+    #[test]
+    fn test_resize_rescales_after_settling() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_waifu_enabled()
+            .with_waifu_gallery(make_gallery(2));
+        app.on_resize(100, 40);
+        app.pending_resize = Instant::now().checked_sub(RESIZE_DEBOUNCE * 2);
+        app.apply_pending_resize();
+        assert!(app.pending_resize.is_none());
+        assert!(app.has_waifu());
+    }
+
+    // --- Claude Personal Countdown ---
+
+    #[test]
+    fn test_claude_personal_countdown_ticks_down() {
+        let mut app = App::test_new(TuiConfig::default());
+        assert_eq!(app.claude_personal_countdown(), 0);
+        app.claude_personal = Some(ClaudePersonalReport {
+            messages_in_window: 45,
+            message_limit: 45,
+            window_hours: 5,
+            next_slot_secs: 120,
+            first_slot_secs: 120,
+            exhaust_eta_secs: None,
+            buckets: Vec::new(),
+        });
+        app.claude_personal_read_at = Instant::now().checked_sub(Duration::from_secs(30)).unwrap();
+        assert_eq!(app.claude_personal_countdown(), 90);
+        app.claude_personal_read_at = Instant::now()
+            .checked_sub(Duration::from_secs(300))
+            .unwrap();
+        assert_eq!(app.claude_personal_countdown(), 0);
+    }
+
     // --- Property-Based Tests ---
 
     use proptest::prelude::*;
@@ -1,8 +1,15 @@
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Result;
 use serde::Deserialize;
 
+use crate::app::Tab;
+use crate::data::buildinfo::{DEFAULT_FLAKE_INPUTS, DEFAULT_FLAKE_LOCK_PATHS};
+use crate::data::claudepersonal::PersonalLimits;
+use crate::data::sysmetrics::PressureWeights;
+
 /// TUI-specific configuration, loaded from the same config.toml as the Go daemon.
 #[derive(Debug, Deserialize)]
 pub struct TuiConfig {
@@ -14,12 +21,55 @@ pub struct TuiConfig {
     pub image: ImageConfig,
     #[serde(default)]
     pub theme: ThemeConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub keys: KeysConfig,
+    #[serde(default)]
+    pub filters: FiltersConfig,
+    #[serde(default)]
+    pub claude: ClaudeConfig,
+    #[serde(default)]
+    pub build: BuildConfig,
 }
 
 #[derive(Debug, Default, Deserialize)]
 pub struct GeneralConfig {
     #[serde(default)]
     pub cache_dir: String,
+    /// Cache dirs synced from other machines, as "path" or "label=path"
+    /// (the label ends at the first `=`, so it can't contain one; a leading
+    /// `~/` is the home dir); their billing is summed into this host's view.
+    #[serde(default)]
+    pub extra_cache_dirs: Vec<String>,
+    /// Require pressing `q` twice to quit.
+    #[serde(default)]
+    pub confirm_quit: bool,
+    /// Title-bar label shown instead of the hostname (e.g. "prod-k8s").
+    #[serde(default)]
+    pub label: String,
+    /// Fastest refresh `+` can reach, in ms (unset = 250).
+    #[serde(default)]
+    pub min_refresh_ms: Option<u64>,
+    /// Slowest refresh `-` can reach, in ms (unset = 5000).
+    #[serde(default)]
+    pub max_refresh_ms: Option<u64>,
+    /// Mirror CPU/MEM into the terminal window title (unset = on).
+    #[serde(default)]
+    pub set_terminal_title: Option<bool>,
+    /// Flag daemon reports timestamped more than this many seconds in the
+    /// future as clock skew (unset = 5, 0 = off).
+    #[serde(default)]
+    pub clock_skew_secs: Option<u64>,
+    /// Tab order, e.g. ["system", "dashboard"]; unlisted tabs follow in
+    /// the built-in order.
+    #[serde(default)]
+    pub tab_order: Vec<String>,
+    /// Tab shown at startup (unset = first in `tab_order`).
+    #[serde(default)]
+    pub default_tab: String,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -52,6 +102,9 @@ pub struct ImageConfig {
     pub waifu_enabled: bool,
     #[serde(default)]
     pub waifu_category: String,
+    /// Play animated GIF/APNG/WebP waifu images (costs extra redraws).
+    #[serde(default)]
+    pub animate: bool,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -62,31 +115,339 @@ pub struct WaifuCollectorConfig {
     pub endpoint: String,
     #[serde(default)]
     pub category: String,
+    /// Max images kept in the in-memory gallery (0 = default of 20).
+    #[serde(default)]
+    pub max_images: usize,
 }
 
 #[derive(Debug, Default, Deserialize)]
 pub struct ThemeConfig {
     #[serde(default)]
     pub name: String,
+    /// Usage color thresholds in percent, four ascending values (empty = 40, 65, 80, 90).
+    #[serde(default)]
+    pub gradient: Vec<f64>,
+    /// Temperature color thresholds in °C (empty = 45, 65, 80, 90).
+    #[serde(default)]
+    pub temp_gradient: Vec<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DisplayConfig {
+    /// Process table columns in display order (empty = default set).
+    #[serde(default)]
+    pub process_columns: Vec<String>,
+    /// Show Linux kernel threads (kthreadd descendants) in the process table.
+    #[serde(default)]
+    pub show_kernel_threads: bool,
+    /// Process table row cap (unset = 100, 0 = unlimited).
+    #[serde(default)]
+    pub max_processes: Option<usize>,
+    /// History graph rendering: "bar" (default) or "braille".
+    #[serde(default)]
+    pub sparkline_style: String,
+    /// Which end of history graphs shows the latest sample: "right" (default) or "left".
+    #[serde(default)]
+    pub sparkline_newest: String,
+    /// Moving-average window (samples) for the headline CPU number (0/1 = off).
+    #[serde(default)]
+    pub cpu_smoothing: usize,
+    /// Process CPU%: "core" (default, 100% = one core) or "total" (100% = whole machine).
+    #[serde(default)]
+    pub cpu_normalize: String,
+    /// Fold idle virtual interfaces (veth, docker, br-, ...) into one network-table row.
+    #[serde(default)]
+    pub hide_idle_interfaces: bool,
+    /// Network rates below this many bytes/s render as "idle" (unset = 1024, 0 = only silence).
+    #[serde(default)]
+    pub net_idle_threshold: Option<u64>,
+    /// Disk gauge label template (blank = `DEFAULT_DISK_LABEL`); see
+    /// `DISK_LABEL_FIELDS` for the placeholders.
+    #[serde(default)]
+    pub disk_label: String,
+    /// Decimals in disk sizes (unset = 1, max 3).
+    #[serde(default)]
+    pub disk_precision: Option<usize>,
+    /// Start the Dashboard CPU widget as one aggregate gauge + history
+    /// instead of per-core bars (toggle with `U`).
+    #[serde(default)]
+    pub cpu_aggregate: bool,
+    /// Per-core CPU widget: "grid" (default, one sparkline per core) or
+    /// "heatmap" (one colored cell per core, for many-core machines).
+    #[serde(default)]
+    pub cpu_view: String,
+    /// One-line CPU/MEM/NET/LOAD strip under the tab bar on every tab.
+    #[serde(default)]
+    pub top_strip: bool,
+    /// Stop drawing while the terminal reports it lost focus (unset = on).
+    #[serde(default)]
+    pub pause_when_unfocused: Option<bool>,
+    /// Blank cells kept around the whole UI, for terminals whose rounded
+    /// corners or overlays clip the outer edge (0 = none).
+    #[serde(default)]
+    pub margin: u16,
+    /// Rows kept between the selected process and the table edge (vim-style;
+    /// a large value keeps the selection centered).
+    #[serde(default)]
+    pub scrolloff: usize,
+    /// Preferred address family for local and Tailscale IPs: "v4" (default) or "v6".
+    #[serde(default)]
+    pub ip_version: String,
+    /// Pressure-gauge weights by input (cpu, mem, swap, load, temp); unset
+    /// inputs keep their default weight.
+    #[serde(default)]
+    pub pressure_weights: HashMap<String, f64>,
+    /// Selected-row look: "highlight" (default, colored background) or
+    /// "reverse" (reverse video, readable on any color scheme).
+    #[serde(default)]
+    pub selection_style: String,
+    /// Memory widget warnings: "percent" (default, by utilization) or
+    /// "absolute" (by free memory, see `mem_warn_free_gib`).
+    #[serde(default)]
+    pub mem_warn_mode: String,
+    /// Free-memory warning threshold in GiB for `mem_warn_mode = "absolute"`
+    /// (unset = 4; red below a quarter of it).
+    #[serde(default)]
+    pub mem_warn_free_gib: Option<f64>,
+}
+
+/// How history graphs are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparklineStyle {
+    /// ratatui block-character bars.
+    #[default]
+    Bar,
+    /// Braille-dot line graph (2x4 dots per cell).
+    Braille,
+}
+
+/// What the memory widget's warning colors are based on.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MemWarnMode {
+    /// Utilization: yellow from 80% (or under 2 GiB free), red from 90%.
+    #[default]
+    Percent,
+    /// Free memory: yellow below `free_gib`, red below a quarter of it.
+    Absolute { free_gib: f64 },
+}
+
+/// How the per-core CPU widget is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CpuView {
+    /// One titled sparkline per core.
+    #[default]
+    Grid,
+    /// One colored cell per core, packed into as few rows as fit.
+    Heatmap,
+}
+
+/// Which side of a history graph holds the newest sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparklineNewest {
+    /// Time flows left to right (newest at the right edge).
+    #[default]
+    Right,
+    /// Newest sample at the left edge.
+    Left,
+}
+
+/// Deep-merge `over` into `base`: nested tables merge key by key, any other
+/// value (including arrays) replaces the base value.
+fn merge_tables(base: &mut toml::Table, over: toml::Table) {
+    for (key, value) in over {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(b)), toml::Value::Table(o)) => merge_tables(b, o),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Four ascending gradient thresholds; anything else is ignored with a warning.
+fn parse_stops(raw: &[f64], key: &str) -> Option<[f64; 4]> {
+    if raw.is_empty() {
+        return None;
+    }
+    match <[f64; 4]>::try_from(raw) {
+        Ok(stops) if stops.windows(2).all(|w| w[0] <= w[1]) => Some(stops),
+        _ => {
+            tracing::warn!("{key} needs four ascending values, got {raw:?}; using default");
+            None
+        }
+    }
+}
+
+/// Address family shown for local and Tailscale IPs (the other family is
+/// the fallback when a host has only one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IpVersion {
+    #[default]
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::V4 => Self::V6,
+            Self::V6 => Self::V4,
+        }
+    }
+
+    pub fn matches(self, ip: &std::net::IpAddr) -> bool {
+        match self {
+            Self::V4 => ip.is_ipv4(),
+            Self::V6 => ip.is_ipv6(),
+        }
+    }
+}
+
+/// How the selected row of a table or list is marked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionStyle {
+    /// Per-widget background color.
+    #[default]
+    Highlight,
+    /// Terminal reverse video, independent of the color scheme.
+    Reverse,
+}
+
+/// Scale for per-process CPU percentages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CpuNormalize {
+    /// sysinfo's native scale: a busy 8-thread process can show 800%.
+    #[default]
+    Core,
+    /// Divided by the core count so values stay within 0-100%.
+    Total,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CacheConfig {
+    /// Per-source max-age overrides, e.g. `{ billing = "20m", tailscale = "2m" }`.
+    #[serde(default)]
+    pub max_age: HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct KeysConfig {
+    /// On the System tab, typing a letter jumps to the next process whose
+    /// name starts with it (shadows the single-letter process commands).
+    #[serde(default)]
+    pub type_to_jump: bool,
+    /// Let `<` / `>` renice the selected process (off by default: it
+    /// changes system state).
+    #[serde(default)]
+    pub allow_renice: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FiltersConfig {
+    /// Named process filters, applied with F1-F9 in name order.
+    #[serde(default)]
+    pub saved: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ClaudeConfig {
+    /// Rate-limit window in hours for the personal-plan gauge (unset = daemon value).
+    #[serde(default)]
+    pub personal_window_hours: Option<i32>,
+    /// Messages allowed per window (unset = daemon value).
+    #[serde(default)]
+    pub personal_message_limit: Option<i32>,
+    /// Monthly spend caps in USD by account name, for accounts whose
+    /// daemon data has none, e.g. `{ work = 200 }`.
+    #[serde(default)]
+    pub account_caps: HashMap<String, f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BuildConfig {
+    /// flake.lock input names shown on the Build tab (unset = nixpkgs,
+    /// nixpkgs-unstable, home-manager, sops-nix, fenix).
+    #[serde(default)]
+    pub flake_inputs: Option<Vec<String>>,
+    /// flake.lock files to try in order; `~/` is the home dir (unset =
+    /// ~/git/crush-dots/flake.lock, then /etc/crush-dots/flake.lock).
+    #[serde(default)]
+    pub flake_lock_paths: Option<Vec<String>>,
 }
 
 fn default_true() -> bool {
     true
 }
 
+/// Trim `path` and expand a leading `~/` to the home dir (None when there
+/// is no home dir to expand it to).
+fn expand_home(path: &str) -> Option<PathBuf> {
+    match path.trim().strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|h| h.join(rest)),
+        None => Some(PathBuf::from(path.trim())),
+    }
+}
+
+/// Environment variable naming an alternate config file.
+pub const CONFIG_ENV: &str = "PROMPT_PULSE_CONFIG";
+
+/// Placeholders `display.disk_label` can use, each written as `{name}`.
+pub const DISK_LABEL_FIELDS: [&str; 6] = ["mount", "fs", "used", "total", "free", "percent"];
+/// Disk gauge label when `display.disk_label` is blank. A `[{fs}]` group
+/// drops out when the filesystem type is unknown.
+pub const DEFAULT_DISK_LABEL: &str = "{mount} [{fs}]: {used} / {total} ({percent}%) {free} free";
+
+/// Images fetched on launch before the waifu gallery stops auto-fetching;
+/// also the smallest `collectors.waifu.max_images`, so eviction can't undo
+/// the prefetch.
+pub const WAIFU_PREFETCH: usize = 3;
+
 impl TuiConfig {
-    /// Load config from the standard path (~/.config/prompt-pulse/config.toml).
+    /// Load config from `$PROMPT_PULSE_CONFIG` or the standard path
+    /// (~/.config/prompt-pulse/config.toml).
     pub fn load() -> Result<Self> {
-        let path = Self::config_path();
+        Self::load_from(&Self::resolve_path(None))
+    }
+
+    /// Load config from `path`; a missing file yields the defaults.
+    ///
+    /// A `[host.<hostname>]` section matching this machine is merged over the
+    /// base config (host values win); other host sections are ignored.
+    pub fn load_from(path: &Path) -> Result<Self> {
         if path.exists() {
-            let contents = std::fs::read_to_string(&path)?;
-            let cfg: TuiConfig = toml::from_str(&contents)?;
-            Ok(cfg)
+            let contents = std::fs::read_to_string(path)?;
+            let host = sysinfo::System::host_name();
+            Self::parse_for_host(&contents, host.as_deref())
         } else {
             Ok(Self::default())
         }
     }
 
+    fn parse_for_host(contents: &str, host: Option<&str>) -> Result<Self> {
+        let mut value: toml::Table = toml::from_str(contents)?;
+        let hosts = value.remove("host");
+        let overrides = match (hosts, host) {
+            (Some(toml::Value::Table(mut hosts)), Some(host)) => hosts.remove(host),
+            _ => None,
+        };
+        if let Some(toml::Value::Table(overrides)) = overrides {
+            merge_tables(&mut value, overrides);
+        }
+        Ok(value.try_into()?)
+    }
+
+    /// Config file to use: `--config` first, then `$PROMPT_PULSE_CONFIG`,
+    /// then the standard path.
+    pub fn resolve_path(cli: Option<&Path>) -> PathBuf {
+        Self::pick_path(cli, std::env::var(CONFIG_ENV).ok())
+    }
+
+    fn pick_path(cli: Option<&Path>, env: Option<String>) -> PathBuf {
+        cli.map(Path::to_path_buf)
+            .or_else(|| env.filter(|e| !e.is_empty()).map(PathBuf::from))
+            .unwrap_or_else(Self::config_path)
+    }
+
     pub fn config_path() -> PathBuf {
         // Respect XDG_CONFIG_HOME (used by Go daemon and home-manager).
         // On macOS, dirs::config_dir() returns ~/Library/Application Support/
@@ -124,6 +485,134 @@ impl TuiConfig {
         }
     }
 
+    /// Extra cache dirs from `general.extra_cache_dirs` with their host
+    /// labels ("label=path", or the directory name when no label is given)
+    /// and a leading `~/` expanded.
+    pub fn extra_cache_dirs(&self) -> Vec<(String, PathBuf)> {
+        self.general
+            .extra_cache_dirs
+            .iter()
+            .filter(|entry| !entry.trim().is_empty())
+            .filter_map(|entry| match entry.split_once('=') {
+                Some((label, path)) => Some((label.trim().to_string(), expand_home(path)?)),
+                None => {
+                    let path = expand_home(entry)?;
+                    let label = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| entry.clone());
+                    Some((label, path))
+                }
+            })
+            .collect()
+    }
+
+    /// Flake inputs to extract from flake.lock, in display order.
+    pub fn flake_inputs(&self) -> Vec<String> {
+        match &self.build.flake_inputs {
+            Some(names) => names.clone(),
+            None => DEFAULT_FLAKE_INPUTS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Candidate flake.lock paths with a leading `~/` expanded.
+    pub fn flake_lock_paths(&self) -> Vec<PathBuf> {
+        let raw: Vec<&str> = match &self.build.flake_lock_paths {
+            Some(paths) => paths.iter().map(String::as_str).collect(),
+            None => DEFAULT_FLAKE_LOCK_PATHS.to_vec(),
+        };
+        raw.into_iter()
+            .filter(|p| !p.trim().is_empty())
+            .filter_map(expand_home)
+            .collect()
+    }
+
+    /// Directory for the TUI's own small state files (e.g. the first-run
+    /// marker): `$XDG_STATE_HOME/prompt-pulse`, else the platform state or
+    /// local data dir, else the cache dir.
+    pub fn state_dir(&self) -> PathBuf {
+        std::env::var("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .ok()
+            .or_else(dirs::state_dir)
+            .or_else(dirs::data_local_dir)
+            .map(|base| base.join("prompt-pulse"))
+            .unwrap_or_else(|| self.cache_dir())
+    }
+
+    /// Refresh-interval bounds for `+`/`-` from `general.min_refresh_ms` /
+    /// `max_refresh_ms`. A zero or inverted pair warns and uses 250-5000 ms.
+    pub fn refresh_bounds(&self) -> (u64, u64) {
+        const DEFAULT: (u64, u64) = (250, 5000);
+        let min = self.general.min_refresh_ms.unwrap_or(DEFAULT.0);
+        let max = self.general.max_refresh_ms.unwrap_or(DEFAULT.1.max(min));
+        if min == 0 || min > max {
+            tracing::warn!(
+                "invalid general.min_refresh_ms/max_refresh_ms ({min}/{max}), using 250/5000"
+            );
+            return DEFAULT;
+        }
+        (min, max)
+    }
+
+    /// Whether to keep the terminal window title updated (default on).
+    pub fn set_terminal_title(&self) -> bool {
+        self.general.set_terminal_title.unwrap_or(true)
+    }
+
+    /// Future-timestamp tolerance for the clock skew warning (0 = off).
+    pub fn clock_skew_secs(&self) -> u64 {
+        self.general.clock_skew_secs.unwrap_or(5)
+    }
+
+    /// Parsed per-source cache max-age overrides. Invalid durations warn and are skipped.
+    pub fn cache_max_ages(&self) -> HashMap<String, Duration> {
+        self.cache
+            .max_age
+            .iter()
+            .filter_map(|(source, value)| match parse_duration(value) {
+                Some(d) => Some((source.clone(), d)),
+                None => {
+                    tracing::warn!("invalid cache.max_age for {source}: {value:?}, skipping");
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Personal-plan window/limit overrides from `[claude]`; non-positive values
+    /// warn and leave the daemon's value in place.
+    pub fn personal_limits(&self) -> PersonalLimits {
+        let positive = |value: Option<i32>, key: &str| match value {
+            Some(v) if v > 0 => Some(v),
+            Some(v) => {
+                tracing::warn!("claude.{key} must be positive, got {v}; using daemon value");
+                None
+            }
+            None => None,
+        };
+        PersonalLimits {
+            window_hours: positive(self.claude.personal_window_hours, "personal_window_hours"),
+            message_limit: positive(self.claude.personal_message_limit, "personal_message_limit"),
+        }
+    }
+
+    /// Positive per-account caps from `claude.account_caps`; others warn and are skipped.
+    pub fn claude_account_caps(&self) -> HashMap<String, f64> {
+        self.claude
+            .account_caps
+            .iter()
+            .filter(|(name, cap)| {
+                let ok = cap.is_finite() && **cap > 0.0;
+                if !ok {
+                    tracing::warn!("claude.account_caps.{name} must be positive, got {cap}");
+                }
+                ok
+            })
+            .map(|(name, cap)| (name.clone(), *cap))
+            .collect()
+    }
+
     /// Get the waifu mirror endpoint URL (from collectors.waifu.endpoint).
     pub fn waifu_endpoint(&self) -> Option<&str> {
         let ep = &self.collectors.waifu.endpoint;
@@ -145,6 +634,279 @@ impl TuiConfig {
             "sfw"
         }
     }
+
+    /// Process table row cap, or None for unlimited (`display.max_processes = 0`).
+    pub fn max_processes(&self) -> Option<usize> {
+        match self.display.max_processes {
+            None => Some(100),
+            Some(0) => None,
+            Some(n) => Some(n),
+        }
+    }
+
+    /// Whether to pause drawing while the terminal is unfocused (default on).
+    pub fn pause_when_unfocused(&self) -> bool {
+        self.display.pause_when_unfocused.unwrap_or(true)
+    }
+
+    /// Rate (bytes/s) under which network traffic is shown as idle.
+    pub fn net_idle_threshold(&self) -> u64 {
+        self.display.net_idle_threshold.unwrap_or(1024)
+    }
+
+    /// Disk gauge label template from `display.disk_label` (blank = default).
+    /// Unknown `{placeholders}` are left as typed, with a warning.
+    pub fn disk_label(&self) -> String {
+        let template = self.display.disk_label.trim();
+        if template.is_empty() {
+            return DEFAULT_DISK_LABEL.to_string();
+        }
+        for field in template
+            .split('{')
+            .skip(1)
+            .filter_map(|s| s.split_once('}'))
+        {
+            if !DISK_LABEL_FIELDS.contains(&field.0) {
+                tracing::warn!(
+                    "display.disk_label: unknown placeholder {{{}}} (known: {})",
+                    field.0,
+                    DISK_LABEL_FIELDS.join(", ")
+                );
+            }
+        }
+        template.to_string()
+    }
+
+    /// Decimals for disk sizes from `display.disk_precision` (default 1, capped at 3).
+    pub fn disk_precision(&self) -> usize {
+        match self.display.disk_precision {
+            Some(p) if p > 3 => {
+                tracing::warn!("display.disk_precision {p} is above 3; using 3");
+                3
+            }
+            Some(p) => p,
+            None => 1,
+        }
+    }
+
+    /// Tab order from `general.tab_order`: known names in the given order
+    /// (unknown names and repeats are skipped with a warning), then any
+    /// tabs it leaves out, in the built-in order.
+    pub fn tab_order(&self) -> Vec<Tab> {
+        let mut order: Vec<Tab> = Vec::with_capacity(Tab::ALL.len());
+        for name in &self.general.tab_order {
+            match Tab::parse(name) {
+                Some(tab) if !order.contains(&tab) => order.push(tab),
+                Some(_) => tracing::warn!("general.tab_order lists {name:?} twice"),
+                None => tracing::warn!("unknown tab {name:?} in general.tab_order"),
+            }
+        }
+        for tab in Tab::ALL {
+            if !order.contains(tab) {
+                order.push(*tab);
+            }
+        }
+        order
+    }
+
+    /// Startup tab from `general.default_tab` (unset or unknown = None).
+    pub fn default_tab(&self) -> Option<Tab> {
+        let name = self.general.default_tab.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let tab = Tab::parse(name);
+        if tab.is_none() {
+            tracing::warn!("unknown general.default_tab {name:?}");
+        }
+        tab
+    }
+
+    /// Graph style from `display.sparkline_style` (unknown values fall back to bars).
+    pub fn sparkline_style(&self) -> SparklineStyle {
+        match self.display.sparkline_style.as_str() {
+            "" | "bar" | "bars" => SparklineStyle::Bar,
+            "braille" => SparklineStyle::Braille,
+            other => {
+                tracing::warn!("unknown display.sparkline_style {other:?}, using bars");
+                SparklineStyle::Bar
+            }
+        }
+    }
+
+    /// Graph direction from `display.sparkline_newest` (unknown values keep newest on the right).
+    pub fn sparkline_newest(&self) -> SparklineNewest {
+        match self.display.sparkline_newest.as_str() {
+            "" | "right" => SparklineNewest::Right,
+            "left" => SparklineNewest::Left,
+            other => {
+                tracing::warn!("unknown display.sparkline_newest {other:?}, using right");
+                SparklineNewest::Right
+            }
+        }
+    }
+
+    /// Memory warning basis from `display.mem_warn_mode` (unknown values
+    /// and bad thresholds fall back to percent / 4 GiB).
+    pub fn mem_warn_mode(&self) -> MemWarnMode {
+        const DEFAULT_FREE_GIB: f64 = 4.0;
+        match self.display.mem_warn_mode.as_str() {
+            "" | "percent" => MemWarnMode::Percent,
+            "absolute" => {
+                let free_gib = match self.display.mem_warn_free_gib {
+                    Some(gib) if gib.is_finite() && gib > 0.0 => gib,
+                    Some(gib) => {
+                        tracing::warn!("display.mem_warn_free_gib {gib} is not positive, using 4");
+                        DEFAULT_FREE_GIB
+                    }
+                    None => DEFAULT_FREE_GIB,
+                };
+                MemWarnMode::Absolute { free_gib }
+            }
+            other => {
+                tracing::warn!("unknown display.mem_warn_mode {other:?}, using percent");
+                MemWarnMode::Percent
+            }
+        }
+    }
+
+    /// Per-core CPU widget from `display.cpu_view` (unknown values keep the grid).
+    pub fn cpu_view(&self) -> CpuView {
+        match self.display.cpu_view.as_str() {
+            "" | "grid" => CpuView::Grid,
+            "heatmap" => CpuView::Heatmap,
+            other => {
+                tracing::warn!("unknown display.cpu_view {other:?}, using grid");
+                CpuView::Grid
+            }
+        }
+    }
+
+    /// Process CPU scale from `display.cpu_normalize` (unknown values keep per-core).
+    pub fn cpu_normalize(&self) -> CpuNormalize {
+        match self.display.cpu_normalize.as_str() {
+            "" | "core" => CpuNormalize::Core,
+            "total" => CpuNormalize::Total,
+            other => {
+                tracing::warn!("unknown display.cpu_normalize {other:?}, using per-core");
+                CpuNormalize::Core
+            }
+        }
+    }
+
+    /// Preferred IP family from `display.ip_version` (unknown values keep IPv4).
+    pub fn ip_version(&self) -> IpVersion {
+        match self.display.ip_version.as_str() {
+            "" | "v4" | "4" | "ipv4" => IpVersion::V4,
+            "v6" | "6" | "ipv6" => IpVersion::V6,
+            other => {
+                tracing::warn!("unknown display.ip_version {other:?}, using v4");
+                IpVersion::V4
+            }
+        }
+    }
+
+    /// Selected-row style from `display.selection_style` (unknown values keep the highlight).
+    pub fn selection_style(&self) -> SelectionStyle {
+        match self.display.selection_style.as_str() {
+            "" | "highlight" => SelectionStyle::Highlight,
+            "reverse" => SelectionStyle::Reverse,
+            other => {
+                tracing::warn!("unknown display.selection_style {other:?}, using highlight");
+                SelectionStyle::Highlight
+            }
+        }
+    }
+
+    /// Pressure-gauge weights from `display.pressure_weights` over the defaults.
+    /// Unknown inputs and negative weights warn and are skipped.
+    pub fn pressure_weights(&self) -> PressureWeights {
+        let mut weights = PressureWeights::default();
+        for (input, &w) in &self.display.pressure_weights {
+            let slot = match input.as_str() {
+                "cpu" => &mut weights.cpu,
+                "mem" | "memory" => &mut weights.mem,
+                "swap" => &mut weights.swap,
+                "load" => &mut weights.load,
+                "temp" | "temperature" => &mut weights.temp,
+                other => {
+                    tracing::warn!("unknown display.pressure_weights input {other:?}, skipping");
+                    continue;
+                }
+            };
+            if w < 0.0 || !w.is_finite() {
+                tracing::warn!("display.pressure_weights.{input} must be >= 0, got {w}; skipping");
+                continue;
+            }
+            *slot = w;
+        }
+        weights
+    }
+
+    /// Usage gradient stops from `theme.gradient` (None = built-in default).
+    pub fn gradient_stops(&self) -> Option<[f64; 4]> {
+        parse_stops(&self.theme.gradient, "theme.gradient")
+    }
+
+    /// Temperature gradient stops from `theme.temp_gradient` (None = built-in default).
+    pub fn temp_gradient_stops(&self) -> Option<[f64; 4]> {
+        parse_stops(&self.theme.temp_gradient, "theme.temp_gradient")
+    }
+
+    /// Custom title-bar label from `general.label`, if set (blank = hostname).
+    pub fn title_label(&self) -> Option<&str> {
+        let label = self.general.label.trim();
+        (!label.is_empty()).then_some(label)
+    }
+
+    /// Max in-memory waifu gallery size (from collectors.waifu.max_images,
+    /// default 20). Values below `WAIFU_PREFETCH` warn and use it instead;
+    /// read once at startup.
+    pub fn waifu_max_images(&self) -> usize {
+        match self.collectors.waifu.max_images {
+            0 => 20,
+            n if n < WAIFU_PREFETCH => {
+                tracing::warn!(
+                    "collectors.waifu.max_images must be at least {WAIFU_PREFETCH}, got {n}; \
+                     using {WAIFU_PREFETCH}"
+                );
+                WAIFU_PREFETCH
+            }
+            n => n,
+        }
+    }
+}
+
+/// Parse a Go-style duration string ("90s", "15m", "2h", "1h30m").
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let mut total = 0u64;
+    let mut num = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+            continue;
+        }
+        let n: u64 = num.parse().ok()?;
+        num.clear();
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return None,
+        };
+        // Values too large to represent are rejected, not wrapped.
+        total = total.checked_add(n.checked_mul(unit)?)?;
+    }
+    // Bare trailing number is seconds.
+    if !num.is_empty() {
+        total = total.checked_add(num.parse::<u64>().ok()?)?;
+    }
+    Some(Duration::from_secs(total))
 }
 
 impl Default for TuiConfig {
@@ -154,6 +916,12 @@ impl Default for TuiConfig {
             collectors: CollectorsConfig::default(),
             image: ImageConfig::default(),
             theme: ThemeConfig::default(),
+            display: DisplayConfig::default(),
+            cache: CacheConfig::default(),
+            keys: KeysConfig::default(),
+            filters: FiltersConfig::default(),
+            claude: ClaudeConfig::default(),
+            build: BuildConfig::default(),
         }
     }
 }
@@ -227,6 +995,87 @@ waifu_enabled = true
         assert!(cfg.image.waifu_enabled);
     }
 
+    #[test]
+    fn test_toml_parse_process_columns() {
+        let toml_str = r#"
+[display]
+process_columns = ["pid", "name", "cpu"]
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.display.process_columns, vec!["pid", "name", "cpu"]);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("15m"), Some(Duration::from_secs(900)));
+        assert_eq!(parse_duration("1h30m"), Some(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("45"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration("5x"), None);
+        // Overflow is an invalid value, not a panic or a wrapped duration.
+        assert_eq!(parse_duration("999999999999999999d"), None);
+        assert_eq!(parse_duration("18446744073709551615s1s"), None);
+    }
+
+    #[test]
+    fn test_toml_parse_extra_cache_dirs() {
+        let toml_str = r#"
+[general]
+extra_cache_dirs = ["/srv/sync/laptop", "nas = /mnt/pulse/cache", "", "desk=~/sync/a=b"]
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            cfg.extra_cache_dirs(),
+            vec![
+                ("laptop".to_string(), PathBuf::from("/srv/sync/laptop")),
+                ("nas".to_string(), PathBuf::from("/mnt/pulse/cache")),
+                ("desk".to_string(), home.join("sync/a=b")),
+            ]
+        );
+        assert!(TuiConfig::default().extra_cache_dirs().is_empty());
+    }
+
+    #[test]
+    fn test_build_flake_settings() {
+        let defaults = TuiConfig::default();
+        assert_eq!(defaults.flake_inputs()[0], "nixpkgs");
+        assert_eq!(defaults.flake_inputs().len(), 5);
+        assert_eq!(
+            defaults.flake_lock_paths().last(),
+            Some(&PathBuf::from("/etc/crush-dots/flake.lock"))
+        );
+
+        let toml_str = r#"
+[build]
+flake_inputs = ["nixpkgs", "darwin"]
+flake_lock_paths = ["/srv/dots/flake.lock", "~/src/nix/flake.lock", ""]
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.flake_inputs(), ["nixpkgs", "darwin"]);
+        let paths = cfg.flake_lock_paths();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], PathBuf::from("/srv/dots/flake.lock"));
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(paths[1], home.join("src/nix/flake.lock"));
+        }
+    }
+
+    #[test]
+    fn test_toml_parse_cache_max_age() {
+        let toml_str = r#"
+[cache]
+max_age = { billing = "20m", tailscale = "2m", k8s = "bogus" }
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        let ages = cfg.cache_max_ages();
+        assert_eq!(ages.get("billing"), Some(&Duration::from_secs(1200)));
+        assert_eq!(ages.get("tailscale"), Some(&Duration::from_secs(120)));
+        assert!(!ages.contains_key("k8s"));
+    }
+
     #[test]
     fn test_toml_parse_real_daemon_config() {
         let toml_str = r#"
@@ -283,6 +1132,315 @@ instant_banner = true
             Some("https://waifu.ephemera.tinyland.dev")
         );
         assert_eq!(cfg.waifu_category(), "nsfw");
+        assert_eq!(cfg.waifu_max_images(), 20);
+    }
+
+    #[test]
+    fn test_max_processes() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.max_processes(), Some(100));
+        cfg.display.max_processes = Some(0);
+        assert_eq!(cfg.max_processes(), None);
+        let cfg: TuiConfig = toml::from_str("[display]\nmax_processes = 250\n").unwrap();
+        assert_eq!(cfg.max_processes(), Some(250));
+    }
+
+    #[test]
+    fn test_sparkline_style() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.sparkline_style(), SparklineStyle::Bar);
+        cfg.display.sparkline_style = "braille".into();
+        assert_eq!(cfg.sparkline_style(), SparklineStyle::Braille);
+        cfg.display.sparkline_style = "dots".into();
+        assert_eq!(cfg.sparkline_style(), SparklineStyle::Bar);
+    }
+
+    #[test]
+    fn test_sparkline_newest() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.sparkline_newest(), SparklineNewest::Right);
+        cfg.display.sparkline_newest = "left".into();
+        assert_eq!(cfg.sparkline_newest(), SparklineNewest::Left);
+        cfg.display.sparkline_newest = "up".into();
+        assert_eq!(cfg.sparkline_newest(), SparklineNewest::Right);
+    }
+
+    #[test]
+    fn test_mem_warn_mode() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.mem_warn_mode(), MemWarnMode::Percent);
+        cfg.display.mem_warn_mode = "absolute".into();
+        assert_eq!(cfg.mem_warn_mode(), MemWarnMode::Absolute { free_gib: 4.0 });
+        cfg.display.mem_warn_free_gib = Some(16.0);
+        assert_eq!(
+            cfg.mem_warn_mode(),
+            MemWarnMode::Absolute { free_gib: 16.0 }
+        );
+        cfg.display.mem_warn_free_gib = Some(-1.0);
+        assert_eq!(cfg.mem_warn_mode(), MemWarnMode::Absolute { free_gib: 4.0 });
+        cfg.display.mem_warn_mode = "bytes".into();
+        assert_eq!(cfg.mem_warn_mode(), MemWarnMode::Percent);
+    }
+
+    #[test]
+    fn test_cpu_view() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.cpu_view(), CpuView::Grid);
+        cfg.display.cpu_view = "heatmap".into();
+        assert_eq!(cfg.cpu_view(), CpuView::Heatmap);
+        cfg.display.cpu_view = "treemap".into();
+        assert_eq!(cfg.cpu_view(), CpuView::Grid);
+    }
+
+    #[test]
+    fn test_refresh_bounds() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.refresh_bounds(), (250, 5000));
+        cfg.general.max_refresh_ms = Some(30_000);
+        assert_eq!(cfg.refresh_bounds(), (250, 30_000));
+        // A floor above the default ceiling lifts the ceiling with it.
+        cfg.general.max_refresh_ms = None;
+        cfg.general.min_refresh_ms = Some(8000);
+        assert_eq!(cfg.refresh_bounds(), (8000, 8000));
+        cfg.general.max_refresh_ms = Some(1000);
+        assert_eq!(cfg.refresh_bounds(), (250, 5000));
+    }
+
+    #[test]
+    fn test_pressure_weights() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.pressure_weights(), PressureWeights::default());
+        cfg.display.pressure_weights = HashMap::from([
+            ("temp".to_string(), 0.5),
+            ("swap".to_string(), -1.0),
+            ("gpu".to_string(), 1.0),
+        ]);
+        let weights = cfg.pressure_weights();
+        assert_eq!(weights.temp, 0.5);
+        assert_eq!(weights.swap, PressureWeights::default().swap);
+        assert_eq!(weights.cpu, PressureWeights::default().cpu);
+    }
+
+    #[test]
+    fn test_personal_limits() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.personal_limits(), PersonalLimits::default());
+        cfg.claude.personal_window_hours = Some(8);
+        cfg.claude.personal_message_limit = Some(0);
+        let limits = cfg.personal_limits();
+        assert_eq!(limits.window_hours, Some(8));
+        assert_eq!(limits.message_limit, None, "zero falls back to the daemon");
+    }
+
+    #[test]
+    fn test_net_idle_threshold() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.net_idle_threshold(), 1024);
+        cfg.display.net_idle_threshold = Some(0);
+        assert_eq!(cfg.net_idle_threshold(), 0);
+    }
+
+    #[test]
+    fn test_pause_when_unfocused_defaults_on() {
+        assert!(TuiConfig::default().pause_when_unfocused());
+        let cfg: TuiConfig = toml::from_str("[display]\npause_when_unfocused = false\n").unwrap();
+        assert!(!cfg.pause_when_unfocused());
+    }
+
+    #[test]
+    fn test_ip_version() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.ip_version(), IpVersion::V4);
+        cfg.display.ip_version = "v6".into();
+        assert_eq!(cfg.ip_version(), IpVersion::V6);
+        assert_eq!(IpVersion::V6.toggle(), IpVersion::V4);
+        cfg.display.ip_version = "v5".into();
+        assert_eq!(cfg.ip_version(), IpVersion::V4);
+    }
+
+    #[test]
+    fn test_selection_style() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.selection_style(), SelectionStyle::Highlight);
+        cfg.display.selection_style = "reverse".into();
+        assert_eq!(cfg.selection_style(), SelectionStyle::Reverse);
+        cfg.display.selection_style = "underline".into();
+        assert_eq!(cfg.selection_style(), SelectionStyle::Highlight);
+    }
+
+    #[test]
+    fn test_cpu_normalize() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.cpu_normalize(), CpuNormalize::Core);
+        cfg.display.cpu_normalize = "total".into();
+        assert_eq!(cfg.cpu_normalize(), CpuNormalize::Total);
+        cfg.display.cpu_normalize = "percore".into();
+        assert_eq!(cfg.cpu_normalize(), CpuNormalize::Core);
+    }
+
+    #[test]
+    fn test_saved_filters_parse_in_name_order() {
+        let cfg: TuiConfig = toml::from_str(
+            r#"
+[filters]
+saved = { node = "node", docker = "docker" }
+"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = cfg.filters.saved.keys().map(String::as_str).collect();
+        assert_eq!(names, ["docker", "node"]);
+    }
+
+    #[test]
+    fn test_host_overrides_merge_over_base() {
+        let toml_str = r#"
+[general]
+label = "base"
+confirm_quit = true
+
+[image]
+waifu_enabled = true
+
+[host.laptop.image]
+waifu_enabled = false
+
+[host.laptop.general]
+label = "laptop"
+
+[host.server.general]
+label = "server"
+"#;
+        let cfg = TuiConfig::parse_for_host(toml_str, Some("laptop")).unwrap();
+        assert_eq!(cfg.general.label, "laptop");
+        assert!(
+            cfg.general.confirm_quit,
+            "unset host keys keep the base value"
+        );
+        assert!(!cfg.image.waifu_enabled);
+
+        let cfg = TuiConfig::parse_for_host(toml_str, Some("desktop")).unwrap();
+        assert_eq!(cfg.general.label, "base");
+        assert!(cfg.image.waifu_enabled);
+
+        let cfg = TuiConfig::parse_for_host(toml_str, None).unwrap();
+        assert_eq!(cfg.general.label, "base");
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.gradient_stops(), None);
+        cfg.theme.gradient = vec![30.0, 50.0, 70.0, 85.0];
+        assert_eq!(cfg.gradient_stops(), Some([30.0, 50.0, 70.0, 85.0]));
+        cfg.theme.gradient = vec![90.0, 50.0, 70.0, 85.0];
+        assert_eq!(cfg.gradient_stops(), None);
+        cfg.theme.temp_gradient = vec![50.0, 60.0];
+        assert_eq!(cfg.temp_gradient_stops(), None);
+    }
+
+    #[test]
+    fn test_config_path_precedence() {
+        let cli = Path::new("/tmp/cli.toml");
+        let env = Some("/tmp/env.toml".to_string());
+        assert_eq!(
+            TuiConfig::pick_path(Some(cli), env.clone()),
+            PathBuf::from("/tmp/cli.toml")
+        );
+        assert_eq!(
+            TuiConfig::pick_path(None, env),
+            PathBuf::from("/tmp/env.toml")
+        );
+        assert_eq!(
+            TuiConfig::pick_path(None, Some(String::new())),
+            TuiConfig::config_path()
+        );
+        assert_eq!(TuiConfig::pick_path(None, None), TuiConfig::config_path());
+    }
+
+    #[test]
+    fn test_load_from_path() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("alt.toml");
+        std::fs::write(&path, "[general]\nlabel = \"alt\"\n").unwrap();
+        let cfg = TuiConfig::load_from(&path).unwrap();
+        assert_eq!(cfg.title_label(), Some("alt"));
+        let cfg = TuiConfig::load_from(&tmp.path().join("missing.toml")).unwrap();
+        assert_eq!(cfg.title_label(), None);
+    }
+
+    #[test]
+    fn test_tab_order() {
+        assert_eq!(TuiConfig::default().tab_order(), Tab::ALL);
+        assert_eq!(TuiConfig::default().default_tab(), None);
+
+        let toml_str = r#"
+[general]
+tab_order = ["System", "dashboard", "bogus", "system", "build"]
+default_tab = "billing"
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            cfg.tab_order(),
+            [
+                Tab::System,
+                Tab::Dashboard,
+                Tab::Build,
+                Tab::Network,
+                Tab::Billing
+            ]
+        );
+        assert_eq!(cfg.default_tab(), Some(Tab::Billing));
+
+        let cfg: TuiConfig = toml::from_str("[general]\ndefault_tab = \"nope\"\n").unwrap();
+        assert_eq!(cfg.default_tab(), None);
+    }
+
+    #[test]
+    fn test_disk_label_and_precision() {
+        let cfg = TuiConfig::default();
+        assert_eq!(cfg.disk_label(), DEFAULT_DISK_LABEL);
+        assert_eq!(cfg.disk_precision(), 1);
+
+        let toml_str = r#"
+[display]
+disk_label = "{mount}: {free} free of {total}"
+disk_precision = 7
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.disk_label(), "{mount}: {free} free of {total}");
+        assert_eq!(cfg.disk_precision(), 3);
+    }
+
+    #[test]
+    fn test_claude_account_caps() {
+        let toml_str = r#"
+[claude]
+account_caps = { work = 200, broken = -5 }
+"#;
+        let cfg: TuiConfig = toml::from_str(toml_str).unwrap();
+        let caps = cfg.claude_account_caps();
+        assert_eq!(caps.len(), 1);
+        assert_eq!(caps["work"], 200.0);
+    }
+
+    #[test]
+    fn test_title_label() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.title_label(), None);
+        cfg.general.label = "   ".into();
+        assert_eq!(cfg.title_label(), None);
+        let cfg: TuiConfig = toml::from_str("[general]\nlabel = \" edge-01 \"\n").unwrap();
+        assert_eq!(cfg.title_label(), Some("edge-01"));
+    }
+
+    #[test]
+    fn test_waifu_max_images_default() {
+        let mut cfg = TuiConfig::default();
+        assert_eq!(cfg.waifu_max_images(), 20);
+        cfg.collectors.waifu.max_images = 5;
+        assert_eq!(cfg.waifu_max_images(), 5);
+        cfg.collectors.waifu.max_images = 1;
+        assert_eq!(cfg.waifu_max_images(), 3);
     }
 
     /// Diagnostic test: load the REAL config from disk and verify waifu init path.
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 /// Mirrors Go billing.BillingReport (daemon cache).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct BillingReport {
     #[serde(default, deserialize_with = "crate::data::null_to_default")]
     pub providers: Vec<ProviderBilling>,
@@ -29,6 +29,42 @@ pub struct ProviderBilling {
     pub balance: f64,
     #[serde(default, deserialize_with = "crate::data::null_to_default")]
     pub resources: Vec<ResourceCost>,
+    /// Source host label for providers merged from another machine's cache
+    /// (empty = this machine). Not part of the daemon's JSON.
+    #[serde(skip)]
+    pub host: String,
+}
+
+impl BillingReport {
+    /// Fold another host's report into this one: totals and budgets are
+    /// summed, its providers are tagged with `host`, and the older timestamp
+    /// is kept so the combined view never looks fresher than its sources.
+    pub fn merge(&mut self, host: &str, other: BillingReport) {
+        self.total_monthly_usd += other.total_monthly_usd;
+        self.budget_usd += other.budget_usd;
+        self.budget_percent = if self.budget_usd > 0.0 {
+            self.total_monthly_usd / self.budget_usd * 100.0
+        } else {
+            0.0
+        };
+        self.timestamp = match (self.timestamp, other.timestamp) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.providers
+            .extend(other.providers.into_iter().map(|mut p| {
+                p.host = host.to_string();
+                p
+            }));
+    }
+
+    /// Number of distinct hosts the providers came from.
+    pub fn host_count(&self) -> usize {
+        let mut hosts: Vec<&str> = self.providers.iter().map(|p| p.host.as_str()).collect();
+        hosts.sort_unstable();
+        hosts.dedup();
+        hosts.len()
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -60,4 +96,22 @@ mod tests {
         let report: BillingReport = serde_json::from_str(json).unwrap();
         assert!(report.providers[0].resources.is_empty());
     }
+
+    #[test]
+    fn test_billing_merge_sums_hosts() {
+        let json = r#"{"providers": [{"name": "civo", "month_to_date": 10}],
+            "total_monthly_usd": 10, "budget_usd": 50, "budget_percent": 20}"#;
+        let mut report: BillingReport = serde_json::from_str(json).unwrap();
+        let other: BillingReport = serde_json::from_str(
+            r#"{"providers": [{"name": "aws"}], "total_monthly_usd": 30, "budget_usd": 50}"#,
+        )
+        .unwrap();
+        report.merge("laptop", other);
+        assert_eq!(report.total_monthly_usd, 40.0);
+        assert_eq!(report.budget_usd, 100.0);
+        assert_eq!(report.budget_percent, 40.0);
+        assert_eq!(report.providers[0].host, "");
+        assert_eq!(report.providers[1].host, "laptop");
+        assert_eq!(report.host_count(), 2);
+    }
 }
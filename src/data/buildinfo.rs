@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::Path;
 
 use serde::Deserialize;
 
@@ -29,6 +29,22 @@ impl TuiBuildInfo {
     }
 }
 
+/// Key/value summary for the about popup and `--version`.
+pub fn about_fields(daemon: Option<&DaemonVersion>) -> Vec<(&'static str, String)> {
+    let build = TuiBuildInfo::current();
+    let daemon = match daemon {
+        Some(d) if d.git_sha.is_empty() => d.version.clone(),
+        Some(d) => format!("{} ({})", d.version, &d.git_sha[..8.min(d.git_sha.len())]),
+        None => "not detected".to_string(),
+    };
+    vec![
+        ("Version", format!("v{}", build.version)),
+        ("Git SHA", build.git_sha.to_string()),
+        ("Dirty", if build.dirty { "yes" } else { "no" }.to_string()),
+        ("Daemon", daemon),
+    ]
+}
+
 /// Runtime component version info read from the daemon's cache files.
 #[derive(Debug, Default)]
 pub struct ComponentVersions {
@@ -55,20 +71,32 @@ pub struct FlakeInput {
 pub fn read_daemon_version(cfg: &TuiConfig) -> Option<DaemonVersion> {
     let path = cfg.cache_dir().join("daemon-status.json");
     let contents = std::fs::read_to_string(&path).ok()?;
-    // The daemon status file has a "version" object.
     let v: serde_json::Value = serde_json::from_str(&contents).ok()?;
-    Some(DaemonVersion {
-        version: v.get("version")?.as_str()?.to_string(),
-        git_sha: v
-            .get("git_sha")
-            .and_then(|s| s.as_str())
-            .unwrap_or("")
-            .to_string(),
-        go_version: v
-            .get("go_version")
+    parse_daemon_version(&v)
+}
+
+/// Pull version fields out of daemon-status.json. Older daemons write them
+/// flat (`"version": "1.4.0"`); newer ones nest them under a `version`
+/// object (`"version": {"version": "1.4.0", "git_sha": ...}`).
+fn parse_daemon_version(v: &serde_json::Value) -> Option<DaemonVersion> {
+    let (fields, version) = match v.get("version")? {
+        serde_json::Value::String(s) => (v, s.as_str()),
+        nested @ serde_json::Value::Object(_) => (nested, nested.get("version")?.as_str()?),
+        _ => return None,
+    };
+    // Nested layouts may still keep the sha at the top level.
+    let field = |key: &str| {
+        fields
+            .get(key)
+            .or_else(|| v.get(key))
             .and_then(|s| s.as_str())
             .unwrap_or("")
-            .to_string(),
+            .to_string()
+    };
+    Some(DaemonVersion {
+        version: version.to_string(),
+        git_sha: field("git_sha"),
+        go_version: field("go_version"),
     })
 }
 
@@ -98,39 +126,39 @@ pub fn read_nix_version() -> Option<String> {
     Some(ver.trim().to_string())
 }
 
-/// Read flake.lock and extract input revisions.
-pub fn read_flake_inputs() -> Vec<FlakeInput> {
-    // Try to find flake.lock relative to the crush-dots repo.
-    let candidates = [
-        dirs::home_dir().map(|h| h.join("git/crush-dots/flake.lock")),
-        Some(PathBuf::from("/etc/crush-dots/flake.lock")),
-    ];
+/// Flake inputs shown when `build.flake_inputs` is unset.
+pub const DEFAULT_FLAKE_INPUTS: &[&str] = &[
+    "nixpkgs",
+    "nixpkgs-unstable",
+    "home-manager",
+    "sops-nix",
+    "fenix",
+];
+
+/// flake.lock locations tried when `build.flake_lock_paths` is unset.
+pub const DEFAULT_FLAKE_LOCK_PATHS: &[&str] =
+    &["~/git/crush-dots/flake.lock", "/etc/crush-dots/flake.lock"];
 
-    for candidate in candidates.iter().flatten() {
-        if let Some(inputs) = parse_flake_lock(candidate) {
+/// Read the first flake.lock found in `build.flake_lock_paths` and extract
+/// the configured input revisions.
+pub fn read_flake_inputs(cfg: &TuiConfig) -> Vec<FlakeInput> {
+    let interesting = cfg.flake_inputs();
+    for candidate in cfg.flake_lock_paths() {
+        if let Some(inputs) = parse_flake_lock(&candidate, &interesting) {
             return inputs;
         }
     }
     Vec::new()
 }
 
-fn parse_flake_lock(path: &PathBuf) -> Option<Vec<FlakeInput>> {
+fn parse_flake_lock(path: &Path, interesting: &[String]) -> Option<Vec<FlakeInput>> {
     let contents = std::fs::read_to_string(path).ok()?;
     let lock: serde_json::Value = serde_json::from_str(&contents).ok()?;
     let nodes = lock.get("nodes")?.as_object()?;
 
     let mut inputs = Vec::new();
-    // Key inputs we care about.
-    let interesting = [
-        "nixpkgs",
-        "nixpkgs-unstable",
-        "home-manager",
-        "sops-nix",
-        "fenix",
-    ];
-
-    for name in &interesting {
-        if let Some(node) = nodes.get(*name) {
+    for name in interesting {
+        if let Some(node) = nodes.get(name) {
             if let Some(locked) = node.get("locked") {
                 let rev = locked
                     .get("rev")
@@ -155,7 +183,7 @@ pub fn collect_versions(cfg: &TuiConfig) -> ComponentVersions {
         daemon: read_daemon_version(cfg),
         hm_generation: read_hm_generation(),
         nix_version: read_nix_version(),
-        flake_inputs: read_flake_inputs(),
+        flake_inputs: read_flake_inputs(cfg),
     }
 }
 
@@ -163,6 +191,54 @@ pub fn collect_versions(cfg: &TuiConfig) -> ComponentVersions {
 mod tests {
     use super::*;
 
+    fn defaults() -> Vec<String> {
+        DEFAULT_FLAKE_INPUTS.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_about_fields_daemon() {
+        let daemon = DaemonVersion {
+            version: "1.4.0".into(),
+            git_sha: "0123456789abcdef".into(),
+            go_version: "go1.23".into(),
+        };
+        let fields = about_fields(Some(&daemon));
+        let keys: Vec<&str> = fields.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, ["Version", "Git SHA", "Dirty", "Daemon"]);
+        assert_eq!(fields[3].1, "1.4.0 (01234567)");
+        assert_eq!(about_fields(None)[3].1, "not detected");
+    }
+
+    #[test]
+    fn test_parse_daemon_version_flat() {
+        let v = serde_json::json!({
+            "version": "1.4.0",
+            "git_sha": "0123456789abcdef",
+            "go_version": "go1.23",
+            "uptime": 42
+        });
+        let d = parse_daemon_version(&v).unwrap();
+        assert_eq!(d.version, "1.4.0");
+        assert_eq!(d.git_sha, "0123456789abcdef");
+        assert_eq!(d.go_version, "go1.23");
+    }
+
+    #[test]
+    fn test_parse_daemon_version_nested() {
+        let v = serde_json::json!({
+            "version": { "version": "2.0.1", "go_version": "go1.24" },
+            "git_sha": "fedcba9876543210"
+        });
+        let d = parse_daemon_version(&v).unwrap();
+        assert_eq!(d.version, "2.0.1");
+        assert_eq!(d.go_version, "go1.24");
+        assert_eq!(d.git_sha, "fedcba9876543210");
+
+        assert!(parse_daemon_version(&serde_json::json!({ "version": 3 })).is_none());
+        assert!(parse_daemon_version(&serde_json::json!({ "version": {} })).is_none());
+        assert!(parse_daemon_version(&serde_json::json!({ "pid": 1 })).is_none());
+    }
+
     #[test]
     fn test_parse_flake_lock_extracts_interesting() {
         let lock_json = r#"{
@@ -174,13 +250,36 @@ mod tests {
         }"#;
         let tmp = tempfile::NamedTempFile::new().unwrap();
         std::fs::write(tmp.path(), lock_json).unwrap();
-        let inputs = parse_flake_lock(&tmp.path().to_path_buf()).unwrap();
+        let inputs = parse_flake_lock(tmp.path(), &defaults()).unwrap();
         let names: Vec<&str> = inputs.iter().map(|i| i.name.as_str()).collect();
         assert!(names.contains(&"nixpkgs"));
         assert!(names.contains(&"home-manager"));
         assert!(!names.contains(&"unrelated"));
     }
 
+    #[test]
+    fn test_read_flake_inputs_uses_configured_names_and_paths() {
+        let lock_json = r#"{
+            "nodes": {
+                "nixpkgs": { "locked": { "rev": "abcdef1234567890" } },
+                "nix-darwin": { "locked": { "rev": "0123456789abcdef" } }
+            }
+        }"#;
+        let dir = tempfile::tempdir().unwrap();
+        let lock = dir.path().join("flake.lock");
+        std::fs::write(&lock, lock_json).unwrap();
+        let mut cfg = TuiConfig::default();
+        cfg.build.flake_inputs = Some(vec!["nix-darwin".into()]);
+        cfg.build.flake_lock_paths = Some(vec![
+            dir.path().join("missing.lock").display().to_string(),
+            lock.display().to_string(),
+        ]);
+        let inputs = read_flake_inputs(&cfg);
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name, "nix-darwin");
+        assert_eq!(inputs[0].rev, "01234567");
+    }
+
     #[test]
     fn test_parse_flake_lock_truncates_rev() {
         let lock_json = r#"{
@@ -190,7 +289,7 @@ mod tests {
         }"#;
         let tmp = tempfile::NamedTempFile::new().unwrap();
         std::fs::write(tmp.path(), lock_json).unwrap();
-        let inputs = parse_flake_lock(&tmp.path().to_path_buf()).unwrap();
+        let inputs = parse_flake_lock(tmp.path(), &defaults()).unwrap();
         assert_eq!(inputs[0].rev, "abcdef12");
     }
 }
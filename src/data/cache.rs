@@ -1,19 +1,102 @@
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::time::{Duration, SystemTime};
 
-use crate::data::claudepersonal::{self, ClaudePersonalReport, ClaudePersonalState};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::data::claudepersonal::{
+    self, ClaudePersonalReport, ClaudePersonalState, PersonalLimits,
+};
 use crate::data::{BillingReport, ClaudeUsage, K8sStatus, TailscaleStatus};
 
-const MAX_CACHE_AGE: Duration = Duration::from_secs(300); // 5 minutes
+const MAX_CACHE_AGE: Duration = Duration::from_secs(300); // 5 minutes (default)
+
+/// Health of one cache file, as the widgets would see it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheFileStatus {
+    Ok,
+    /// Older than the source's max age (widgets treat it as missing).
+    Stale,
+    /// Unreadable or fails to deserialize; holds the error text.
+    Error(String),
+}
+
+/// One entry in the `--debug` cache inspector.
+#[derive(Debug, Clone)]
+pub struct CacheFileInfo {
+    /// Cache key (file stem), e.g. "billing".
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    /// Time since last modification (None if the mtime is unavailable).
+    pub age: Option<Duration>,
+    pub status: CacheFileStatus,
+}
+
+impl CacheFileInfo {
+    /// File name on disk, e.g. "billing.json.gz".
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.name.clone())
+    }
+}
 
 /// Reads JSON cache files written by the Go daemon.
 pub struct CacheReader {
     dir: PathBuf,
+    /// Per-source max-age overrides keyed by cache name (e.g. "billing").
+    max_age: HashMap<String, Duration>,
+    /// Configured personal-plan window/limit, applied over the daemon's.
+    personal_limits: PersonalLimits,
+    /// Other machines' cache dirs (host label, path) merged into billing.
+    extra_dirs: Vec<(String, PathBuf)>,
+    /// Claude monthly caps by account name, for accounts the daemon sent none for.
+    account_caps: HashMap<String, f64>,
 }
 
 impl CacheReader {
     pub fn new(dir: PathBuf) -> Self {
-        Self { dir }
+        Self {
+            dir,
+            max_age: HashMap::new(),
+            personal_limits: PersonalLimits::default(),
+            extra_dirs: Vec::new(),
+            account_caps: HashMap::new(),
+        }
+    }
+
+    /// Builder: set per-source max-age overrides.
+    pub fn with_max_age(mut self, max_age: HashMap<String, Duration>) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Builder: override the personal-plan window and message limit.
+    pub fn with_personal_limits(mut self, limits: PersonalLimits) -> Self {
+        self.personal_limits = limits;
+        self
+    }
+
+    /// Builder: read other hosts' cache dirs too (billing totals are summed
+    /// across them, providers labeled by host).
+    pub fn with_extra_dirs(mut self, dirs: Vec<(String, PathBuf)>) -> Self {
+        self.extra_dirs = dirs;
+        self
+    }
+
+    /// Builder: fallback Claude account caps (`[claude] account_caps`).
+    pub fn with_account_caps(mut self, caps: HashMap<String, f64>) -> Self {
+        self.account_caps = caps;
+        self
+    }
+
+    /// Max age for a source: its override, or the 5-minute default.
+    fn max_age_for(&self, key: &str) -> Duration {
+        self.max_age.get(key).copied().unwrap_or(MAX_CACHE_AGE)
     }
 
     pub fn read_tailscale(&self) -> Option<TailscaleStatus> {
@@ -21,11 +104,27 @@ impl CacheReader {
     }
 
     pub fn read_claude(&self) -> Option<ClaudeUsage> {
-        self.read_json("claude")
+        let mut usage: ClaudeUsage = self.read_json("claude")?;
+        for account in &mut usage.accounts {
+            if account.cap().is_none() {
+                account.monthly_cap = self.account_caps.get(&account.name).copied();
+            }
+        }
+        Some(usage)
     }
 
+    /// This host's billing plus every extra dir's, merged; None only when
+    /// no dir has a fresh report.
     pub fn read_billing(&self) -> Option<BillingReport> {
-        self.read_json("billing")
+        let mut report: Option<BillingReport> = self.read_json("billing");
+        for (host, dir) in &self.extra_dirs {
+            if let Some(other) = self.read_json_in(dir, "billing") {
+                report
+                    .get_or_insert_with(BillingReport::default)
+                    .merge(host, other);
+            }
+        }
+        report
     }
 
     pub fn read_k8s(&self) -> Option<K8sStatus> {
@@ -35,31 +134,202 @@ impl CacheReader {
     /// Read the claude personal state file (written by Go collector, no max age).
     pub fn read_claude_personal(&self) -> Option<ClaudePersonalReport> {
         let state: ClaudePersonalState = self.read_json("claude-personal")?;
-        Some(claudepersonal::compute_report(&state))
+        Some(claudepersonal::compute_report(&state, self.personal_limits))
+    }
+
+    /// A watcher for the cache dir and the extra dirs (see `CacheWatcher::new`).
+    pub fn watcher(&self) -> Option<CacheWatcher> {
+        let extra: Vec<PathBuf> = self.extra_dirs.iter().map(|(_, dir)| dir.clone()).collect();
+        CacheWatcher::new(&self.dir, &extra)
+    }
+
+    /// Whether the cache dir exists (the daemon may not have created it yet).
+    pub fn dir_exists(&self) -> bool {
+        self.dir.is_dir()
+    }
+
+    /// When source `key`'s file (plain or gzipped) was last written; for
+    /// billing, the newest across the extra dirs too. None when no file exists.
+    pub fn modified(&self, key: &str) -> Option<SystemTime> {
+        let extra = self
+            .extra_dirs
+            .iter()
+            .filter(|_| key == "billing")
+            .map(|(_, dir)| dir.as_path());
+        std::iter::once(self.dir.as_path())
+            .chain(extra)
+            .flat_map(|dir| [format!("{key}.json"), format!("{key}.json.gz")].map(|n| dir.join(n)))
+            .filter_map(|path| std::fs::metadata(path).ok()?.modified().ok())
+            .max()
+    }
+
+    /// Every `*.json` file in the cache dir with size, age, and parse status,
+    /// sorted by name. Known sources are checked against their real types so
+    /// schema mismatches show up, not just malformed JSON.
+    pub fn list_cache_files(&self) -> Vec<CacheFileInfo> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut files: Vec<CacheFileInfo> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter_map(|path| {
+                let name = cache_key(&path)?.to_string();
+                let meta = std::fs::metadata(&path).ok()?;
+                let age = meta
+                    .modified()
+                    .ok()
+                    .and_then(|m| SystemTime::now().duration_since(m).ok());
+                let status = match read_cache_text(&path) {
+                    Err(e) => CacheFileStatus::Error(e.to_string()),
+                    Ok(data) => match check_schema(&name, &data) {
+                        Err(e) => CacheFileStatus::Error(e.to_string()),
+                        Ok(()) if age.is_some_and(|a| a > self.max_age_for(&name)) => {
+                            CacheFileStatus::Stale
+                        }
+                        Ok(()) => CacheFileStatus::Ok,
+                    },
+                };
+                Some(CacheFileInfo {
+                    name,
+                    path,
+                    size: meta.len(),
+                    age,
+                    status,
+                })
+            })
+            .collect();
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        files
     }
 
     fn read_json<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
-        let path = self.dir.join(format!("{key}.json"));
-        let meta = std::fs::metadata(&path).ok()?;
+        self.read_json_in(&self.dir, key)
+    }
+
+    fn read_json_in<T: serde::de::DeserializeOwned>(&self, dir: &Path, key: &str) -> Option<T> {
+        // Plain file first (fast path), then a gzipped copy.
+        let (path, meta) = [format!("{key}.json"), format!("{key}.json.gz")]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find_map(|path| std::fs::metadata(&path).ok().map(|meta| (path, meta)))?;
         let modified = meta.modified().ok()?;
         if SystemTime::now()
             .duration_since(modified)
             .unwrap_or(Duration::MAX)
-            > MAX_CACHE_AGE
+            > self.max_age_for(key)
         {
             return None;
         }
-        let data = std::fs::read_to_string(&path).ok()?;
+        let data = match read_cache_text(&path) {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!("cache {} read error: {e}", path.display());
+                return None;
+            }
+        };
         match serde_json::from_str(&data) {
             Ok(v) => Some(v),
             Err(e) => {
-                tracing::warn!("cache {key}.json parse error: {e}");
+                tracing::warn!("cache {} parse error: {e}", path.display());
                 None
             }
         }
     }
 }
 
+/// Cache key for a `<key>.json` or `<key>.json.gz` path; None for other files.
+fn cache_key(path: &Path) -> Option<&str> {
+    let name = path.file_name()?.to_str()?;
+    name.strip_suffix(".json")
+        .or_else(|| name.strip_suffix(".json.gz"))
+}
+
+/// Read a cache file as text, gunzipping `.gz` files.
+pub fn read_cache_text(path: &Path) -> std::io::Result<String> {
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut data = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(path)?).read_to_string(&mut data)?;
+        Ok(data)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Deserialize `data` as the type the TUI expects for cache `key`
+/// (unknown keys only need to be valid JSON).
+fn check_schema(key: &str, data: &str) -> serde_json::Result<()> {
+    match key {
+        "tailscale" => serde_json::from_str::<TailscaleStatus>(data).map(drop),
+        "claude" => serde_json::from_str::<ClaudeUsage>(data).map(drop),
+        "billing" => serde_json::from_str::<BillingReport>(data).map(drop),
+        "k8s" => serde_json::from_str::<K8sStatus>(data).map(drop),
+        "claude-personal" => serde_json::from_str::<ClaudePersonalState>(data).map(drop),
+        _ => serde_json::from_str::<serde_json::Value>(data).map(drop),
+    }
+}
+
+/// Watches the cache directory (and any extra synced dirs) so sources are
+/// only re-read when their file changes.
+pub struct CacheWatcher {
+    _watcher: RecommendedWatcher,
+    rx: mpsc::Receiver<notify::Result<notify::Event>>,
+}
+
+impl CacheWatcher {
+    /// Start watching `dir` and `extra` dirs. Returns None if `dir` can't be
+    /// watched (missing directory, inotify limits, ...); callers fall back to
+    /// polling. An unwatchable extra dir only warns: the periodic sweep
+    /// still re-reads it.
+    pub fn new(dir: &Path, extra: &[PathBuf]) -> Option<Self> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(w) => w,
+            Err(e) => {
+                tracing::warn!("cache watcher unavailable, polling instead: {e}");
+                return None;
+            }
+        };
+        if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            tracing::warn!("cannot watch {}, polling instead: {e}", dir.display());
+            return None;
+        }
+        for dir in extra {
+            if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+                tracing::warn!("cannot watch {}: {e}", dir.display());
+            }
+        }
+        Some(Self {
+            _watcher: watcher,
+            rx,
+        })
+    }
+
+    /// Drain pending events without blocking and return the cache names
+    /// (e.g. "billing") whose `.json` or `.json.gz` file changed.
+    pub fn poll(&self) -> HashSet<String> {
+        let mut changed = HashSet::new();
+        while let Ok(res) = self.rx.try_recv() {
+            let event = match res {
+                Ok(event) => event,
+                Err(e) => {
+                    tracing::warn!("cache watcher error: {e}");
+                    continue;
+                }
+            };
+            if event.kind.is_access() {
+                continue;
+            }
+            for path in &event.paths {
+                if let Some(key) = cache_key(path) {
+                    changed.insert(key.to_string());
+                }
+            }
+        }
+        changed
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +343,22 @@ mod tests {
         assert!(reader.read_billing().is_some());
     }
 
+    #[test]
+    fn test_read_claude_fills_configured_caps() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let json = r#"{"accounts": [
+            {"name": "work"},
+            {"name": "team", "monthly_cap": 500}
+        ]}"#;
+        std::fs::write(tmp.path().join("claude.json"), json).unwrap();
+        let caps = HashMap::from([("work".to_string(), 100.0), ("team".to_string(), 50.0)]);
+        let reader = CacheReader::new(tmp.path().to_path_buf()).with_account_caps(caps);
+        let usage = reader.read_claude().unwrap();
+        assert_eq!(usage.accounts[0].cap(), Some(100.0));
+        // The daemon's own cap wins over config.
+        assert_eq!(usage.accounts[1].cap(), Some(500.0));
+    }
+
     #[test]
     fn test_cache_reader_missing_file() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -88,6 +374,76 @@ mod tests {
         assert!(reader.read_billing().is_none());
     }
 
+    #[test]
+    fn test_cache_reader_gzip_fallback() {
+        use std::io::Write;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let json = r#"{"providers":[],"total_monthly_usd":42.5,"budget_usd":100}"#;
+        let file = std::fs::File::create(tmp.path().join("billing.json.gz")).unwrap();
+        let mut gz = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        gz.write_all(json.as_bytes()).unwrap();
+        gz.finish().unwrap();
+
+        let reader = CacheReader::new(tmp.path().to_path_buf());
+        let report = reader.read_billing().unwrap();
+        assert_eq!(report.total_monthly_usd, 42.5);
+
+        // The plain file wins when both exist.
+        std::fs::write(
+            tmp.path().join("billing.json"),
+            r#"{"providers":[],"total_monthly_usd":1}"#,
+        )
+        .unwrap();
+        assert_eq!(reader.read_billing().unwrap().total_monthly_usd, 1.0);
+
+        let files = reader.list_cache_files();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(|f| f.name == "billing"));
+    }
+
+    #[test]
+    fn test_cache_reader_max_age_override() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let json = r#"{"providers":[],"total_monthly_usd":0}"#;
+        std::fs::write(tmp.path().join("billing.json"), json).unwrap();
+        let reader = CacheReader::new(tmp.path().to_path_buf())
+            .with_max_age(HashMap::from([("billing".to_string(), Duration::ZERO)]));
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(reader.read_billing().is_none());
+        assert_eq!(reader.max_age_for("tailscale"), MAX_CACHE_AGE);
+    }
+
+    #[test]
+    fn test_cache_reader_merges_extra_dirs() {
+        let local = tempfile::TempDir::new().unwrap();
+        let laptop = tempfile::TempDir::new().unwrap();
+        let missing = local.path().join("nope");
+        std::fs::write(
+            laptop.path().join("billing.json"),
+            r#"{"providers":[{"name":"aws"}],"total_monthly_usd":12.5}"#,
+        )
+        .unwrap();
+        let reader = CacheReader::new(local.path().to_path_buf()).with_extra_dirs(vec![
+            ("laptop".to_string(), laptop.path().to_path_buf()),
+            ("gone".to_string(), missing),
+        ]);
+
+        // No local report: the remote one still shows, labeled.
+        let report = reader.read_billing().unwrap();
+        assert_eq!(report.total_monthly_usd, 12.5);
+        assert_eq!(report.providers[0].host, "laptop");
+
+        std::fs::write(
+            local.path().join("billing.json"),
+            r#"{"providers":[{"name":"civo"}],"total_monthly_usd":7.5}"#,
+        )
+        .unwrap();
+        let report = reader.read_billing().unwrap();
+        assert_eq!(report.total_monthly_usd, 20.0);
+        assert_eq!(report.providers.len(), 2);
+    }
+
     #[test]
     fn test_cache_reader_null_fields() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -97,4 +453,76 @@ mod tests {
         let report = reader.read_billing().unwrap();
         assert!(report.providers.is_empty());
     }
+
+    #[test]
+    fn test_list_cache_files_status() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("billing.json"), r#"{"providers":[]}"#).unwrap();
+        std::fs::write(tmp.path().join("k8s.json"), "{ truncated").unwrap();
+        std::fs::write(tmp.path().join("tailscale.json"), "{}").unwrap();
+        std::fs::write(tmp.path().join("notes.txt"), "ignored").unwrap();
+        let reader = CacheReader::new(tmp.path().to_path_buf())
+            .with_max_age(HashMap::from([("tailscale".to_string(), Duration::ZERO)]));
+        std::thread::sleep(Duration::from_millis(20));
+
+        let files = reader.list_cache_files();
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["billing", "k8s", "tailscale"]);
+        assert_eq!(files[0].status, CacheFileStatus::Ok);
+        assert_eq!(files[0].size, 16);
+        assert!(matches!(files[1].status, CacheFileStatus::Error(_)));
+        assert_eq!(files[2].status, CacheFileStatus::Stale);
+    }
+
+    #[test]
+    fn test_list_cache_files_missing_dir() {
+        let reader = CacheReader::new(PathBuf::from("/nonexistent/prompt-pulse"));
+        assert!(reader.list_cache_files().is_empty());
+    }
+
+    #[test]
+    fn test_cache_watcher_reports_changed_source() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let Some(watcher) = CacheWatcher::new(tmp.path(), &[]) else {
+            return; // no watcher backend in this environment
+        };
+        std::fs::write(tmp.path().join("billing.json"), "{}").unwrap();
+        std::fs::write(tmp.path().join("notes.txt"), "ignored").unwrap();
+
+        let mut changed = HashSet::new();
+        for _ in 0..50 {
+            changed.extend(watcher.poll());
+            if changed.contains("billing") {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(changed.contains("billing"));
+        assert!(!changed.contains("notes"));
+    }
+
+    #[test]
+    fn test_cache_watcher_reports_extra_dir() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let extra = tempfile::TempDir::new().unwrap();
+        let Some(watcher) = CacheWatcher::new(tmp.path(), &[extra.path().to_path_buf()]) else {
+            return; // no watcher backend in this environment
+        };
+        std::fs::write(extra.path().join("billing.json"), "{}").unwrap();
+
+        let mut changed = HashSet::new();
+        for _ in 0..50 {
+            changed.extend(watcher.poll());
+            if changed.contains("billing") {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(changed.contains("billing"));
+    }
+
+    #[test]
+    fn test_cache_watcher_missing_dir() {
+        assert!(CacheWatcher::new(Path::new("/nonexistent/prompt-pulse"), &[]).is_none());
+    }
 }
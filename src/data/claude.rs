@@ -35,6 +35,21 @@ pub struct AccountUsage {
     pub projected_monthly: f64,
     #[serde(default)]
     pub days_remaining: i32,
+    /// Monthly spend cap in USD, from the daemon or `[claude] account_caps`.
+    #[serde(default)]
+    pub monthly_cap: Option<f64>,
+}
+
+impl AccountUsage {
+    /// The monthly cap, when one is set and positive.
+    pub fn cap(&self) -> Option<f64> {
+        self.monthly_cap.filter(|cap| *cap > 0.0)
+    }
+
+    /// This month's spend as a fraction of the cap (may exceed 1.0).
+    pub fn cap_ratio(&self) -> Option<f64> {
+        self.cap().map(|cap| self.current_month.cost_usd / cap)
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -95,6 +110,19 @@ mod tests {
         assert!(usage.accounts[0].models.is_empty());
     }
 
+    #[test]
+    fn test_account_cap() {
+        let json = r#"{"accounts": [
+            {"name": "work", "monthly_cap": 200, "current_month": {"cost_usd": 150}},
+            {"name": "side", "monthly_cap": 0},
+            {"name": "old"}
+        ]}"#;
+        let usage: ClaudeUsage = serde_json::from_str(json).unwrap();
+        assert_eq!(usage.accounts[0].cap_ratio(), Some(0.75));
+        assert_eq!(usage.accounts[1].cap(), None);
+        assert_eq!(usage.accounts[2].cap(), None);
+    }
+
     #[test]
     fn test_claude_null_workspaces() {
         let json = r#"{"accounts": [{"name": "test", "models": [], "workspaces": null}]}"#;
@@ -23,6 +23,12 @@ pub struct PersonalMessage {
     pub source: String,
 }
 
+/// Histogram bucket width for the message-rate graph.
+const BUCKET_MINUTES: i64 = 5;
+
+/// Most recent messages whose spacing sets the rate for the exhaust ETA.
+const RATE_SAMPLE: usize = 10;
+
 fn default_window_hours() -> i32 {
     5
 }
@@ -30,6 +36,14 @@ fn default_message_limit() -> i32 {
     45
 }
 
+/// User overrides for the plan's window and limit (`[claude]` config).
+/// `None` keeps the value the daemon persisted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonalLimits {
+    pub window_hours: Option<i32>,
+    pub message_limit: Option<i32>,
+}
+
 /// Computed report for the TUI widget.
 #[derive(Debug, Clone)]
 pub struct ClaudePersonalReport {
@@ -38,12 +52,23 @@ pub struct ClaudePersonalReport {
     pub window_hours: i32,
     /// Seconds until the oldest message in the window expires (0 if under limit).
     pub next_slot_secs: i64,
+    /// Seconds until the oldest message in the window expires, limit or not
+    /// (0 when the window is empty).
+    pub first_slot_secs: i64,
+    /// Estimated seconds until the limit is reached at the recent message
+    /// rate (None at the limit or with too few messages to tell).
+    pub exhaust_eta_secs: Option<i64>,
+    /// Messages per 5-minute bucket across the window (oldest first).
+    pub buckets: Vec<i32>,
 }
 
-/// Compute a usage report from the persisted state.
-pub fn compute_report(state: &ClaudePersonalState) -> ClaudePersonalReport {
+/// Compute a usage report from the persisted state, with any configured
+/// overrides taking precedence over the daemon's window and limit.
+pub fn compute_report(state: &ClaudePersonalState, limits: PersonalLimits) -> ClaudePersonalReport {
+    let window_hours = limits.window_hours.unwrap_or(state.window_hours);
+    let message_limit = limits.message_limit.unwrap_or(state.message_limit);
     let now = Utc::now();
-    let window = chrono::Duration::hours(state.window_hours as i64);
+    let window = chrono::Duration::hours(window_hours as i64);
     let cutoff = now - window;
 
     let mut in_window: Vec<DateTime<Utc>> = state
@@ -59,21 +84,51 @@ pub fn compute_report(state: &ClaudePersonalState) -> ClaudePersonalReport {
     let messages_in_window = in_window.len() as i32;
 
     // Time until oldest message in window expires.
-    let next_slot_secs = if messages_in_window >= state.message_limit && !in_window.is_empty() {
-        let oldest = in_window[0];
-        let expires_at = oldest + window;
-        let remaining = expires_at - now;
-        remaining.num_seconds().max(0)
+    let first_slot_secs = in_window
+        .first()
+        .map(|oldest| (*oldest + window - now).num_seconds().max(0))
+        .unwrap_or(0);
+    let next_slot_secs = if messages_in_window >= message_limit {
+        first_slot_secs
     } else {
         0
     };
+    let exhaust_eta_secs = exhaust_eta(&in_window, message_limit, now);
+
+    // Bucket messages by age for the rate graph.
+    let bucket_count = ((window_hours.max(0) as i64 * 60) / BUCKET_MINUTES).max(1) as usize;
+    let mut buckets = vec![0; bucket_count];
+    for dt in &in_window {
+        let offset_mins = (*dt - cutoff).num_minutes().max(0);
+        let idx = ((offset_mins / BUCKET_MINUTES) as usize).min(bucket_count - 1);
+        buckets[idx] += 1;
+    }
 
     ClaudePersonalReport {
         messages_in_window,
-        message_limit: state.message_limit,
-        window_hours: state.window_hours,
+        message_limit,
+        window_hours,
         next_slot_secs,
+        first_slot_secs,
+        exhaust_eta_secs,
+        buckets,
+    }
+}
+
+/// Seconds until `limit` is reached if messages keep arriving at the mean
+/// spacing of the last few, counted from the latest one. `sorted` is the
+/// in-window timestamps, oldest first. None once that moment has passed
+/// without the limit being hit (the burst stopped).
+fn exhaust_eta(sorted: &[DateTime<Utc>], limit: i32, now: DateTime<Utc>) -> Option<i64> {
+    let remaining = limit as i64 - sorted.len() as i64;
+    let recent = &sorted[sorted.len().saturating_sub(RATE_SAMPLE)..];
+    if remaining <= 0 || recent.len() < 2 {
+        return None;
     }
+    let last = *recent.last()?;
+    let spacing = (last - recent[0]).num_seconds() / (recent.len() as i64 - 1);
+    let eta = (last + chrono::Duration::seconds(spacing * remaining) - now).num_seconds();
+    (eta > 0).then_some(eta)
 }
 
 #[cfg(test)]
@@ -101,7 +156,7 @@ mod tests {
         let now = chrono::Utc::now();
         let ts = (now - chrono::Duration::minutes(30)).to_rfc3339();
         let state = make_state(vec![ts], 5, 45);
-        let report = compute_report(&state);
+        let report = compute_report(&state, PersonalLimits::default());
         assert_eq!(report.messages_in_window, 1);
         assert_eq!(report.next_slot_secs, 0);
     }
@@ -113,7 +168,7 @@ mod tests {
             .map(|i| (now - chrono::Duration::minutes(i * 5)).to_rfc3339())
             .collect();
         let state = make_state(timestamps, 5, 45);
-        let report = compute_report(&state);
+        let report = compute_report(&state, PersonalLimits::default());
         assert_eq!(report.messages_in_window, 45);
         assert!(report.next_slot_secs > 0);
     }
@@ -121,7 +176,7 @@ mod tests {
     #[test]
     fn test_compute_report_empty() {
         let state = make_state(vec![], 5, 45);
-        let report = compute_report(&state);
+        let report = compute_report(&state, PersonalLimits::default());
         assert_eq!(report.messages_in_window, 0);
         assert_eq!(report.next_slot_secs, 0);
     }
@@ -130,17 +185,87 @@ mod tests {
     fn test_compute_report_all_expired() {
         let old = (chrono::Utc::now() - chrono::Duration::hours(10)).to_rfc3339();
         let state = make_state(vec![old], 5, 45);
-        let report = compute_report(&state);
+        let report = compute_report(&state, PersonalLimits::default());
         assert_eq!(report.messages_in_window, 0);
     }
 
+    #[test]
+    fn test_compute_report_buckets() {
+        let now = chrono::Utc::now();
+        let timestamps = vec![
+            (now - chrono::Duration::minutes(1)).to_rfc3339(),
+            (now - chrono::Duration::minutes(2)).to_rfc3339(),
+            (now - chrono::Duration::minutes(282)).to_rfc3339(),
+        ];
+        let state = make_state(timestamps, 5, 45);
+        let report = compute_report(&state, PersonalLimits::default());
+        assert_eq!(report.buckets.len(), 60);
+        assert_eq!(report.buckets.iter().sum::<i32>(), 3);
+        assert_eq!(*report.buckets.last().unwrap(), 2);
+        assert_eq!(report.buckets[3], 1);
+    }
+
+    #[test]
+    fn test_compute_report_overrides() {
+        let now = chrono::Utc::now();
+        // 7h old: outside the daemon's 5h window, inside an 8h override.
+        let ts = (now - chrono::Duration::hours(7)).to_rfc3339();
+        let state = make_state(vec![ts], 5, 45);
+        let limits = PersonalLimits {
+            window_hours: Some(8),
+            message_limit: Some(1),
+        };
+        let report = compute_report(&state, limits);
+        assert_eq!(report.window_hours, 8);
+        assert_eq!(report.message_limit, 1);
+        assert_eq!(report.messages_in_window, 1);
+        assert!(report.next_slot_secs > 0, "override limit reached");
+        assert_eq!(report.buckets.len(), 96);
+    }
+
+    #[test]
+    fn test_compute_report_exhaust_eta() {
+        let now = chrono::Utc::now();
+        // One message every 10 minutes, latest just now: 5 left -> ~50m.
+        let timestamps: Vec<String> = (0..5)
+            .map(|i| (now - chrono::Duration::minutes(i * 10)).to_rfc3339())
+            .collect();
+        let state = make_state(timestamps, 5, 10);
+        let report = compute_report(&state, PersonalLimits::default());
+        let eta = report.exhaust_eta_secs.unwrap();
+        assert!((2990..=3000).contains(&eta), "eta {eta}");
+        // Oldest (40m ago) frees its slot in ~4h20m, well after the ETA.
+        assert!(report.first_slot_secs > eta);
+        assert_eq!(report.next_slot_secs, 0);
+
+        let single = make_state(vec![now.to_rfc3339()], 5, 10);
+        assert_eq!(
+            compute_report(&single, PersonalLimits::default()).exhaust_eta_secs,
+            None
+        );
+    }
+
+    #[test]
+    fn test_exhaust_eta_clears_after_burst_then_idle() {
+        let now = chrono::Utc::now();
+        // A message a minute, the last one an hour ago: the projected
+        // limit (5m after it) is long past.
+        let timestamps: Vec<String> = (0..5)
+            .map(|i| (now - chrono::Duration::minutes(60 + i)).to_rfc3339())
+            .collect();
+        let state = make_state(timestamps, 5, 10);
+        let report = compute_report(&state, PersonalLimits::default());
+        assert_eq!(report.messages_in_window, 5);
+        assert_eq!(report.exhaust_eta_secs, None);
+    }
+
     #[test]
     fn test_compute_report_mixed() {
         let now = chrono::Utc::now();
         let recent = (now - chrono::Duration::minutes(30)).to_rfc3339();
         let old = (now - chrono::Duration::hours(10)).to_rfc3339();
         let state = make_state(vec![recent, old], 5, 45);
-        let report = compute_report(&state);
+        let report = compute_report(&state, PersonalLimits::default());
         assert_eq!(report.messages_in_window, 1);
     }
 }
@@ -0,0 +1,239 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::sysmetrics::SysSnapshot;
+use super::{BillingReport, TailscaleStatus};
+
+/// Serializable one-shot snapshot for `--once` (text or `--format json`).
+///
+/// Timestamps serialize as RFC3339 and all metrics as plain JSON numbers.
+#[derive(Debug, Serialize)]
+pub struct SnapshotDto {
+    pub timestamp: DateTime<Utc>,
+    pub hostname: String,
+    pub os: String,
+    pub kernel: String,
+    pub arch: String,
+    pub uptime_secs: u64,
+    pub cpu: CpuDto,
+    pub memory: MemoryDto,
+    pub load_avg: [f64; 3],
+    pub disks: Vec<DiskDto>,
+    pub networks: Vec<NetDto>,
+    pub temperatures: Vec<TempDto>,
+    pub battery: Option<BatteryDto>,
+    pub tailscale: Option<TailscaleDto>,
+    pub billing: Option<BillingDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CpuDto {
+    pub brand: String,
+    pub count: usize,
+    pub total_percent: f32,
+    pub per_core_percent: Vec<f32>,
+    pub freq_mhz: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemoryDto {
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub available_bytes: u64,
+    pub percent: f64,
+    pub swap_total_bytes: u64,
+    pub swap_used_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiskDto {
+    pub mount: String,
+    pub fs_type: String,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub percent: f64,
+    pub read_rate: Option<u64>,
+    pub write_rate: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetDto {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_rate: u64,
+    pub tx_rate: u64,
+    pub up: Option<bool>,
+    pub mac: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TempDto {
+    pub label: String,
+    pub celsius: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatteryDto {
+    pub percent: f32,
+    pub charging: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TailscaleDto {
+    pub tailnet: String,
+    pub online_peers: usize,
+    pub total_peers: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BillingDto {
+    pub total_monthly_usd: f64,
+    pub budget_usd: f64,
+    pub budget_percent: f64,
+}
+
+impl SnapshotDto {
+    pub fn new(
+        snap: &SysSnapshot,
+        tailscale: Option<&TailscaleStatus>,
+        billing: Option<&BillingReport>,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            timestamp,
+            hostname: snap.hostname.clone(),
+            os: snap.os_name.clone(),
+            kernel: snap.kernel_version.clone(),
+            arch: snap.arch.clone(),
+            uptime_secs: snap.uptime_secs,
+            cpu: CpuDto {
+                brand: snap.cpu_brand.clone(),
+                count: snap.cpu_count,
+                total_percent: snap.cpu_total,
+                per_core_percent: snap.cpu_usage.clone(),
+                freq_mhz: snap.cpu_freq_mhz,
+            },
+            memory: MemoryDto {
+                total_bytes: snap.mem_total,
+                used_bytes: snap.mem_used,
+                available_bytes: snap.mem_available,
+                percent: snap.mem_percent,
+                swap_total_bytes: snap.swap_total,
+                swap_used_bytes: snap.swap_used,
+            },
+            load_avg: snap.load_avg,
+            disks: snap
+                .disks
+                .iter()
+                .map(|d| DiskDto {
+                    mount: d.mount.clone(),
+                    fs_type: d.fs_type.clone(),
+                    total_bytes: d.total,
+                    used_bytes: d.used,
+                    percent: d.percent,
+                    read_rate: d.read_rate,
+                    write_rate: d.write_rate,
+                })
+                .collect(),
+            networks: snap
+                .networks
+                .iter()
+                .map(|n| NetDto {
+                    name: n.name.clone(),
+                    rx_bytes: n.rx_bytes,
+                    tx_bytes: n.tx_bytes,
+                    rx_rate: n.rx_rate,
+                    tx_rate: n.tx_rate,
+                    up: n.up,
+                    mac: n.mac.clone(),
+                })
+                .collect(),
+            temperatures: snap
+                .temperatures
+                .iter()
+                .map(|t| TempDto {
+                    label: t.label.clone(),
+                    celsius: t.temp_c,
+                })
+                .collect(),
+            battery: snap.battery.as_ref().map(|b| BatteryDto {
+                percent: b.percent,
+                charging: b.charging,
+            }),
+            tailscale: tailscale.map(|ts| TailscaleDto {
+                tailnet: ts.tailnet_name.clone(),
+                online_peers: ts.peers.iter().filter(|p| p.online).count(),
+                total_peers: ts.peers.len(),
+            }),
+            billing: billing.map(|b| BillingDto {
+                total_monthly_usd: b.total_monthly_usd,
+                budget_usd: b.budget_usd,
+                budget_percent: b.budget_percent,
+            }),
+        }
+    }
+
+    /// Compact human-readable summary (default `--once` output).
+    pub fn to_text(&self) -> String {
+        const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let mut lines = vec![
+            format!("{} ({}, {})", self.hostname, self.os, self.arch),
+            format!(
+                "cpu     {:.1}% of {} cores",
+                self.cpu.total_percent, self.cpu.count
+            ),
+            format!(
+                "memory  {:.1}% ({:.1}G / {:.1}G)",
+                self.memory.percent,
+                self.memory.used_bytes as f64 / GIB,
+                self.memory.total_bytes as f64 / GIB
+            ),
+            format!(
+                "load    {:.2} {:.2} {:.2}",
+                self.load_avg[0], self.load_avg[1], self.load_avg[2]
+            ),
+        ];
+        for d in &self.disks {
+            lines.push(format!("disk    {} {:.0}%", d.mount, d.percent));
+        }
+        if let Some(ts) = &self.tailscale {
+            lines.push(format!(
+                "tailnet {} ({}/{} online)",
+                ts.tailnet, ts.online_peers, ts.total_peers
+            ));
+        }
+        if let Some(b) = &self.billing {
+            lines.push(format!("billing ${:.2} this month", b.total_monthly_usd));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::SysMetrics;
+
+    #[test]
+    fn test_json_numbers_and_rfc3339() {
+        let sys = SysMetrics::empty();
+        let ts: TailscaleStatus = serde_json::from_str(
+            r#"{"tailnet_name": "tn", "peers": [{"hostname": "a", "online": true}, {"hostname": "b"}]}"#,
+        )
+        .unwrap();
+        let when = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let dto = SnapshotDto::new(&sys.snapshot(), Some(&ts), None, when);
+        let value = serde_json::to_value(&dto).unwrap();
+
+        assert_eq!(value["timestamp"], "2026-01-02T03:04:05Z");
+        assert!(value["uptime_secs"].is_u64());
+        assert!(value["memory"]["percent"].is_number());
+        assert!(value["load_avg"][0].is_number());
+        assert_eq!(value["tailscale"]["online_peers"], 1);
+        assert_eq!(value["tailscale"]["total_peers"], 2);
+        assert!(value["billing"].is_null());
+    }
+}
@@ -3,14 +3,16 @@ pub mod buildinfo;
 pub mod cache;
 pub mod claude;
 pub mod claudepersonal;
+pub mod export;
 pub mod k8s;
+pub mod prometheus;
 pub mod sysmetrics;
 pub mod tailscale;
 pub mod waifu;
 pub mod waifu_client;
 
 pub use billing::BillingReport;
-pub use cache::CacheReader;
+pub use cache::{CacheFileInfo, CacheFileStatus, CacheReader, CacheWatcher};
 pub use claude::ClaudeUsage;
 pub use k8s::K8sStatus;
 pub use sysmetrics::SysMetrics;
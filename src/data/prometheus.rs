@@ -0,0 +1,172 @@
+//! node_exporter textfile-collector output for `--prometheus <path>`.
+//!
+//! Written from the normal refresh loop, so the running TUI doubles as a
+//! lightweight exporter. Metric names are prefixed `prompt_pulse_`.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::sysmetrics::SysSnapshot;
+
+/// Render one snapshot in the Prometheus text exposition format.
+pub fn render(snap: &SysSnapshot) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: &[(String, f64)]| {
+        if samples.is_empty() {
+            return;
+        }
+        let _ = writeln!(out, "# HELP prompt_pulse_{name} {help}");
+        let _ = writeln!(out, "# TYPE prompt_pulse_{name} gauge");
+        for (labels, value) in samples {
+            let _ = writeln!(out, "prompt_pulse_{name}{labels} {value}");
+        }
+    };
+    let one = |v: f64| vec![(String::new(), v)];
+
+    gauge(
+        "cpu_usage_percent",
+        "Average CPU usage across all cores.",
+        &one(snap.cpu_total as f64),
+    );
+    let cores: Vec<_> = snap
+        .cpu_usage
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| (labels(&[("core", &i.to_string())]), u as f64))
+        .collect();
+    gauge("cpu_core_usage_percent", "CPU usage per core.", &cores);
+    gauge(
+        "memory_total_bytes",
+        "Total physical memory.",
+        &one(snap.mem_total as f64),
+    );
+    gauge(
+        "memory_used_bytes",
+        "Used memory.",
+        &one(snap.mem_used as f64),
+    );
+    gauge(
+        "memory_available_bytes",
+        "Memory available for new allocations.",
+        &one(snap.mem_available as f64),
+    );
+    gauge(
+        "swap_total_bytes",
+        "Total swap.",
+        &one(snap.swap_total as f64),
+    );
+    gauge("swap_used_bytes", "Used swap.", &one(snap.swap_used as f64));
+    for (i, window) in ["1", "5", "15"].iter().enumerate() {
+        gauge(
+            &format!("load{window}"),
+            &format!("{window}-minute load average."),
+            &one(snap.load_avg[i]),
+        );
+    }
+
+    let disk = |f: &dyn Fn(&super::sysmetrics::DiskInfo) -> Option<f64>| -> Vec<(String, f64)> {
+        snap.disks
+            .iter()
+            .filter_map(|d| {
+                let value = f(d)?;
+                Some((
+                    labels(&[("mount", &d.mount), ("fstype", &d.fs_type)]),
+                    value,
+                ))
+            })
+            .collect()
+    };
+    gauge(
+        "disk_total_bytes",
+        "Filesystem size.",
+        &disk(&|d| Some(d.total as f64)),
+    );
+    gauge(
+        "disk_used_bytes",
+        "Filesystem space in use.",
+        &disk(&|d| Some(d.used as f64)),
+    );
+    gauge(
+        "disk_read_bytes_per_second",
+        "Disk read rate (Linux).",
+        &disk(&|d| d.read_rate.map(|r| r as f64)),
+    );
+    gauge(
+        "disk_write_bytes_per_second",
+        "Disk write rate (Linux).",
+        &disk(&|d| d.write_rate.map(|r| r as f64)),
+    );
+
+    let net = |f: &dyn Fn(&super::sysmetrics::NetInfo) -> f64| -> Vec<(String, f64)> {
+        snap.networks
+            .iter()
+            .map(|n| (labels(&[("interface", &n.name)]), f(n)))
+            .collect()
+    };
+    gauge(
+        "network_receive_bytes_per_second",
+        "Interface receive rate.",
+        &net(&|n| n.rx_rate as f64),
+    );
+    gauge(
+        "network_transmit_bytes_per_second",
+        "Interface transmit rate.",
+        &net(&|n| n.tx_rate as f64),
+    );
+    out
+}
+
+/// Write `render(snap)` to `path` atomically: a sibling `.tmp` file (which
+/// the textfile collector ignores) renamed over the target.
+pub fn write_textfile(path: &Path, snap: &SysSnapshot) -> io::Result<()> {
+    let mut tmp = PathBuf::from(path);
+    tmp.as_mut_os_string().push(".tmp");
+    std::fs::write(&tmp, render(snap))?;
+    std::fs::rename(&tmp, path)
+}
+
+/// `{k="v",...}` with label values escaped per the exposition format.
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let body: Vec<String> = pairs
+        .iter()
+        .map(|(k, v)| {
+            let v = v
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{k}=\"{v}\"")
+        })
+        .collect();
+    format!("{{{}}}", body.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::SysMetrics;
+
+    #[test]
+    fn test_render_and_atomic_write() {
+        let text = render(&SysMetrics::empty().snapshot());
+        assert!(text.contains("# TYPE prompt_pulse_cpu_usage_percent gauge"));
+        assert!(text.contains("prompt_pulse_load15 "));
+        for line in text.lines().filter(|l| !l.starts_with('#')) {
+            let value = line.rsplit(' ').next().unwrap();
+            assert!(value.parse::<f64>().is_ok(), "bad sample: {line}");
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prompt_pulse.prom");
+        write_textfile(&path, &SysMetrics::empty().snapshot()).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("prompt_pulse_memory_total_bytes"));
+        assert!(!dir.path().join("prompt_pulse.prom.tmp").exists());
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(labels(&[("mount", "/a\"b\\c")]), r#"{mount="/a\"b\\c"}"#);
+    }
+}
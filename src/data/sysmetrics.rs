@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Instant;
 use sysinfo::{
     Components, CpuRefreshKind, Disks, MemoryRefreshKind, Networks, RefreshKind, System,
 };
@@ -11,6 +12,22 @@ pub struct SysMetrics {
     components: Components,
     /// Previous network counters for rate computation.
     prev_net: HashMap<String, (u64, u64)>,
+    /// Block-device (read, written) byte counters from the latest and the
+    /// previous refresh, keyed by kernel device name (e.g. `nvme0n1p2`).
+    disk_io: HashMap<String, (u64, u64)>,
+    prev_disk_io: HashMap<String, (u64, u64)>,
+    /// Kernel device name for each sysinfo disk name, resolved on refresh
+    /// (canonicalizing `/dev/mapper/*` symlinks is too slow for every frame).
+    disk_devices: HashMap<std::ffi::OsString, String>,
+    /// When the disk and network counters were last read, and the seconds
+    /// between the last two reads (counter deltas are divided by it to get
+    /// rates).
+    sampled_at: Instant,
+    sample_secs: f64,
+    /// Interface operational state (Linux operstate), refreshed with counters.
+    net_up: HashMap<String, bool>,
+    /// Fan speeds from hwmon (Linux), re-read on refresh.
+    fans: Vec<FanInfo>,
 }
 
 /// Snapshot of system metrics for rendering.
@@ -33,21 +50,94 @@ pub struct SysSnapshot {
     pub networks: Vec<NetInfo>,
     pub load_avg: [f64; 3],
     pub temperatures: Vec<TempInfo>,
+    /// Fan speeds (Linux hwmon; empty elsewhere or without fan sensors).
+    pub fans: Vec<FanInfo>,
     pub battery: Option<BatteryInfo>,
     pub nix_packages: usize,
     pub local_ip: String,
+    /// Primary global IPv6 address ("" when the host has no IPv6 route).
+    pub local_ip6: String,
     pub process_count: usize,
     pub arch: String,
     pub cpu_freq_mhz: u64,
     pub cpu_freqs: Vec<u64>, // per-core frequency in MHz
 }
 
+/// Relative weight of each input to `SysSnapshot::pressure_score`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PressureWeights {
+    pub cpu: f64,
+    pub mem: f64,
+    pub swap: f64,
+    pub load: f64,
+    pub temp: f64,
+}
+
+impl Default for PressureWeights {
+    fn default() -> Self {
+        Self {
+            cpu: 0.3,
+            mem: 0.3,
+            swap: 0.1,
+            load: 0.2,
+            temp: 0.1,
+        }
+    }
+}
+
+impl SysSnapshot {
+    /// Composite 0-100 pressure (lower is healthier): the weighted mean of
+    /// CPU %, memory %, swap %, 1-min load per core (100% = one runnable task
+    /// per core), and the hottest sensor as a share of 100 °C. Inputs the
+    /// machine doesn't report (no swap, no sensors) drop out of the mean.
+    pub fn pressure_score(&self, weights: &PressureWeights) -> f64 {
+        let mut inputs = vec![
+            (weights.cpu, self.cpu_total as f64),
+            (weights.mem, self.mem_percent),
+            (
+                weights.load,
+                self.load_avg[0] / self.cpu_count.max(1) as f64 * 100.0,
+            ),
+        ];
+        if self.swap_total > 0 {
+            let swap = self.swap_used as f64 / self.swap_total as f64 * 100.0;
+            inputs.push((weights.swap, swap));
+        }
+        if let Some(hottest) = self.temperatures.iter().map(|t| t.temp_c).reduce(f32::max) {
+            inputs.push((weights.temp, hottest as f64));
+        }
+        let total_weight: f64 = inputs.iter().map(|(w, _)| w).sum();
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        let sum: f64 = inputs.iter().map(|(w, v)| w * v.clamp(0.0, 100.0)).sum();
+        sum / total_weight
+    }
+}
+
 pub struct TempInfo {
     pub label: String,
     pub temp_c: f32,
     pub max_c: f32,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct FanInfo {
+    pub label: String,
+    pub rpm: u64,
+    /// Rated maximum from `fan*_max`, when the driver reports one.
+    pub max_rpm: Option<u64>,
+}
+
+impl FanInfo {
+    /// Speed as a percentage of the rated maximum, when known.
+    pub fn percent(&self) -> Option<f64> {
+        self.max_rpm
+            .filter(|max| *max > 0)
+            .map(|max| self.rpm as f64 / max as f64 * 100.0)
+    }
+}
+
 pub struct BatteryInfo {
     pub percent: f32,
     pub charging: bool,
@@ -62,6 +152,11 @@ pub struct DiskInfo {
     pub used: u64,
     pub percent: f64,
     pub is_removable: bool,
+    /// Bytes read/written per second over the last refresh interval (None
+    /// until two samples exist, or where the platform exposes no per-device
+    /// counters).
+    pub read_rate: Option<u64>,
+    pub write_rate: Option<u64>,
 }
 
 pub struct NetInfo {
@@ -69,8 +164,19 @@ pub struct NetInfo {
     pub kind: NetKind,
     pub rx_bytes: u64,
     pub tx_bytes: u64,
-    pub rx_rate: u64, // bytes/sec since last refresh
-    pub tx_rate: u64, // bytes/sec since last refresh
+    pub rx_rate: u64, // bytes/sec over the last refresh interval
+    pub tx_rate: u64, // bytes/sec over the last refresh interval
+    /// Operational state: Some(true) up, Some(false) down, None unknown.
+    pub up: Option<bool>,
+    /// Hardware address, when the interface has one.
+    pub mac: Option<String>,
+}
+
+impl NetInfo {
+    /// Virtual interface with no traffic since the last refresh.
+    pub fn is_idle_virtual(&self) -> bool {
+        matches!(self.kind, NetKind::Virtual) && self.rx_rate == 0 && self.tx_rate == 0
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -122,6 +228,13 @@ impl SysMetrics {
             networks: Networks::new_with_refreshed_list(),
             components: Components::new_with_refreshed_list(),
             prev_net: HashMap::new(),
+            disk_io: HashMap::new(),
+            prev_disk_io: HashMap::new(),
+            disk_devices: HashMap::new(),
+            sampled_at: Instant::now(),
+            sample_secs: 1.0,
+            net_up: HashMap::new(),
+            fans: Vec::new(),
         }
     }
 
@@ -145,12 +258,22 @@ impl SysMetrics {
                 )
             })
             .collect();
+        let net_up = read_operstates(networks.list().keys().map(String::as_str));
+        let disk_devices = disk_device_keys(&disks);
         Self {
             sys,
             disks,
             networks,
             components,
             prev_net,
+            // No previous sample yet: rates stay None until the first refresh.
+            disk_io: read_disk_io(),
+            prev_disk_io: HashMap::new(),
+            disk_devices,
+            sampled_at: Instant::now(),
+            sample_secs: 1.0,
+            net_up,
+            fans: read_fans(),
         }
     }
 
@@ -158,6 +281,11 @@ impl SysMetrics {
         self.sys.refresh_cpu_all();
         self.sys.refresh_memory();
         self.disks.refresh();
+        self.disk_devices = disk_device_keys(&self.disks);
+        self.prev_disk_io = std::mem::replace(&mut self.disk_io, read_disk_io());
+        let now = Instant::now();
+        self.sample_secs = now.duration_since(self.sampled_at).as_secs_f64();
+        self.sampled_at = now;
         // Snapshot previous counters before refresh.
         self.prev_net = self
             .networks
@@ -170,7 +298,9 @@ impl SysMetrics {
             })
             .collect();
         self.networks.refresh();
+        self.net_up = read_operstates(self.networks.list().keys().map(String::as_str));
         self.components.refresh();
+        self.fans = read_fans();
     }
 
     pub fn snapshot(&self) -> SysSnapshot {
@@ -204,6 +334,15 @@ impl SysMetrics {
                 } else {
                     0.0
                 };
+                let device = self.disk_devices.get(d.name());
+                let io_rate = |pick: fn(&(u64, u64)) -> u64| {
+                    let now = self.disk_io.get(device?)?;
+                    let prev = self.prev_disk_io.get(device?)?;
+                    Some(per_second(
+                        pick(now).saturating_sub(pick(prev)),
+                        self.sample_secs,
+                    ))
+                };
                 DiskInfo {
                     mount: d.mount_point().to_string_lossy().to_string(),
                     fs_type: d.file_system().to_string_lossy().to_string(),
@@ -211,6 +350,8 @@ impl SysMetrics {
                     used,
                     percent,
                     is_removable: d.is_removable(),
+                    read_rate: io_rate(|c| c.0),
+                    write_rate: io_rate(|c| c.1),
                 }
             })
             .collect();
@@ -232,8 +373,12 @@ impl SysMetrics {
                     kind: classify_interface(name),
                     rx_bytes: rx,
                     tx_bytes: tx,
-                    rx_rate: rx.saturating_sub(prev_rx),
-                    tx_rate: tx.saturating_sub(prev_tx),
+                    rx_rate: per_second(rx.saturating_sub(prev_rx), self.sample_secs),
+                    tx_rate: per_second(tx.saturating_sub(prev_tx), self.sample_secs),
+                    up: self.net_up.get(name.as_str()).copied(),
+                    mac: Some(data.mac_address())
+                        .filter(|m| !m.is_unspecified())
+                        .map(|m| m.to_string()),
                 }
             })
             .collect();
@@ -280,9 +425,11 @@ impl SysMetrics {
             networks,
             load_avg: [load.one, load.five, load.fifteen],
             temperatures,
+            fans: self.fans.clone(),
             battery: get_battery_info(),
             nix_packages: get_nix_package_count(),
             local_ip: get_local_ip(),
+            local_ip6: get_local_ip6(),
             process_count: self.sys.processes().len(),
             arch: std::env::consts::ARCH.to_string(),
             cpu_freq_mhz: self.sys.cpus().first().map(|c| c.frequency()).unwrap_or(0),
@@ -374,6 +521,152 @@ fn get_battery_info() -> Option<BatteryInfo> {
     }
 }
 
+/// Up/down state per interface from `/sys/class/net/<if>/operstate` (Linux).
+/// Interfaces reporting "unknown" (common for tun/loopback) are left out.
+fn read_operstates<'a>(names: impl Iterator<Item = &'a str>) -> HashMap<String, bool> {
+    #[cfg(target_os = "linux")]
+    {
+        names
+            .filter_map(|name| {
+                let state =
+                    std::fs::read_to_string(format!("/sys/class/net/{name}/operstate")).ok()?;
+                parse_operstate(&state).map(|up| (name.to_string(), up))
+            })
+            .collect()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = names;
+        HashMap::new()
+    }
+}
+
+/// Map an operstate string to up (true) / down (false); None when indeterminate.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_operstate(state: &str) -> Option<bool> {
+    match state.trim() {
+        "up" => Some(true),
+        "down" | "lowerlayerdown" | "notpresent" => Some(false),
+        _ => None,
+    }
+}
+
+/// Kernel device names for every sysinfo disk, keyed by disk name.
+fn disk_device_keys(disks: &Disks) -> HashMap<std::ffi::OsString, String> {
+    disks
+        .iter()
+        .map(|d| (d.name().to_os_string(), disk_device_key(d.name())))
+        .collect()
+}
+
+/// Kernel device name for a sysinfo disk (`/dev/mapper/root` -> `dm-0`).
+fn disk_device_key(name: &std::ffi::OsStr) -> String {
+    let path = std::path::Path::new(name);
+    let resolved = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    resolved
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Per-device cumulative (read, written) bytes. Linux only (/proc/diskstats);
+/// other platforms return an empty map and the disk panel omits I/O rates.
+fn read_disk_io() -> HashMap<String, (u64, u64)> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/diskstats")
+            .map(|text| parse_diskstats(&text))
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        HashMap::new()
+    }
+}
+
+/// Parse /proc/diskstats lines: `major minor name reads merged sectors_read
+/// ms writes merged sectors_written ...`. Sectors are always 512 bytes here.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_diskstats(text: &str) -> HashMap<String, (u64, u64)> {
+    const SECTOR: u64 = 512;
+    text.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let name = fields.get(2)?;
+            let read: u64 = fields.get(5)?.parse().ok()?;
+            let written: u64 = fields.get(9)?.parse().ok()?;
+            Some((name.to_string(), (read * SECTOR, written * SECTOR)))
+        })
+        .collect()
+}
+
+/// Fan speeds from `/sys/class/hwmon` (Linux); other platforms report none.
+fn read_fans() -> Vec<FanInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        read_hwmon_fans(std::path::Path::new("/sys/class/hwmon"))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Every `fan<N>_input` under the hwmon devices in `root`, labelled from
+/// `fan<N>_label` or "<chip> fan<N>". Headers reading 0 RPM with no rated
+/// maximum are usually unconnected and are skipped.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_hwmon_fans(root: &std::path::Path) -> Vec<FanInfo> {
+    let read = |path: std::path::PathBuf| std::fs::read_to_string(path).ok();
+    let mut chips: Vec<std::path::PathBuf> = std::fs::read_dir(root)
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    chips.sort();
+
+    let mut fans = Vec::new();
+    for chip in chips {
+        let chip_name = read(chip.join("name")).unwrap_or_default();
+        let mut inputs: Vec<u32> = std::fs::read_dir(&chip)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|e| {
+                        let name = e.file_name().to_string_lossy().into_owned();
+                        name.strip_prefix("fan")?
+                            .strip_suffix("_input")?
+                            .parse()
+                            .ok()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        inputs.sort_unstable();
+        for n in inputs {
+            let Some(rpm) =
+                read(chip.join(format!("fan{n}_input"))).and_then(|s| s.trim().parse::<u64>().ok())
+            else {
+                continue;
+            };
+            let max_rpm = read(chip.join(format!("fan{n}_max")))
+                .and_then(|s| s.trim().parse::<u64>().ok())
+                .filter(|max| *max > 0);
+            if rpm == 0 && max_rpm.is_none() {
+                continue;
+            }
+            let label = read(chip.join(format!("fan{n}_label")))
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| format!("{} fan{n}", chip_name.trim()).trim().to_string());
+            fans.push(FanInfo {
+                label,
+                rpm,
+                max_rpm,
+            });
+        }
+    }
+    fans
+}
+
 /// Count installed Nix profile packages (from `nix profile list`).
 /// Uses a cached value to avoid calling the command on every snapshot.
 fn get_nix_package_count() -> usize {
@@ -406,10 +699,132 @@ fn get_local_ip() -> String {
     .clone()
 }
 
+/// Primary IPv6 address, found the same way as `get_local_ip` (a UDP
+/// connect sends no packets). Empty when there is no IPv6 route.
+fn get_local_ip6() -> String {
+    use std::sync::OnceLock;
+    static IP: OnceLock<String> = OnceLock::new();
+    IP.get_or_init(|| {
+        std::net::UdpSocket::bind("[::]:0")
+            .and_then(|sock| {
+                sock.connect("[2001:4860:4860::8888]:80")?;
+                sock.local_addr()
+            })
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_default()
+    })
+    .clone()
+}
+
+/// A counter delta over `secs` seconds as a per-second rate (the delta
+/// itself when no time has passed).
+fn per_second(delta: u64, secs: f64) -> u64 {
+    if secs > 0.0 {
+        (delta as f64 / secs).round() as u64
+    } else {
+        delta
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pressure_score() {
+        let mut snap = SysMetrics::empty().snapshot();
+        snap.cpu_total = 50.0;
+        snap.mem_percent = 50.0;
+        snap.cpu_count = 4;
+        snap.load_avg = [2.0, 0.0, 0.0]; // half the cores busy
+        snap.swap_total = 0;
+        snap.temperatures.clear();
+        let weights = PressureWeights::default();
+        assert!((snap.pressure_score(&weights) - 50.0).abs() < 1e-9);
+
+        // Overloaded inputs clamp at 100; missing swap/temps don't count as 0.
+        snap.load_avg = [40.0, 0.0, 0.0];
+        let only_load = PressureWeights {
+            cpu: 0.0,
+            mem: 0.0,
+            swap: 1.0,
+            load: 1.0,
+            temp: 1.0,
+        };
+        assert_eq!(snap.pressure_score(&only_load), 100.0);
+
+        let zero = PressureWeights {
+            cpu: 0.0,
+            mem: 0.0,
+            swap: 0.0,
+            load: 0.0,
+            temp: 0.0,
+        };
+        assert_eq!(snap.pressure_score(&zero), 0.0);
+    }
+
+    #[test]
+    fn test_read_hwmon_fans() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let chip = tmp.path().join("hwmon2");
+        std::fs::create_dir(&chip).unwrap();
+        let files = [
+            ("name", "nct6798\n"),
+            ("fan1_input", "1250\n"),
+            ("fan1_label", "CPU Fan\n"),
+            ("fan2_input", "800\n"),
+            ("fan2_max", "2000\n"),
+            // Unconnected header: skipped.
+            ("fan3_input", "0\n"),
+        ];
+        for (name, body) in files {
+            std::fs::write(chip.join(name), body).unwrap();
+        }
+        let fans = read_hwmon_fans(tmp.path());
+        assert_eq!(fans.len(), 2);
+        assert_eq!(fans[0].label, "CPU Fan");
+        assert_eq!(fans[0].percent(), None);
+        assert_eq!(fans[1].label, "nct6798 fan2");
+        assert_eq!(fans[1].percent(), Some(40.0));
+        assert!(read_hwmon_fans(&tmp.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_parse_operstate() {
+        assert_eq!(parse_operstate("up\n"), Some(true));
+        assert_eq!(parse_operstate("down\n"), Some(false));
+        assert_eq!(parse_operstate("lowerlayerdown"), Some(false));
+        assert_eq!(parse_operstate("unknown\n"), None);
+    }
+
+    #[test]
+    fn test_parse_diskstats() {
+        let text = "\
+ 259       0 nvme0n1 1000 10 2048 300 500 20 4096 700 0 900 1000
+ 259       2 nvme0n1p2 800 5 1024 250 400 10 8 600 0 800 850
+ 253       0 dm-0 bogus
+";
+        let io = parse_diskstats(text);
+        assert_eq!(io.get("nvme0n1"), Some(&(2048 * 512, 4096 * 512)));
+        assert_eq!(io.get("nvme0n1p2"), Some(&(1024 * 512, 8 * 512)));
+        assert!(!io.contains_key("dm-0"));
+    }
+
+    #[test]
+    fn test_per_second() {
+        assert_eq!(per_second(4096, 2.0), 2048);
+        assert_eq!(per_second(1000, 0.5), 2000);
+        assert_eq!(per_second(1000, 0.0), 1000);
+    }
+
+    #[test]
+    fn test_disk_device_key_strips_dev() {
+        assert_eq!(
+            disk_device_key(std::ffi::OsStr::new("/dev/does-not-exist-xyz")),
+            "does-not-exist-xyz"
+        );
+    }
+
     #[test]
     fn test_classify_wifi() {
         assert!(matches!(classify_interface("wlan0"), NetKind::Wifi));
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+use crate::config::IpVersion;
+
 /// Mirrors the Go tailscale.Status struct (daemon cache).
 #[derive(Debug, Deserialize)]
 pub struct TailscaleStatus {
@@ -45,9 +47,46 @@ pub struct PeerInfo {
     pub rx_bytes: i64,
     #[serde(default)]
     pub tx_bytes: i64,
+    /// Round-trip latency from the daemon's pings (absent/null = not measured).
+    #[serde(default)]
+    pub latency_ms: Option<f64>,
+}
+
+impl PeerInfo {
+    /// First Tailscale IP of the `version` family, else the first of any
+    /// (tailnets hand out both, in no guaranteed order).
+    pub fn ip(&self, version: IpVersion) -> Option<&str> {
+        self.tailscale_ips
+            .iter()
+            .find(|ip| ip.parse().is_ok_and(|addr| version.matches(&addr)))
+            .or_else(|| self.tailscale_ips.first())
+            .map(String::as_str)
+    }
+
+    /// Display name: full MagicDNS name when `fqdn` is set and known, else hostname.
+    pub fn display_name(&self, fqdn: bool) -> &str {
+        let dns = self.dns_name.trim_end_matches('.');
+        if fqdn && !dns.is_empty() {
+            dns
+        } else {
+            &self.hostname
+        }
+    }
 }
 
 impl TailscaleStatus {
+    /// This machine's node, from whichever of `self_node` / `self` is populated.
+    pub fn self_peer(&self) -> Option<&PeerInfo> {
+        self.self_node.as_ref().or(self.self_info.as_ref())
+    }
+
+    /// Whether the daemon reported latency for any online peer.
+    pub fn has_latency(&self) -> bool {
+        self.peers
+            .iter()
+            .any(|p| p.online && p.latency_ms.is_some())
+    }
+
     /// Only online peers, sorted by hostname.
     pub fn online_peers_sorted(&self) -> Vec<&PeerInfo> {
         let mut peers: Vec<&PeerInfo> = self.peers.iter().filter(|p| p.online).collect();
@@ -76,6 +115,19 @@ mod tests {
         assert!(status.peers[0].tags.is_empty());
     }
 
+    #[test]
+    fn test_peer_ip_prefers_family() {
+        let json = r#"{"peers": [{"hostname": "a", "tailscale_ips": ["fd7a:115c:a1e0::1", "100.64.0.2"]}]}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        let peer = &status.peers[0];
+        assert_eq!(peer.ip(IpVersion::V4), Some("100.64.0.2"));
+        assert_eq!(peer.ip(IpVersion::V6), Some("fd7a:115c:a1e0::1"));
+
+        let json = r#"{"peers": [{"hostname": "b", "tailscale_ips": ["100.64.0.3"]}]}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.peers[0].ip(IpVersion::V6), Some("100.64.0.3"));
+    }
+
     #[test]
     fn test_tailscale_null_ips() {
         let json = r#"{"peers": [{"hostname": "test", "tailscale_ips": null}]}"#;
@@ -83,6 +135,52 @@ mod tests {
         assert!(status.peers[0].tailscale_ips.is_empty());
     }
 
+    #[test]
+    fn test_self_peer_from_either_field() {
+        let json = r#"{"self": {"hostname": "me", "tailscale_ips": ["100.64.0.1"]}}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.self_peer().unwrap().hostname, "me");
+
+        let json = r#"{"self_node": {"hostname": "node", "exit_node": true}}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        let me = status.self_peer().unwrap();
+        assert_eq!(me.hostname, "node");
+        assert!(me.exit_node);
+
+        let status: TailscaleStatus = serde_json::from_str("{}").unwrap();
+        assert!(status.self_peer().is_none());
+    }
+
+    #[test]
+    fn test_display_name_fqdn_fallback() {
+        let json = r#"{"peers": [
+            {"hostname": "box", "dns_name": "box.tail1234.ts.net."},
+            {"hostname": "bare", "dns_name": ""}
+        ]}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.peers[0].display_name(false), "box");
+        assert_eq!(status.peers[0].display_name(true), "box.tail1234.ts.net");
+        assert_eq!(status.peers[1].display_name(true), "bare");
+    }
+
+    #[test]
+    fn test_latency_optional() {
+        let json = r#"{"peers": [
+            {"hostname": "a", "online": true, "latency_ms": 12.5},
+            {"hostname": "b", "online": true, "latency_ms": null},
+            {"hostname": "c", "online": true}
+        ]}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.peers[0].latency_ms, Some(12.5));
+        assert_eq!(status.peers[1].latency_ms, None);
+        assert_eq!(status.peers[2].latency_ms, None);
+        assert!(status.has_latency());
+
+        let json = r#"{"peers": [{"hostname": "a", "online": true}]}"#;
+        let status: TailscaleStatus = serde_json::from_str(json).unwrap();
+        assert!(!status.has_latency());
+    }
+
     #[test]
     fn test_online_peers_sorted() {
         let json = r#"{
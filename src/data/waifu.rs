@@ -1,19 +1,101 @@
+use std::io::Cursor;
+use std::time::Duration;
+
 use anyhow::Result;
-use image::DynamicImage;
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, DynamicImage, ImageFormat};
+
+/// Upper bound on decoded animation frames kept per image.
+const MAX_ANIMATION_FRAMES: usize = 200;
+
+/// Frame delays below this are treated as 100ms, matching browser behavior.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20);
 
 /// An in-memory waifu image entry (no disk cache).
 #[derive(Clone)]
 pub struct WaifuEntry {
+    pub image: DynamicImage,     // first frame for animations
+    pub name: String,            // human-readable name from ImageMeta.id
+    pub hash: String,            // dedup key (content_hash of the fetched bytes)
+    pub frames: Vec<WaifuFrame>, // all frames if animated, empty for still images
+}
+
+/// A single frame of an animated waifu image.
+#[derive(Clone)]
+pub struct WaifuFrame {
     pub image: DynamicImage,
-    pub name: String, // human-readable name from ImageMeta.id
-    pub hash: String, // dedup key
+    pub delay: Duration,
+}
+
+/// Dedup key for fetched image bytes. Cheap next to decoding, so a
+/// duplicate can be spotted before any frame is decoded.
+pub fn content_hash(data: &[u8]) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    data.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
 }
 
 /// Decode image bytes into a DynamicImage using magic byte detection.
+/// Animated formats decode to their first frame.
 pub fn decode_image_bytes(data: &[u8]) -> Result<DynamicImage> {
     Ok(image::load_from_memory(data)?)
 }
 
+/// Decode all frames of an animated GIF, APNG, or WebP.
+/// Returns an empty Vec for still images or if frame decoding fails,
+/// so callers can fall back to the single frame from `decode_image_bytes`.
+pub fn decode_animation(data: &[u8]) -> Vec<WaifuFrame> {
+    match try_decode_animation(data) {
+        Ok(frames) if frames.len() > 1 => frames,
+        Ok(_) => Vec::new(),
+        Err(e) => {
+            tracing::warn!("waifu animation decode failed, showing first frame: {e}");
+            Vec::new()
+        }
+    }
+}
+
+fn try_decode_animation(data: &[u8]) -> Result<Vec<WaifuFrame>> {
+    let cursor = Cursor::new(data);
+    let frames = match image::guess_format(data)? {
+        ImageFormat::Gif => GifDecoder::new(cursor)?.into_frames(),
+        ImageFormat::Png => {
+            let decoder = PngDecoder::new(cursor)?;
+            if !decoder.is_apng()? {
+                return Ok(Vec::new());
+            }
+            decoder.apng()?.into_frames()
+        }
+        ImageFormat::WebP => {
+            let decoder = WebPDecoder::new(cursor)?;
+            if !decoder.has_animation() {
+                return Ok(Vec::new());
+            }
+            decoder.into_frames()
+        }
+        _ => return Ok(Vec::new()),
+    };
+
+    let mut out = Vec::new();
+    for frame in frames.take(MAX_ANIMATION_FRAMES) {
+        let frame = frame?;
+        let (num, denom) = frame.delay().numer_denom_ms();
+        let delay = Duration::from_millis(u64::from(num) / u64::from(denom.max(1)));
+        out.push(WaifuFrame {
+            image: DynamicImage::ImageRgba8(frame.into_buffer()),
+            delay: if delay < MIN_FRAME_DELAY {
+                Duration::from_millis(100)
+            } else {
+                delay
+            },
+        });
+    }
+    Ok(out)
+}
+
 /// Format an image name as a human-readable string.
 /// Strips extension, replaces `_` and `-` with spaces.
 pub fn format_image_name(name: &str) -> String {
@@ -64,4 +146,46 @@ mod tests {
         let result = decode_image_bytes(b"not an image");
         assert!(result.is_err(), "should fail on invalid bytes");
     }
+
+    fn encode_gif(delays_ms: &[u32]) -> Vec<u8> {
+        use image::codecs::gif::GifEncoder;
+        use image::{Delay, Frame, RgbaImage};
+        let mut buf = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut buf);
+            let frames = delays_ms.iter().map(|&ms| {
+                Frame::from_parts(
+                    RgbaImage::new(2, 2),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(ms, 1),
+                )
+            });
+            encoder.encode_frames(frames).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn test_decode_animation_gif_frames() {
+        let data = encode_gif(&[50, 200, 0]);
+        assert!(decode_image_bytes(&data).is_ok(), "first frame decodes");
+        let frames = decode_animation(&data);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[1].delay, Duration::from_millis(200));
+        // Zero delay falls back to 100ms.
+        assert_eq!(frames[2].delay, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_decode_animation_still_image() {
+        let single = encode_gif(&[100]);
+        assert!(decode_animation(&single).is_empty());
+
+        let img = image::RgbImage::new(1, 1);
+        let mut buf = std::io::Cursor::new(Vec::new());
+        img.write_to(&mut buf, image::ImageFormat::Png).unwrap();
+        assert!(decode_animation(buf.get_ref()).is_empty());
+        assert!(decode_animation(b"not an image").is_empty());
+    }
 }
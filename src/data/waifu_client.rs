@@ -15,7 +15,6 @@ pub struct ImageMeta {
 pub struct FetchResult {
     pub data: Vec<u8>,
     pub name: String, // from ImageMeta.id
-    pub hash: String, // dedup key
 }
 
 /// Fetch a random image from the waifu mirror API.
@@ -42,6 +41,5 @@ pub async fn fetch_random(endpoint: &str, category: &str) -> Result<FetchResult>
     Ok(FetchResult {
         data: data.to_vec(),
         name: meta.id,
-        hash: meta.hash,
     })
 }
@@ -12,12 +12,16 @@ mod config;
 mod data;
 mod ui;
 
-use std::io;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+        Event, KeyCode, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -25,11 +29,15 @@ use ratatui::prelude::*;
 use ratatui_image::picker::{Picker, ProtocolType};
 use tracing_subscriber::EnvFilter;
 
-use crate::app::App;
+use crate::app::{App, WAIFU_DRAIN};
 use crate::config::TuiConfig;
 
 const TICK_RATE: Duration = Duration::from_millis(250);
 
+/// xterm window-title stack: save (CSI 22;0 t) and restore (CSI 23;0 t).
+const PUSH_TITLE: &[u8] = b"\x1b[22;0t";
+const POP_TITLE: &[u8] = b"\x1b[23;0t";
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize tracing (RUST_LOG=debug for verbose output).
@@ -38,19 +46,58 @@ async fn main() -> Result<()> {
         .with_writer(io::stderr)
         .init();
 
-    // Parse CLI args: --expand <widget-id>
+    // Parse CLI args: --expand <widget-id>, --print-keys, --version, --no-color,
+    // --once [--format text|json], --debug, --config <path>, --prometheus <path>
     let args: Vec<String> = std::env::args().collect();
+    if args.iter().any(|a| a == "--print-keys") {
+        print!("{}", ui::keymap::to_markdown());
+        return Ok(());
+    }
+    let config_override = args
+        .windows(2)
+        .find(|w| w[0] == "--config")
+        .map(|w| PathBuf::from(&w[1]));
+    let config_path = TuiConfig::resolve_path(config_override.as_deref());
+    if args.iter().any(|a| a == "--version" || a == "-V") {
+        // The config only locates the daemon's cache dir here; a missing or
+        // broken config.toml must not stop --version from printing.
+        let cfg = TuiConfig::load_from(&config_path).unwrap_or_default();
+        let daemon = data::buildinfo::read_daemon_version(&cfg);
+        println!("prompt-pulse-tui");
+        for (key, value) in data::buildinfo::about_fields(daemon.as_ref()) {
+            println!("  {key:<8} {value}");
+        }
+        return Ok(());
+    }
+    if let Some(path) = config_override.as_deref().filter(|p| !p.exists()) {
+        anyhow::bail!("config file not found: {}", path.display());
+    }
+    if args.iter().any(|a| a == "--once") {
+        let format = args
+            .windows(2)
+            .find(|w| w[0] == "--format")
+            .map_or("text", |w| w[1].as_str());
+        return print_once(&config_path, format).await;
+    }
     let expand_widget = args
         .windows(2)
         .find(|w| w[0] == "--expand")
         .map(|w| w[1].clone());
 
-    let cfg = TuiConfig::load()?;
+    let cfg = TuiConfig::load_from(&config_path)?;
 
     // Terminal setup.
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Focus reports let drawing pause while the terminal is in the background
+    // (display.pause_when_unfocused); terminals that don't support them never
+    // send FocusLost, so drawing continues.
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableFocusChange
+    )?;
 
     // Query terminal for image protocol support and font size.
     // Must be called after EnterAlternateScreen but before event loop.
@@ -83,6 +130,22 @@ async fn main() -> Result<()> {
     }
 
     let mut app = App::new(cfg, picker, expand_widget).await?;
+    if args.iter().any(|a| a == "--no-color") {
+        app.disable_colors();
+    }
+    if args.iter().any(|a| a == "--debug") {
+        app.debug_mode = true;
+    }
+    app.prometheus_path = args
+        .windows(2)
+        .find(|w| w[0] == "--prometheus")
+        .map(|w| PathBuf::from(&w[1]));
+
+    // Save the current window title on the xterm title stack so exit can
+    // put it back (terminals without the stack ignore this).
+    if app.set_title {
+        stdout.write_all(PUSH_TITLE)?;
+    }
 
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -94,22 +157,67 @@ async fn main() -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableFocusChange
     )?;
+    if app.set_title {
+        terminal.backend_mut().write_all(POP_TITLE)?;
+    }
     terminal.show_cursor()?;
 
+    // A normal quit lets a nearly-finished waifu download complete (Ctrl+C
+    // returns without setting should_quit, so it stays immediate).
+    if app.should_quit {
+        app.drain_waifu_fetch(WAIFU_DRAIN).await;
+    }
+
     result
 }
 
+/// Headless `--once`: sample metrics twice (so CPU and rates are meaningful),
+/// print one snapshot, and exit without touching the terminal.
+async fn print_once(config_path: &Path, format: &str) -> Result<()> {
+    if !matches!(format, "text" | "json") {
+        anyhow::bail!("unknown --format {format:?} (expected \"text\" or \"json\")");
+    }
+    let cfg = TuiConfig::load_from(config_path)?;
+    let cache = data::CacheReader::new(cfg.cache_dir()).with_max_age(cfg.cache_max_ages());
+    let mut sys = data::SysMetrics::collect();
+    tokio::time::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL.max(Duration::from_millis(500))).await;
+    sys.refresh();
+
+    let dto = data::export::SnapshotDto::new(
+        &sys.snapshot(),
+        cache.read_tailscale().as_ref(),
+        cache.read_billing().as_ref(),
+        chrono::Utc::now(),
+    );
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&dto)?);
+    } else {
+        println!("{}", dto.to_text());
+    }
+    Ok(())
+}
+
 async fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
 ) -> Result<()> {
     loop {
-        terminal.draw(|frame| ui::draw(frame, app))?;
+        // Unfocused terminals skip drawing; tick keeps collecting history and
+        // the first loop after FocusGained repaints.
+        if app.focused {
+            terminal.draw(|frame| ui::draw(frame, app))?;
+        }
 
-        // Poll for events with tick-rate timeout.
-        if event::poll(TICK_RATE)? {
+        // Poll for events with tick-rate timeout (shorter while a waifu
+        // animation frame is due and visible).
+        let timeout = match app.next_frame_in() {
+            Some(d) if app.focused => d.min(TICK_RATE),
+            _ => TICK_RATE,
+        };
+        if event::poll(timeout)? {
             match event::read()? {
                 Event::Key(key) => {
                     // Ctrl+C always quits.
@@ -118,15 +226,12 @@ async fn run_loop(
                     {
                         return Ok(());
                     }
-                    // q quits (unless in expand mode where Esc exits expand first).
-                    if key.code == KeyCode::Char('q') {
-                        return Ok(());
-                    }
-                    // Esc quits only if not in expand mode.
-                    if key.code == KeyCode::Esc && !app.expanded {
+                    // q / Esc quitting is handled by the app (filter input and
+                    // confirm_quit both need to intercept it).
+                    app.handle_key(key);
+                    if app.should_quit {
                         return Ok(());
                     }
-                    app.handle_key(key);
                 }
                 Event::Resize(w, h) => {
                     app.on_resize(w, h);
@@ -134,11 +239,61 @@ async fn run_loop(
                 Event::Mouse(mouse) => {
                     app.handle_mouse(mouse);
                 }
+                Event::FocusGained => {
+                    // Coming back from a pause: repaint every cell.
+                    if !app.focused {
+                        terminal.clear()?;
+                    }
+                    app.focused = true;
+                }
+                Event::FocusLost => app.focused = !app.cfg.pause_when_unfocused(),
                 _ => {}
             }
         }
 
+        // Clipboard copies (e.g. "Copy PID") go to the terminal as OSC 52.
+        if let Some(text) = app.pending_clipboard.take() {
+            let backend = terminal.backend_mut();
+            backend.write_all(osc52(&text).as_bytes())?;
+            Write::flush(backend)?;
+        }
+
         // Tick: refresh real-time data (CPU, RAM, network).
         app.tick().await;
     }
 }
+
+/// OSC 52 "set clipboard" sequence for `text` (base64 payload). Works over
+/// SSH in terminals that allow it; others ignore the sequence.
+fn osc52(text: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in text.as_bytes().chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    format!("\x1b]52;c;{encoded}\x07")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_base64_padding() {
+        assert_eq!(osc52("1234"), "\x1b]52;c;MTIzNA==\x07");
+        assert_eq!(osc52("12345"), "\x1b]52;c;MTIzNDU=\x07");
+        assert_eq!(osc52("123456"), "\x1b]52;c;MTIzNDU2\x07");
+    }
+}
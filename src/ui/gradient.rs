@@ -0,0 +1,126 @@
+use std::cell::Cell;
+
+use ratatui::style::Color;
+
+thread_local! {
+    /// Monochrome gate (NO_COLOR / --no-color), set from
+    /// `App::colors_enabled` at the start of every frame.
+    static COLORS_ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Turn the palette on or off for the frames drawn on this thread.
+pub fn set_colors_enabled(enabled: bool) {
+    COLORS_ENABLED.set(enabled);
+}
+
+/// `color`, or the terminal default in monochrome mode. Palette helpers and
+/// decorative shading return through here.
+pub fn gate(color: Color) -> Color {
+    if COLORS_ENABLED.get() {
+        color
+    } else {
+        Color::Reset
+    }
+}
+
+/// Default percentage stops: yellow-green, yellow, orange-red, red.
+pub const PCT_STOPS: [f64; 4] = [40.0, 65.0, 80.0, 90.0];
+/// Default temperature stops (°C); warms up a little later than usage.
+pub const TEMP_STOPS: [f64; 4] = [45.0, 65.0, 80.0, 90.0];
+/// Colors below the first stop, then from each stop up.
+pub const LEVEL_COLORS: [Color; 5] = [
+    Color::Green,
+    Color::Rgb(150, 255, 0), // yellow-green
+    Color::Yellow,
+    Color::Rgb(255, 100, 0), // orange-red
+    Color::Red,
+];
+
+/// Network rate stops (bytes/s) for green, cyan, yellow and magenta.
+pub const NET_RATE_STOPS: [u64; 4] = [1024, 100 * 1024, 1024 * 1024, 10 * 1024 * 1024];
+/// Network rate colors from each of `NET_RATE_STOPS` up.
+pub const NET_RATE_COLORS: [Color; 4] = [Color::Green, Color::Cyan, Color::Yellow, Color::Magenta];
+
+/// btm-style color gradient: green -> yellow -> red. The single definition
+/// shared by CPU bars, sparklines, disk, memory and temperature widgets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient {
+    /// Ascending thresholds where the color steps up.
+    pub stops: [f64; 4],
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self { stops: PCT_STOPS }
+    }
+}
+
+impl Gradient {
+    pub fn new(stops: [f64; 4]) -> Self {
+        Self { stops }
+    }
+
+    pub fn color(&self, value: f64) -> Color {
+        let level = self.stops.iter().filter(|stop| value >= **stop).count();
+        gate(LEVEL_COLORS[level])
+    }
+}
+
+/// Network rate color: DarkGray when idle (below `idle_below`) or under the
+/// first stop, then up through `NET_RATE_COLORS`.
+pub fn net_rate_color(bytes_per_sec: u64, idle_below: u64) -> Color {
+    if bytes_per_sec < idle_below {
+        return gate(Color::DarkGray);
+    }
+    match NET_RATE_STOPS
+        .iter()
+        .rposition(|stop| bytes_per_sec >= *stop)
+    {
+        Some(i) => gate(NET_RATE_COLORS[i]),
+        None => gate(Color::DarkGray),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_stops_match_legacy_colors() {
+        let g = Gradient::default();
+        assert_eq!(g.color(20.0), Color::Green);
+        assert_eq!(g.color(40.0), Color::Rgb(150, 255, 0));
+        assert_eq!(g.color(70.0), Color::Yellow);
+        assert_eq!(g.color(85.0), Color::Rgb(255, 100, 0));
+        assert_eq!(g.color(95.0), Color::Red);
+
+        let t = Gradient::new(TEMP_STOPS);
+        assert_eq!(t.color(42.0), Color::Green);
+        assert_eq!(t.color(50.0), Color::Rgb(150, 255, 0));
+    }
+
+    #[test]
+    fn test_net_rate_color() {
+        assert_eq!(net_rate_color(512, 0), Color::DarkGray);
+        assert_eq!(net_rate_color(2048, 0), Color::Green);
+        assert_eq!(net_rate_color(2048, 4096), Color::DarkGray);
+        assert_eq!(net_rate_color(200 * 1024, 0), Color::Cyan);
+        assert_eq!(net_rate_color(20 * 1024 * 1024, 0), Color::Magenta);
+    }
+
+    #[test]
+    fn test_gate_resets_in_monochrome() {
+        set_colors_enabled(false);
+        assert_eq!(Gradient::default().color(95.0), Color::Reset);
+        assert_eq!(net_rate_color(20 * 1024 * 1024, 0), Color::Reset);
+        set_colors_enabled(true);
+        assert_eq!(gate(Color::Red), Color::Red);
+    }
+
+    #[test]
+    fn test_custom_stops() {
+        let g = Gradient::new([10.0, 20.0, 30.0, 50.0]);
+        assert_eq!(g.color(25.0), Color::Yellow);
+        assert_eq!(g.color(50.0), Color::Red);
+    }
+}
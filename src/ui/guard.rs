@@ -0,0 +1,84 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, Once};
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+thread_local! {
+    /// Set while a guarded widget is drawing, so the panic hook stays quiet
+    /// (the default hook would print over the alternate screen).
+    static IN_WIDGET: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Widgets whose panic has already been logged this session.
+static LOGGED: Mutex<Option<HashSet<&'static str>>> = Mutex::new(None);
+
+/// Wrap the existing panic hook once: panics inside `guarded` are captured
+/// for the log instead of printed; all others go to the previous hook.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IN_WIDGET.with(|w| w.get()) {
+                let msg = match info.location() {
+                    Some(loc) => format!("{info} at {}:{}", loc.file(), loc.line()),
+                    None => info.to_string(),
+                };
+                LAST_PANIC.with(|p| *p.borrow_mut() = Some(msg));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Draw one widget, replacing it with an inline error block if it panics so
+/// the rest of the tab keeps rendering. Each widget's panic is logged once.
+pub fn guarded(
+    frame: &mut Frame,
+    area: Rect,
+    app: &mut App,
+    name: &'static str,
+    draw: impl FnOnce(&mut Frame, Rect, &mut App),
+) {
+    install_hook();
+    if let Some(rects) = app.layout_dump.as_mut() {
+        rects.push((name, area));
+    }
+    IN_WIDGET.with(|w| w.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| draw(frame, area, app)));
+    IN_WIDGET.with(|w| w.set(false));
+    if result.is_ok() {
+        return;
+    }
+
+    let msg = LAST_PANIC
+        .with(|p| p.borrow_mut().take())
+        .unwrap_or_else(|| "unknown panic".into());
+    let first = LOGGED
+        .lock()
+        .map(|mut seen| seen.get_or_insert_with(HashSet::new).insert(name))
+        .unwrap_or(false);
+    if first {
+        tracing::error!("widget {name} panicked: {msg}");
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {name} "))
+        .border_style(Style::default().fg(Color::Red));
+    let text = Paragraph::new(Span::styled(
+        " widget error (see log)",
+        Style::default().fg(Color::Red),
+    ))
+    .block(block);
+    frame.render_widget(Clear, area);
+    frame.render_widget(text, area);
+}
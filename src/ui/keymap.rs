@@ -0,0 +1,236 @@
+//! Keymap reference content, shared by the help overlay and `--print-keys`.
+
+/// A titled group of keybindings (key, description).
+pub struct HelpSection {
+    pub title: &'static str,
+    pub bindings: Vec<(&'static str, &'static str)>,
+}
+
+/// Help overlay tab names, in display order.
+pub const HELP_TABS: [&str; 4] = ["TUI", "Shell", "Lab", "Starship"];
+
+/// Sections for the given help tab (out-of-range falls back to the TUI tab).
+pub fn help_tab(idx: usize) -> Vec<HelpSection> {
+    match idx {
+        1 => help_tab_shell(),
+        2 => help_tab_lab(),
+        3 => help_tab_starship(),
+        _ => help_tab_tui(),
+    }
+}
+
+fn section(title: &'static str, bindings: &[(&'static str, &'static str)]) -> HelpSection {
+    HelpSection {
+        title,
+        bindings: bindings.to_vec(),
+    }
+}
+
+fn help_tab_tui() -> Vec<HelpSection> {
+    vec![
+        section(
+            "Navigation",
+            &[
+                ("Tab / Right", "Next tab"),
+                ("Shift-Tab / Left", "Previous tab"),
+                ("1-5", "Jump to tab (in tab order)"),
+                ("Space", "Freeze/resume data"),
+            ],
+        ),
+        section(
+            "Process Table (System tab)",
+            &[
+                ("j/k / Up/Down", "Scroll processes"),
+                ("g / Home", "Jump to top"),
+                ("G / End", "Jump to bottom"),
+                ("C / M", "Jump to top CPU / memory process"),
+                ("/", "Filter by name/PID"),
+                ("F1-F9", "Apply saved filter ([filters] saved)"),
+                ("c / m / p / n", "Sort: CPU/Mem/PID/Name"),
+                ("w", "Sort by swap (Linux)"),
+                ("< / >", "Renice -1 / +1 (keys.allow_renice)"),
+                ("r", "Reverse sort order"),
+                ("o", "Process details + CPU graph"),
+                ("e", "Toggle full command"),
+                ("t", "Toggle tree view"),
+                ("X", "Group by executable"),
+                ("N", "Change feed: new/exited/changed only"),
+                ("K", "Show/hide kernel threads"),
+                ("PgUp / PgDn", "Page through processes"),
+                ("dd", "Kill process (TERM)"),
+                ("D", "Force kill (KILL)"),
+                ("s", "Signal menu (HUP/STOP/CONT/...)"),
+                ("Right-click", "Row menu: kill, copy PID, detail"),
+                ("a-z", "Jump by name (keys.type_to_jump)"),
+            ],
+        ),
+        section(
+            "Waifu (Dashboard tab)",
+            &[
+                ("n / p", "Next / previous image"),
+                ("r", "Random image"),
+                ("f", "Fetch new from live service"),
+                ("i", "Toggle info overlay"),
+                ("Mouse wheel", "Flip images (over waifu)"),
+            ],
+        ),
+        section(
+            "Network tab",
+            &[
+                ("F", "Toggle MagicDNS FQDN / hostname"),
+                ("B", "Toggle exact / short peer RX/TX bytes"),
+                ("6", "Toggle IPv4 / IPv6 addresses"),
+                ("I", "Collapse idle virtual interfaces"),
+            ],
+        ),
+        section(
+            "Display",
+            &[
+                ("+ / -", "Adjust refresh rate"),
+                ("?", "This help"),
+                ("v", "About / version"),
+                ("l", "Color legend"),
+                ("H", "Hide/show tab bar and help bar"),
+                ("U", "Per-core / aggregate CPU (Dashboard)"),
+                ("W", "Waifu / cluster widgets (Dashboard)"),
+                ("Enter", "Zoom widgets (Tab cycles, Esc back)"),
+                ("L", "Kill log (this session)"),
+                ("R", "Re-read daemon cache now"),
+                ("q / Esc", "Quit"),
+            ],
+        ),
+    ]
+}
+
+fn help_tab_shell() -> Vec<HelpSection> {
+    vec![
+        section(
+            "Shell Keybindings",
+            &[
+                ("Ctrl+P", "Launch TUI dashboard"),
+                ("Ctrl+W", "Launch waifu viewer"),
+                ("pp", "prompt-pulse alias"),
+                ("pp-tui", "prompt-pulse-tui alias"),
+                ("pp-status", "Daemon health check"),
+                ("pp-start", "Start daemon"),
+                ("pp-stop", "Stop daemon"),
+                ("pp-banner", "Show text banner"),
+            ],
+        ),
+        section(
+            "Starship Prompt",
+            &[
+                ("Claude segment", "Purple - API usage & burn rate"),
+                ("Billing segment", "Cyan - CIVO + DO costs"),
+                ("Infra segment", "Green - Tailscale + K8s"),
+            ],
+        ),
+    ]
+}
+
+fn help_tab_lab() -> Vec<HelpSection> {
+    vec![
+        section(
+            "Deployment",
+            &[
+                ("just deploy <host>", "Full deployment"),
+                ("just nix-switch", "Nix config only"),
+                ("just check <host>", "Dry-run with diff"),
+            ],
+        ),
+        section(
+            "Diagnostics",
+            &[
+                ("just doctor", "Run diagnostic checks"),
+                ("lab_status", "Show API key status"),
+                ("tinyland_build", "Show build info"),
+            ],
+        ),
+        section(
+            "Development",
+            &[
+                ("just test", "Run all tests"),
+                ("just molecule <role>", "Molecule test role"),
+                ("just test-pbt", "Property-based tests"),
+                ("just nix-check", "Nix flake check"),
+                ("jb-dev", "DevContainer launcher"),
+            ],
+        ),
+    ]
+}
+
+fn help_tab_starship() -> Vec<HelpSection> {
+    vec![
+        section(
+            "Starship Modules",
+            &[
+                ("custom.claude", "Claude API usage (purple)"),
+                ("custom.billing", "Cloud billing (cyan)"),
+                ("custom.infra", "Infra status (green)"),
+            ],
+        ),
+        section(
+            "Themes",
+            &[
+                ("ultra-minimal", "Directory only, fastest"),
+                ("minimal", "Dir + git, clean"),
+                ("full", "Languages, duration, etc."),
+                ("plain", "No special chars"),
+                ("monitoring", "With prompt-pulse modules"),
+            ],
+        ),
+        section(
+            "Configuration",
+            &[
+                ("~/.config/starship", "Managed by Nix"),
+                ("nix/hosts/base.nix", "Theme selection"),
+                ("starship.nix", "Module definitions"),
+            ],
+        ),
+    ]
+}
+
+/// Render every help tab as markdown tables (for `--print-keys`).
+pub fn to_markdown() -> String {
+    let mut out = String::from("# prompt-pulse-tui keymap\n");
+    for (idx, name) in HELP_TABS.iter().enumerate() {
+        out.push_str(&format!("\n## {name}\n"));
+        for section in help_tab(idx) {
+            out.push_str(&format!("\n### {}\n\n", section.title));
+            out.push_str("| Key | Action |\n| --- | --- |\n");
+            for (key, desc) in &section.bindings {
+                out.push_str(&format!(
+                    "| `{}` | {} |\n",
+                    key.replace('|', "\\|"),
+                    desc.replace('|', "\\|")
+                ));
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_tab_has_sections() {
+        for idx in 0..HELP_TABS.len() {
+            let sections = help_tab(idx);
+            assert!(!sections.is_empty());
+            assert!(sections.iter().all(|s| !s.bindings.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_markdown_export() {
+        let md = to_markdown();
+        for name in HELP_TABS {
+            assert!(md.contains(&format!("## {name}\n")));
+        }
+        assert!(md.contains("### Navigation\n\n| Key | Action |\n| --- | --- |\n"));
+        assert!(md.contains("| `Tab / Right` | Next tab |\n"));
+        assert!(md.contains("| `dd` | Kill process (TERM) |\n"));
+    }
+}
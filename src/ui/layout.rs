@@ -1,14 +1,49 @@
 use ratatui::prelude::*;
 
 use super::widgets;
-use crate::app::App;
+use crate::app::{App, WidgetId};
+
+/// Draw a widget behind the panic guard (`ui::guard`), labelled by its
+/// function name, so one failing widget doesn't take down the tab.
+macro_rules! guarded {
+    ($frame:expr, $area:expr, $app:expr, $($draw:ident)::+) => {
+        super::guard::guarded(
+            $frame,
+            $area,
+            $app,
+            widget_name(stringify!($($draw)::+)),
+            |f, a, app| $($draw)::+(f, a, app),
+        )
+    };
+}
+
+/// "widgets :: cpu :: draw_cpu_bars" -> "cpu_bars".
+fn widget_name(path: &'static str) -> &'static str {
+    let func = path.rsplit("::").next().unwrap_or(path).trim();
+    func.strip_prefix("draw_").unwrap_or(func)
+}
+
+/// Content heights below this get the compact layout (e.g. an 80x24 terminal).
+const COMPACT_HEIGHT: u16 = 30;
+
+/// Content heights below this get the mini layout (e.g. a short tmux pane);
+/// terminals this short also drop the tab bar.
+pub const MINI_HEIGHT: u16 = 12;
 
 /// Dashboard tab: overview of everything.
-/// Adaptive layout based on terminal width.
+/// Adaptive layout based on terminal width (and height, for small screens).
 pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
+    if area.height < MINI_HEIGHT {
+        mini(frame, area, app);
+        return;
+    }
+    if area.height < COMPACT_HEIGHT {
+        compact(frame, area, app, true);
+        return;
+    }
     let wide = area.width >= 120;
 
-    let has_waifu = app.wants_waifu();
+    let has_waifu = app.dashboard_shows_waifu();
 
     if wide {
         if has_waifu {
@@ -18,7 +53,7 @@ pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
                 .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
                 .split(area);
 
-            widgets::waifu::draw_waifu(frame, cols[0], app);
+            guarded!(frame, cols[0], app, widgets::waifu::draw_waifu);
 
             // Right column: host + sparklines + CPU + memory + tailscale/disks + claude/billing.
             let right = Layout::default()
@@ -33,36 +68,58 @@ pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
                 ])
                 .split(cols[1]);
 
-            widgets::host::draw_host_info(frame, right[0], app);
+            guarded!(frame, right[0], app, widgets::host::draw_host_info);
 
             let spark_cols = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
-                    Constraint::Percentage(35),
-                    Constraint::Percentage(35),
                     Constraint::Percentage(30),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(15),
                 ])
                 .split(right[1]);
-            widgets::sparkline::draw_cpu_sparkline(frame, spark_cols[0], app);
-            widgets::sparkline::draw_mem_sparkline(frame, spark_cols[1], app);
-            widgets::sparkline::draw_swap_sparkline(frame, spark_cols[2], app);
+            guarded!(
+                frame,
+                spark_cols[0],
+                app,
+                widgets::sparkline::draw_cpu_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[1],
+                app,
+                widgets::sparkline::draw_mem_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[2],
+                app,
+                widgets::sparkline::draw_swap_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[3],
+                app,
+                widgets::pressure::draw_pressure_gauge
+            );
 
-            widgets::cpu::draw_cpu_bars(frame, right[2], app);
-            widgets::memory::draw_memory(frame, right[3], app);
+            dashboard_cpu(frame, right[2], app);
+            guarded!(frame, right[3], app, widgets::memory::draw_memory);
 
             let mid_row = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
                 .split(right[4]);
-            widgets::tailscale::draw_tailscale(frame, mid_row[0], app);
-            widgets::disk::draw_disks(frame, mid_row[1], app);
+            guarded!(frame, mid_row[0], app, widgets::tailscale::draw_tailscale);
+            guarded!(frame, mid_row[1], app, widgets::disk::draw_disks);
 
             let bottom = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
                 .split(right[5]);
-            widgets::claude::draw_claude(frame, bottom[0], app);
-            widgets::billing_widget::draw_billing(frame, bottom[1], app);
+            guarded!(frame, bottom[0], app, widgets::claude::draw_claude);
+            guarded!(frame, bottom[1], app, widgets::billing_widget::draw_billing);
         } else {
             // Wide without waifu: [left 55%: sys] [right 45%: network/billing]
             let cols = Layout::default()
@@ -81,23 +138,45 @@ pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
                 ])
                 .split(cols[0]);
 
-            widgets::host::draw_host_info(frame, left[0], app);
+            guarded!(frame, left[0], app, widgets::host::draw_host_info);
 
             let spark_cols = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
-                    Constraint::Percentage(35),
-                    Constraint::Percentage(35),
                     Constraint::Percentage(30),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(15),
                 ])
                 .split(left[1]);
-            widgets::sparkline::draw_cpu_sparkline(frame, spark_cols[0], app);
-            widgets::sparkline::draw_mem_sparkline(frame, spark_cols[1], app);
-            widgets::sparkline::draw_swap_sparkline(frame, spark_cols[2], app);
+            guarded!(
+                frame,
+                spark_cols[0],
+                app,
+                widgets::sparkline::draw_cpu_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[1],
+                app,
+                widgets::sparkline::draw_mem_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[2],
+                app,
+                widgets::sparkline::draw_swap_sparkline
+            );
+            guarded!(
+                frame,
+                spark_cols[3],
+                app,
+                widgets::pressure::draw_pressure_gauge
+            );
 
-            widgets::cpu::draw_cpu_bars(frame, left[2], app);
-            widgets::memory::draw_memory(frame, left[3], app);
-            widgets::disk::draw_disks(frame, left[4], app);
+            dashboard_cpu(frame, left[2], app);
+            guarded!(frame, left[3], app, widgets::memory::draw_memory);
+            guarded!(frame, left[4], app, widgets::disk::draw_disks);
 
             let right = Layout::default()
                 .direction(Direction::Vertical)
@@ -108,16 +187,16 @@ pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
                 ])
                 .split(cols[1]);
 
-            widgets::tailscale::draw_tailscale(frame, right[0], app);
-            widgets::k8s::draw_k8s(frame, right[1], app);
+            guarded!(frame, right[0], app, widgets::tailscale::draw_tailscale);
+            guarded!(frame, right[1], app, widgets::k8s::draw_k8s);
 
             let bottom = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
                 .split(right[2]);
 
-            widgets::claude::draw_claude(frame, bottom[0], app);
-            widgets::billing_widget::draw_billing(frame, bottom[1], app);
+            guarded!(frame, bottom[0], app, widgets::claude::draw_claude);
+            guarded!(frame, bottom[1], app, widgets::billing_widget::draw_billing);
         }
     } else {
         // Narrow: single-column stack.
@@ -139,35 +218,63 @@ pub fn dashboard(frame: &mut Frame, area: Rect, app: &mut App) {
             .split(area);
 
         let mut idx = 0;
-        widgets::host::draw_host_info(frame, rows[idx], app);
+        guarded!(frame, rows[idx], app, widgets::host::draw_host_info);
         idx += 1;
 
         let spark_cols = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+            ])
             .split(rows[idx]);
-        widgets::sparkline::draw_cpu_sparkline(frame, spark_cols[0], app);
-        widgets::sparkline::draw_mem_sparkline(frame, spark_cols[1], app);
+        guarded!(
+            frame,
+            spark_cols[0],
+            app,
+            widgets::sparkline::draw_cpu_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[1],
+            app,
+            widgets::sparkline::draw_mem_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[2],
+            app,
+            widgets::pressure::draw_pressure_gauge
+        );
         idx += 1;
 
-        widgets::memory::draw_memory(frame, rows[idx], app);
+        guarded!(frame, rows[idx], app, widgets::memory::draw_memory);
         idx += 1;
-        widgets::disk::draw_disks(frame, rows[idx], app);
+        guarded!(frame, rows[idx], app, widgets::disk::draw_disks);
         idx += 1;
 
         if has_waifu {
-            widgets::waifu::draw_waifu(frame, rows[idx], app);
+            guarded!(frame, rows[idx], app, widgets::waifu::draw_waifu);
             idx += 1;
         }
 
-        widgets::tailscale::draw_tailscale(frame, rows[idx], app);
+        guarded!(frame, rows[idx], app, widgets::tailscale::draw_tailscale);
         idx += 1;
-        widgets::billing_widget::draw_billing(frame, rows[idx], app);
+        guarded!(frame, rows[idx], app, widgets::billing_widget::draw_billing);
     }
 }
 
 /// System tab: detailed CPU per-core, memory, disks, temps, network, processes.
 pub fn system(frame: &mut Frame, area: Rect, app: &mut App) {
+    if area.height < MINI_HEIGHT {
+        mini(frame, area, app);
+        return;
+    }
+    if area.height < COMPACT_HEIGHT {
+        compact(frame, area, app, false);
+        return;
+    }
     let wide = area.width >= 120;
 
     if wide {
@@ -198,21 +305,51 @@ pub fn system(frame: &mut Frame, area: Rect, app: &mut App) {
                 Constraint::Percentage(20),
             ])
             .split(left[0]);
-        widgets::sparkline::draw_cpu_sparkline(frame, spark_cols[0], app);
-        widgets::sparkline::draw_mem_sparkline(frame, spark_cols[1], app);
-        widgets::sparkline::draw_swap_sparkline(frame, spark_cols[2], app);
-        widgets::sparkline::draw_load_sparkline(frame, spark_cols[3], app);
-        widgets::sparkline::draw_temp_sparkline(frame, spark_cols[4], app);
-        widgets::sparkline::draw_cpu_per_core(frame, left[1], app);
-        widgets::memory::draw_memory(frame, left[2], app);
+        guarded!(
+            frame,
+            spark_cols[0],
+            app,
+            widgets::sparkline::draw_cpu_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[1],
+            app,
+            widgets::sparkline::draw_mem_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[2],
+            app,
+            widgets::sparkline::draw_swap_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[3],
+            app,
+            widgets::sparkline::draw_load_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[4],
+            app,
+            widgets::sparkline::draw_temp_sparkline
+        );
+        guarded!(frame, left[1], app, widgets::sparkline::draw_cpu_per_core);
+        guarded!(frame, left[2], app, widgets::memory::draw_memory);
 
         // Disks and temps side by side.
         let disk_temp = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
             .split(left[3]);
-        widgets::disk::draw_disks(frame, disk_temp[0], app);
-        widgets::temperature::draw_temperatures(frame, disk_temp[1], app);
+        guarded!(frame, disk_temp[0], app, widgets::disk::draw_disks);
+        guarded!(
+            frame,
+            disk_temp[1],
+            app,
+            widgets::temperature::draw_temperatures
+        );
 
         let right = Layout::default()
             .direction(Direction::Vertical)
@@ -228,11 +365,21 @@ pub fn system(frame: &mut Frame, area: Rect, app: &mut App) {
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(right[0]);
-        widgets::sparkline::draw_net_rx_sparkline(frame, net_spark_cols[0], app);
-        widgets::sparkline::draw_net_tx_sparkline(frame, net_spark_cols[1], app);
+        guarded!(
+            frame,
+            net_spark_cols[0],
+            app,
+            widgets::sparkline::draw_net_rx_sparkline
+        );
+        guarded!(
+            frame,
+            net_spark_cols[1],
+            app,
+            widgets::sparkline::draw_net_tx_sparkline
+        );
 
-        widgets::processes::draw_processes(frame, right[1], app);
-        widgets::network::draw_network(frame, right[2], app);
+        guarded!(frame, right[1], app, widgets::processes::draw_processes);
+        guarded!(frame, right[2], app, widgets::network::draw_network);
     } else {
         // Narrow: single stack
         let chunks = Layout::default()
@@ -257,27 +404,123 @@ pub fn system(frame: &mut Frame, area: Rect, app: &mut App) {
                 Constraint::Percentage(30),
             ])
             .split(chunks[0]);
-        widgets::sparkline::draw_cpu_sparkline(frame, spark_cols[0], app);
-        widgets::sparkline::draw_mem_sparkline(frame, spark_cols[1], app);
-        widgets::sparkline::draw_temp_sparkline(frame, spark_cols[2], app);
-        widgets::cpu::draw_cpu_bars(frame, chunks[1], app);
-        widgets::memory::draw_memory(frame, chunks[2], app);
+        guarded!(
+            frame,
+            spark_cols[0],
+            app,
+            widgets::sparkline::draw_cpu_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[1],
+            app,
+            widgets::sparkline::draw_mem_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[2],
+            app,
+            widgets::sparkline::draw_temp_sparkline
+        );
+        guarded!(frame, chunks[1], app, widgets::cpu::draw_cpu_bars);
+        guarded!(frame, chunks[2], app, widgets::memory::draw_memory);
 
         // Net sparklines in narrow mode too.
         let net_spark_cols = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(chunks[3]);
-        widgets::sparkline::draw_net_rx_sparkline(frame, net_spark_cols[0], app);
-        widgets::sparkline::draw_net_tx_sparkline(frame, net_spark_cols[1], app);
+        guarded!(
+            frame,
+            net_spark_cols[0],
+            app,
+            widgets::sparkline::draw_net_rx_sparkline
+        );
+        guarded!(
+            frame,
+            net_spark_cols[1],
+            app,
+            widgets::sparkline::draw_net_tx_sparkline
+        );
 
-        widgets::processes::draw_processes(frame, chunks[4], app);
-        widgets::disk::draw_disks(frame, chunks[5], app);
-        widgets::temperature::draw_temperatures(frame, chunks[6], app);
-        widgets::network::draw_network(frame, chunks[7], app);
+        guarded!(frame, chunks[4], app, widgets::processes::draw_processes);
+        guarded!(frame, chunks[5], app, widgets::disk::draw_disks);
+        guarded!(
+            frame,
+            chunks[6],
+            app,
+            widgets::temperature::draw_temperatures
+        );
+        guarded!(frame, chunks[7], app, widgets::network::draw_network);
     }
 }
 
+/// Short screens: the essentials only -- optional host info, CPU (and
+/// memory) sparklines, memory gauges, and the process table in the rest.
+fn compact(frame: &mut Frame, area: Rect, app: &mut App, with_host: bool) {
+    let mut constraints = Vec::with_capacity(4);
+    if with_host {
+        constraints.push(Constraint::Length(7)); // host
+    }
+    constraints.extend([
+        Constraint::Length(4), // sparklines
+        Constraint::Length(4), // memory
+        Constraint::Min(4),    // processes
+    ]);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    let mut idx = 0;
+    if with_host {
+        guarded!(frame, rows[idx], app, widgets::host::draw_host_info);
+        idx += 1;
+    }
+
+    if with_host {
+        guarded!(
+            frame,
+            rows[idx],
+            app,
+            widgets::sparkline::draw_cpu_sparkline
+        );
+    } else {
+        let spark_cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[idx]);
+        guarded!(
+            frame,
+            spark_cols[0],
+            app,
+            widgets::sparkline::draw_cpu_sparkline
+        );
+        guarded!(
+            frame,
+            spark_cols[1],
+            app,
+            widgets::sparkline::draw_mem_sparkline
+        );
+    }
+    idx += 1;
+
+    guarded!(frame, rows[idx], app, widgets::memory::draw_memory);
+    idx += 1;
+    guarded!(frame, rows[idx], app, widgets::processes::draw_processes);
+}
+
+/// Mini layout for very short panes: a one-row CPU/MEM strip over as many
+/// top processes as fit. Process keys still work.
+fn mini(frame: &mut Frame, area: Rect, app: &mut App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    guarded!(frame, rows[0], app, widgets::sparkline::draw_mini_strip);
+    guarded!(frame, rows[1], app, widgets::processes::draw_top_processes);
+}
+
 /// Network tab: net sparklines + interface table + Tailscale peers + K8s clusters.
 pub fn network(frame: &mut Frame, area: Rect, app: &mut App) {
     let chunks = Layout::default()
@@ -294,17 +537,27 @@ pub fn network(frame: &mut Frame, area: Rect, app: &mut App) {
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(chunks[0]);
-    widgets::sparkline::draw_net_rx_sparkline(frame, net_spark_cols[0], app);
-    widgets::sparkline::draw_net_tx_sparkline(frame, net_spark_cols[1], app);
+    guarded!(
+        frame,
+        net_spark_cols[0],
+        app,
+        widgets::sparkline::draw_net_rx_sparkline
+    );
+    guarded!(
+        frame,
+        net_spark_cols[1],
+        app,
+        widgets::sparkline::draw_net_tx_sparkline
+    );
 
-    widgets::network::draw_network(frame, chunks[1], app);
-    widgets::tailscale::draw_tailscale(frame, chunks[2], app);
-    widgets::k8s::draw_k8s(frame, chunks[3], app);
+    guarded!(frame, chunks[1], app, widgets::network::draw_network);
+    guarded!(frame, chunks[2], app, widgets::tailscale::draw_tailscale);
+    guarded!(frame, chunks[3], app, widgets::k8s::draw_k8s);
 }
 
 /// Build tab: component SHAs, versions, and flake input revisions.
 pub fn build(frame: &mut Frame, area: Rect, app: &mut App) {
-    widgets::buildinfo::draw_build_info(frame, area, app);
+    guarded!(frame, area, app, widgets::buildinfo::draw_build_info);
 }
 
 /// Billing tab: Claude personal gauge + Claude API usage + cloud billing.
@@ -318,16 +571,89 @@ pub fn billing(frame: &mut Frame, area: Rect, app: &mut App) {
         ])
         .split(area);
 
-    widgets::claudepersonal::draw_claude_personal(frame, chunks[0], app);
-    widgets::claude::draw_claude(frame, chunks[1], app);
-    widgets::billing_widget::draw_billing(frame, chunks[2], app);
+    guarded!(
+        frame,
+        chunks[0],
+        app,
+        widgets::claudepersonal::draw_claude_personal
+    );
+    guarded!(frame, chunks[1], app, widgets::claude::draw_claude);
+    guarded!(frame, chunks[2], app, widgets::billing_widget::draw_billing);
+}
+
+/// Expand mode: a single widget in the whole area.
+pub fn expanded(frame: &mut Frame, area: Rect, app: &mut App, widget: WidgetId) {
+    match widget {
+        WidgetId::Waifu => guarded!(frame, area, app, widgets::waifu::draw_waifu),
+        WidgetId::Host => guarded!(frame, area, app, widgets::host::draw_host_info),
+        WidgetId::Cpu => expanded_cpu(frame, area, app),
+        WidgetId::CpuCores => {
+            guarded!(frame, area, app, widgets::sparkline::draw_cpu_per_core)
+        }
+        WidgetId::Memory => guarded!(frame, area, app, widgets::memory::draw_memory),
+        WidgetId::Disks => guarded!(frame, area, app, widgets::disk::draw_disks),
+        WidgetId::Temps => guarded!(frame, area, app, widgets::temperature::draw_temperatures),
+        WidgetId::Processes => guarded!(frame, area, app, widgets::processes::draw_processes),
+        WidgetId::Network => guarded!(frame, area, app, widgets::network::draw_network),
+        WidgetId::Tailscale => guarded!(frame, area, app, widgets::tailscale::draw_tailscale),
+        WidgetId::K8s => guarded!(frame, area, app, widgets::k8s::draw_k8s),
+        WidgetId::ClaudePersonal => guarded!(
+            frame,
+            area,
+            app,
+            widgets::claudepersonal::draw_claude_personal
+        ),
+        WidgetId::Claude => guarded!(frame, area, app, widgets::claude::draw_claude),
+        WidgetId::Billing => guarded!(frame, area, app, widgets::billing_widget::draw_billing),
+        WidgetId::Build => guarded!(frame, area, app, widgets::buildinfo::draw_build_info),
+    }
+}
+
+/// Dashboard CPU: per-core bars, or the aggregate gauge + history when
+/// toggled with `U`.
+fn dashboard_cpu(frame: &mut Frame, area: Rect, app: &mut App) {
+    if app.cpu_aggregate_view {
+        guarded!(frame, area, app, widgets::cpu::draw_cpu_aggregate);
+    } else {
+        guarded!(frame, area, app, widgets::cpu::draw_cpu_bars);
+    }
+}
+
+/// Zoomed CPU: the per-core bars with the usage histogram underneath when
+/// there is room for all ten buckets.
+fn expanded_cpu(frame: &mut Frame, area: Rect, app: &mut App) {
+    if area.height < 24 {
+        guarded!(frame, area, app, widgets::cpu::draw_cpu_bars);
+        return;
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(12)])
+        .split(area);
+    guarded!(frame, chunks[0], app, widgets::cpu::draw_cpu_bars);
+    guarded!(
+        frame,
+        chunks[1],
+        app,
+        widgets::sparkline::draw_cpu_histogram
+    );
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::app::{App, Tab};
+    use crate::app::{App, ProcessInfo, ProcessState, Tab};
     use crate::config::TuiConfig;
-    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+    use crate::ui::testing::{
+        assert_snapshot, process, render_app, render_to_string, render_widget_to_string,
+    };
+    use crate::ui::widgets;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use ratatui::{
+        backend::TestBackend,
+        buffer::Buffer,
+        style::{Color, Modifier},
+        Terminal,
+    };
 
     /// Check if a rendered Buffer contains a substring.
     fn buffer_contains(buf: &Buffer, needle: &str) -> bool {
@@ -335,14 +661,6 @@ mod tests {
         content.contains(needle)
     }
 
-    fn render_app(width: u16, height: u16, app: &mut App) -> Buffer {
-        app.on_resize(width, height);
-        let backend = TestBackend::new(width, height);
-        let mut terminal = Terminal::new(backend).unwrap();
-        terminal.draw(|frame| crate::ui::draw(frame, app)).unwrap();
-        terminal.backend().buffer().clone()
-    }
-
     // --- Dashboard tab: wide layout ---
 
     #[test]
@@ -395,6 +713,58 @@ mod tests {
         );
     }
 
+    // --- Top metric strip ---
+
+    #[test]
+    fn top_strip_sits_under_tab_bar_on_every_tab() {
+        let mut cfg = TuiConfig::default();
+        cfg.display.top_strip = true;
+        let mut app = App::test_new(cfg);
+        for (tab, widget) in [
+            (Tab::Dashboard, "Host"),
+            (Tab::System, "Memory"),
+            (Tab::Network, "Tailscale"),
+        ] {
+            app.active_tab = tab;
+            let text = render_to_string(160, 50, &mut app);
+            let row = text.lines().nth(3).unwrap_or_default();
+            assert!(
+                row.starts_with(" CPU ") && row.contains("LOAD"),
+                "{tab:?}: strip row was {row:?}"
+            );
+            assert!(text.contains(widget), "{tab:?} should still show {widget}");
+        }
+
+        // Off by default: the row under the tab bar is content.
+        let mut app = App::test_new(TuiConfig::default());
+        let text = render_to_string(160, 50, &mut app);
+        assert!(!text.lines().nth(3).unwrap_or_default().starts_with(" CPU "));
+    }
+
+    // --- Display margin ---
+
+    #[test]
+    fn margin_leaves_blank_border() {
+        let mut cfg = TuiConfig::default();
+        cfg.display.margin = 2;
+        let mut app = App::test_new(cfg);
+        let text = render_to_string(160, 50, &mut app);
+        let lines: Vec<&str> = text.lines().collect();
+        for row in [0, 1, 48, 49] {
+            let line = lines.get(row).copied().unwrap_or_default();
+            assert!(line.trim().is_empty(), "row {row} was {line:?}");
+        }
+        for line in &lines[2..48] {
+            assert!(line.starts_with("  "), "left edge drawn: {line:?}");
+        }
+        // The tab bar header moves down into the inset.
+        assert!(lines[2].contains("prompt-pulse"), "{:?}", lines[2]);
+
+        // Too small to spare the margin: draw edge to edge.
+        let text = render_to_string(22, 8, &mut app);
+        assert!(!text.lines().next().unwrap_or_default().trim().is_empty());
+    }
+
     // --- System tab ---
 
     #[test]
@@ -437,7 +807,7 @@ mod tests {
     #[test]
     fn expanded_mode_no_tab_bar() {
         let mut app = App::test_new(TuiConfig::default()).with_waifu_enabled();
-        app.expanded = true;
+        app.expanded = Some(crate::app::WidgetId::Waifu);
         let buf = render_app(160, 50, &mut app);
         // In expanded mode, only waifu renders -- no tab bar with Dashboard/System/etc.
         assert!(
@@ -450,6 +820,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expanded_processes_fill_the_screen() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::System;
+        app.expanded = Some(crate::app::WidgetId::Processes);
+        let screen = render_to_string(120, 30, &mut app);
+        assert!(screen.contains("Processes"));
+        assert!(!screen.contains("Memory"), "only the zoomed widget draws");
+        assert!(!screen.contains("Dashboard"));
+    }
+
+    #[test]
+    fn expanded_processes_draw_popups() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(vec![ProcessInfo {
+            cpu_usage: 1.0,
+            ..process(42, "worker")
+        }]);
+        app.active_tab = Tab::System;
+        app.expanded = Some(crate::app::WidgetId::Processes);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('s')));
+        let screen = render_to_string(120, 30, &mut app);
+        assert!(screen.contains("Signal worker (42)"), "{screen}");
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('?')));
+        assert!(render_to_string(120, 30, &mut app).contains("Keymap Reference"));
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+
+        // The filter prompt shows in the zoomed table's title.
+        app.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('w')));
+        assert!(render_to_string(120, 30, &mut app).contains("[/w|]"));
+    }
+
+    #[test]
+    fn expanded_cpu_shows_histogram() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.cpu_history.extend([2.0, 3.0, 4.0, 95.0]);
+        app.expanded = Some(crate::app::WidgetId::Cpu);
+        let screen = render_to_string(120, 40, &mut app);
+        assert!(screen.contains("CPU distribution (4 samples)"));
+        assert!(screen.contains("75%"), "three of four samples idle");
+
+        let short = render_to_string(120, 20, &mut app);
+        assert!(!short.contains("CPU distribution"));
+    }
+
+    #[test]
+    fn cgroup_column_appears_when_wide() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(
+            (0..3)
+                .map(|i| ProcessInfo {
+                    cpu_usage: 1.0,
+                    cgroup: "4f9a1c2b3d4e".into(),
+                    ..process(i, &format!("worker{i}"))
+                })
+                .collect(),
+        );
+        app.active_tab = Tab::System;
+        app.expanded = Some(crate::app::WidgetId::Processes);
+        let screen = render_to_string(120, 20, &mut app);
+        assert!(screen.contains("Unit"), "{screen}");
+        assert!(screen.contains("4f9a1c2b3d4e"));
+
+        let screen = render_to_string(80, 20, &mut app);
+        assert!(!screen.contains("Unit"));
+    }
+
+    // --- Signal menu popup ---
+
+    #[test]
+    fn signal_menu_popup_lists_signals() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::System;
+        app.signal_menu = Some(0);
+        let screen = render_to_string(120, 40, &mut app);
+        assert!(screen.contains("SIGHUP"));
+        assert!(screen.contains("SIGCONT"));
+
+        let popup = render_widget_to_string(40, 16, |frame, area| {
+            widgets::signal_menu::draw_signal_menu(frame, area, &app)
+        });
+        assert_snapshot!("signal_menu", popup);
+    }
+
+    // --- NO_COLOR / --no-color ---
+
+    #[test]
+    fn no_color_renders_monochrome() {
+        for tab in Tab::ALL {
+            let mut app = App::test_new(TuiConfig::default());
+            app.cpu_history.extend([10.0, 50.0, 85.0]);
+            app.disable_colors();
+            app.active_tab = *tab;
+            let buf = render_app(160, 50, &mut app);
+            assert!(!has_rgb(&buf), "{tab:?} should render without RGB styling");
+        }
+    }
+
+    #[test]
+    fn no_color_reverses_only_the_selection() {
+        let procs = ["worker-a", "worker-b", "worker-c"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| ProcessInfo {
+                cpu_usage: 3.0 - i as f32,
+                ..process(100 + i as u32, name)
+            })
+            .collect();
+        let mut app = App::test_new(TuiConfig::default()).with_processes(procs);
+        app.disable_colors();
+        app.active_tab = Tab::System;
+        let buf = render_app(160, 50, &mut app);
+        // The first cell of `name` on screen, and whether it is reversed.
+        let reversed = |name: &str| {
+            let symbols: Vec<&str> = buf.content.iter().map(|c| c.symbol()).collect();
+            let at = (0..symbols.len() - name.len())
+                .find(|&i| (0..name.len()).all(|k| symbols[i + k] == &name[k..k + 1]))
+                .unwrap_or_else(|| panic!("{name} not drawn"));
+            buf.content[at].modifier.contains(Modifier::REVERSED)
+        };
+        assert!(reversed("worker-a"), "selected row");
+        assert!(!reversed("worker-b"), "shaded alternate row");
+        assert!(!reversed("worker-c"));
+        assert!(!has_rgb(&buf), "row shading");
+    }
+
+    /// Whether any cell still carries an RGB color.
+    fn has_rgb(buf: &Buffer) -> bool {
+        buf.content
+            .iter()
+            .any(|c| matches!(c.fg, Color::Rgb(..)) || matches!(c.bg, Color::Rgb(..)))
+    }
+
+    #[test]
+    fn braille_sparklines_render_all_tabs() {
+        for tab in Tab::ALL {
+            let mut app = App::test_new(TuiConfig::default());
+            app.sparkline_style = crate::config::SparklineStyle::Braille;
+            app.cpu_history.extend([10.0, 50.0, 90.0, 40.0]);
+            app.active_tab = *tab;
+            let screen = render_to_string(160, 50, &mut app);
+            if *tab == Tab::Dashboard {
+                assert!(
+                    screen
+                        .chars()
+                        .any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)),
+                    "no braille cells in the CPU graph:\n{screen}"
+                );
+            }
+        }
+    }
+
     // --- All tabs render without panic ---
 
     #[test]
@@ -469,4 +993,347 @@ mod tests {
             let _buf = render_app(80, 30, &mut app);
         }
     }
+
+    // --- Compact layout (short terminals) ---
+
+    #[test]
+    fn compact_dashboard_at_80x24() {
+        let mut app = App::test_new(TuiConfig::default()).with_waifu_enabled();
+        app.active_tab = Tab::Dashboard;
+        let screen = render_to_string(80, 24, &mut app);
+        for widget in ["Host", "CPU", "Memory", "Processes"] {
+            assert!(screen.contains(widget), "{widget} missing:\n{screen}");
+        }
+        assert!(!screen.contains("Tailscale"));
+        assert!(!screen.contains("Waifu"));
+    }
+
+    #[test]
+    fn compact_system_at_80x24() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.active_tab = Tab::System;
+        let screen = render_to_string(80, 24, &mut app);
+        for widget in ["CPU", "MEM", "Memory", "Processes"] {
+            assert!(screen.contains(widget), "{widget} missing:\n{screen}");
+        }
+        assert!(!screen.contains("Temps"));
+    }
+
+    #[test]
+    fn dashboard_swaps_waifu_for_cluster() {
+        let mut cfg = TuiConfig::default();
+        cfg.image.waifu_enabled = true;
+        cfg.collectors.waifu.endpoint = "https://waifu.example.com".into();
+        let mut app = App::test_new(cfg);
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(!screen.contains("Kubernetes"), "{screen}");
+        app.handle_key(crossterm::event::KeyEvent::from(
+            crossterm::event::KeyCode::Char('W'),
+        ));
+        assert_eq!(app.focus_target(), crate::app::FocusTarget::Processes);
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(screen.contains("Kubernetes"), "{screen}");
+    }
+
+    #[test]
+    fn layout_dump_records_one_frame() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.handle_key(crossterm::event::KeyEvent::from(
+            crossterm::event::KeyCode::F(12),
+        ));
+        assert!(app.layout_dump.is_none(), "needs --debug");
+        app.debug_mode = true;
+        app.handle_key(crossterm::event::KeyEvent::from(
+            crossterm::event::KeyCode::F(12),
+        ));
+        assert!(app.layout_dump.is_some());
+        render_to_string(160, 50, &mut app);
+        assert!(app.layout_dump.is_none());
+        let msg = app.status_message().unwrap();
+        assert!(
+            msg.starts_with("layout of ") && !msg.starts_with("layout of 0 "),
+            "{msg}"
+        );
+    }
+
+    #[test]
+    fn process_detail_popup_shows_graph() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(vec![ProcessInfo {
+            ppid: 1,
+            cmd: "cargo build --release".into(),
+            user: "dev".into(),
+            cpu_usage: 80.0,
+            run_time_secs: 90,
+            ..process(4242, "builder")
+        }]);
+        app.detail_pid = Some(4242);
+        app.show_process_detail = true;
+        app.detail_cpu_history.extend([40.0, 80.0]);
+        let screen = render_to_string(120, 40, &mut app);
+        assert!(screen.contains("builder (4242)"), "{screen}");
+        assert!(screen.contains("cargo build --release"));
+        assert!(screen.contains("CPU 80% (pk:80%, 2 samples)"));
+    }
+
+    #[test]
+    fn grouped_process_detail_has_no_single_pid() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(vec![ProcessInfo {
+            ppid: 1,
+            cmd: "/usr/bin/builder".into(),
+            user: "dev".into(),
+            cpu_usage: 80.0,
+            run_time_secs: 90,
+            exe: "/usr/bin/builder".into(),
+            group_count: 4,
+            ..process(4242, "builder")
+        }]);
+        app.detail_pid = Some(4242);
+        app.detail_grouped = true;
+        app.show_process_detail = true;
+        let screen = render_to_string(120, 40, &mut app);
+        assert!(screen.contains("builder (4 processes)"), "{screen}");
+        assert!(screen.contains("PPID  - "), "{screen}");
+        assert!(!screen.contains("(4242)"));
+
+        // The table row has no single PID either.
+        app.show_process_detail = false;
+        app.active_tab = Tab::System;
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(screen.contains("builder (4)"), "{screen}");
+        assert!(!screen.contains("4242"), "grouped row shows a member PID");
+    }
+
+    #[test]
+    fn mini_layout_in_short_pane() {
+        for tab in [Tab::Dashboard, Tab::System] {
+            let mut app = App::test_new(TuiConfig::default()).with_processes(
+                (0..5)
+                    .map(|i| ProcessInfo {
+                        ppid: 1,
+                        cpu_usage: 1.0,
+                        state: ProcessState::Sleep,
+                        ..process(100 + i, &format!("proc{i}"))
+                    })
+                    .collect(),
+            );
+            app.active_tab = tab;
+            let screen = render_to_string(80, 5, &mut app);
+            assert!(screen.contains("CPU") && screen.contains("MEM"), "{screen}");
+            assert!(screen.contains("proc2"), "three processes fit:\n{screen}");
+            assert!(!screen.contains("proc3"));
+            assert!(!screen.contains("Dashboard"), "tab bar dropped");
+        }
+    }
+
+    // --- Daemon error strings ---
+
+    #[test]
+    fn scrolling_table_pins_header_above_scrollbar() {
+        let mut app = App::test_new(TuiConfig::default())
+            .with_processes((0..50).map(|i| process(i, &format!("p{i}"))).collect());
+        app.process_scroll = 30;
+        let screen = render_widget_to_string(60, 10, |frame, area| {
+            widgets::processes::draw_processes(frame, area, &mut app)
+        });
+        let lines: Vec<&str> = screen.lines().collect();
+        assert!(lines[1].contains("PID"), "header pinned: {screen}");
+        assert!(lines[1].ends_with('\u{2502}'), "no scrollbar on header row");
+        let track: String = lines[2..9]
+            .iter()
+            .filter_map(|l| l.chars().last())
+            .collect();
+        assert!(
+            track.contains('\u{2588}'),
+            "scrollbar thumb in body: {track}"
+        );
+    }
+
+    #[test]
+    fn dashboard_cpu_aggregate_view_renders() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.cpu_history.extend([10.0, 50.0, 90.0]);
+        app.cpu_aggregate_view = true;
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(screen.contains("cores"), "CPU block title");
+        assert!(!screen.contains("widget error"));
+    }
+
+    #[test]
+    fn dashboard_shows_pressure_gauge() {
+        for width in [100, 160] {
+            let mut app = App::test_new(TuiConfig::default());
+            let screen = render_to_string(width, 50, &mut app);
+            assert!(screen.contains("Pressure"), "missing at width {width}");
+        }
+    }
+
+    #[test]
+    fn claude_personal_shows_exhaust_eta() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.claude_personal = Some(crate::data::claudepersonal::ClaudePersonalReport {
+            messages_in_window: 30,
+            message_limit: 45,
+            window_hours: 5,
+            next_slot_secs: 0,
+            first_slot_secs: 3 * 3600,
+            exhaust_eta_secs: Some(25 * 60),
+            buckets: Vec::new(),
+        });
+        let widget = render_widget_to_string(50, 5, |frame, area| {
+            widgets::claudepersonal::draw_claude_personal(frame, area, &app)
+        });
+        assert!(widget.contains("At this rate, limit in 25m00s"), "{widget}");
+    }
+
+    #[test]
+    fn disconnected_sources_show_daemon_errors() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.billing = serde_json::from_str(
+            r#"{"providers": [{"name": "civo", "connected": false, "error": "civo: 401 invalid API key"}]}"#,
+        )
+        .ok();
+        app.claude = serde_json::from_str(
+            r#"{"accounts": [{"name": "work", "connected": false, "error": "admin key revoked"}]}"#,
+        )
+        .ok();
+        app.k8s = serde_json::from_str(
+            r#"{"clusters": [{"context": "prod", "connected": false, "error": "dial tcp: i/o timeout"}]}"#,
+        )
+        .ok();
+
+        app.active_tab = Tab::Billing;
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(screen.contains("civo: 401 invalid API key"), "{screen}");
+        assert!(screen.contains("admin key revoked"), "{screen}");
+
+        app.active_tab = Tab::Network;
+        let screen = render_to_string(160, 50, &mut app);
+        assert!(screen.contains("dial tcp: i/o timeout"), "{screen}");
+    }
+
+    #[test]
+    fn title_shows_configured_label() {
+        let mut cfg = TuiConfig::default();
+        cfg.general.label = "lab-cluster-east".into();
+        let mut app = App::test_new(cfg);
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "prompt-pulse v3 :: lab-cluster-east"));
+    }
+
+    #[test]
+    fn hidden_chrome_skips_tab_and_help_bars() {
+        let mut app = App::test_new(TuiConfig::default());
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "prompt-pulse v3"));
+        app.chrome_hidden = true;
+        let buf = render_app(160, 50, &mut app);
+        assert!(!buffer_contains(&buf, "prompt-pulse v3"));
+        assert!(!buffer_contains(&buf, " Quit "));
+    }
+
+    #[test]
+    fn cpu_header_reflects_normalization() {
+        let mut app = App::test_new(TuiConfig::default()).with_processes(vec![]);
+        app.active_tab = Tab::System;
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "CPU%"));
+        app.cpu_normalize = crate::config::CpuNormalize::Total;
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "Tot%"));
+    }
+
+    #[test]
+    fn kill_log_popup_lists_entries() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.kill_log
+            .push((std::time::Instant::now(), 4242, "runaway-job".into(), true));
+        app.show_kill_log = true;
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "Kill log (1)"));
+        assert!(buffer_contains(&buf, "runaway-job"));
+
+        let popup = render_widget_to_string(60, 8, |frame, area| {
+            widgets::kill_log::draw_kill_log_popup(frame, area, &app)
+        });
+        assert_snapshot!("kill_log_popup", popup);
+    }
+
+    #[test]
+    fn help_bar_snapshots_per_tab() {
+        let mut app = App::test_new(TuiConfig::default());
+        for (tab, name) in [
+            (Tab::Dashboard, "help_bar_dashboard"),
+            (Tab::System, "help_bar_system"),
+            (Tab::Network, "help_bar_network"),
+        ] {
+            app.active_tab = tab;
+            let bar = render_widget_to_string(160, 1, |frame, area| {
+                widgets::help::draw_help_bar(frame, area, &app)
+            });
+            assert_snapshot!(name, bar);
+        }
+    }
+
+    #[test]
+    fn cache_debug_popup_renders() {
+        use crate::app::CacheDebugView;
+        use crate::data::{CacheFileInfo, CacheFileStatus};
+
+        let mut app = App::test_new(TuiConfig::default());
+        app.cache_debug = Some(CacheDebugView {
+            files: vec![CacheFileInfo {
+                name: "billing".into(),
+                path: "/tmp/billing.json".into(),
+                size: 2048,
+                age: Some(std::time::Duration::from_secs(90)),
+                status: CacheFileStatus::Error("missing field".into()),
+            }],
+            selected: 0,
+            raw: None,
+            raw_scroll: 0,
+        });
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "billing.json"));
+        assert!(buffer_contains(&buf, "error: missing field"));
+
+        app.cache_debug.as_mut().unwrap().raw = Some("{\"total\": 1}".into());
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "\"total\": 1"));
+
+        // Scrolling past the end snaps back so the last page stays on screen.
+        let raw: String = (0..100).map(|i| format!("line {i}\n")).collect();
+        let view = app.cache_debug.as_mut().unwrap();
+        view.raw = Some(raw);
+        view.raw_scroll = 500;
+        let buf = render_app(160, 50, &mut app);
+        assert!(buffer_contains(&buf, "line 99"));
+        // 40-row popup: 38 inner rows, so the first shown line is 62.
+        assert_eq!(app.cache_debug.as_ref().unwrap().raw_scroll, 62);
+    }
+
+    #[test]
+    fn panicking_widget_renders_placeholder() {
+        let mut app = App::test_new(TuiConfig::default());
+        let backend = TestBackend::new(60, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                crate::ui::guard::guarded(frame, area, &mut app, "boom", |_, _, _| {
+                    panic!("bad widget data")
+                });
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer().clone();
+        assert!(buffer_contains(&buf, "boom"));
+        assert!(buffer_contains(&buf, "widget error"));
+    }
+
+    #[test]
+    fn widget_name_from_path() {
+        assert_eq!(
+            super::widget_name("widgets :: cpu :: draw_cpu_bars"),
+            "cpu_bars"
+        );
+    }
 }
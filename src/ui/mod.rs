@@ -1,4 +1,9 @@
+pub mod gradient;
+pub mod guard;
+pub mod keymap;
 pub mod layout;
+#[cfg(test)]
+pub(crate) mod testing;
 pub mod widgets;
 
 use ratatui::prelude::*;
@@ -7,42 +12,156 @@ use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap};
 use crate::app::{App, Tab};
 
 /// Top-level draw: tab bar + active tab content + help bar + optional help overlay.
-/// In expand mode, renders the waifu widget fullscreen (no tab bar or help bar).
+/// In expand mode, renders one widget fullscreen (no tab bar or help bar).
 pub fn draw(frame: &mut Frame, app: &mut App) {
-    let area = frame.area();
+    let area = inset(frame.area(), app.cfg.display.margin);
+    gradient::set_colors_enabled(app.colors_enabled);
+
+    // Widgets republish their hit-test rects each frame.
+    app.waifu_rect = None;
+    app.process_rows_rect = None;
+    app.process_menu_rect = None;
+
+    // Expand mode: one widget fullscreen.
+    let content = match app.expanded {
+        Some(widget) => {
+            layout::expanded(frame, area, app, widget);
+            area
+        }
+        None => draw_tabs_and_content(frame, area, app),
+    };
+
+    // Popups go over either path, so a zoomed widget never hides a modal.
+    draw_overlays(frame, area, content, app);
 
-    // Expand mode: fullscreen waifu.
-    if app.expanded {
-        widgets::waifu::draw_waifu(frame, area, app);
-        return;
+    if let Some(rects) = app.layout_dump.take() {
+        log_layout(app, area, &rects);
     }
+}
 
-    // Split into tab bar (3 lines) + content + help bar (1 line).
+/// Smallest UI area `display.margin` may leave; tighter terminals ignore it.
+const MIN_INSET_SIZE: (u16, u16) = (20, 6);
+
+/// The screen minus a `margin`-cell blank border on every side, or the
+/// whole screen when that would leave less than `MIN_INSET_SIZE`.
+pub fn inset(area: Rect, margin: u16) -> Rect {
+    let inner = area.inner(Margin::new(margin, margin));
+    if inner.width < MIN_INSET_SIZE.0 || inner.height < MIN_INSET_SIZE.1 {
+        area
+    } else {
+        inner
+    }
+}
+
+/// `--debug` layout dump: write the Rect each widget was drawn into this
+/// frame to the tracing log, so "widget X is 0-height" reports come with
+/// numbers.
+fn log_layout(app: &mut App, area: Rect, rects: &[(&'static str, Rect)]) {
+    let view = match app.expanded {
+        Some(widget) => format!("expanded {}", widget.id()),
+        None => app.active_tab.title().to_string(),
+    };
+    tracing::info!(
+        "layout dump: {view}, terminal {}x{}",
+        area.width,
+        area.height
+    );
+    for (name, r) in rects {
+        tracing::info!(
+            "layout dump:   {name:<16} x={:<3} y={:<3} {}x{}",
+            r.x,
+            r.y,
+            r.width,
+            r.height
+        );
+    }
+    app.set_status(format!(
+        "layout of {} widgets written to the log",
+        rects.len()
+    ));
+}
+
+/// Tab bar + active tab content + help bar + overlays.
+/// Tab bar, active tab, and help bar; returns the content area.
+fn draw_tabs_and_content(frame: &mut Frame, area: Rect, app: &mut App) -> Rect {
+    // Split into tab bar (3 lines) + content + help bar (1 line); with chrome
+    // hidden ('H') the content gets the whole area.
+    // Panes shorter than the mini-layout threshold also drop the tab bar.
+    let (bar, tab_h) = if app.chrome_hidden {
+        (0, 0)
+    } else if area.height < layout::MINI_HEIGHT {
+        (1, 0)
+    } else {
+        (1, 3)
+    };
+    // Optional metric strip rides along with the tab bar.
+    let strip_h = if app.cfg.display.top_strip && tab_h > 0 {
+        1
+    } else {
+        0
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),
+            Constraint::Length(tab_h),
+            Constraint::Length(strip_h),
             Constraint::Min(0),
-            Constraint::Length(1),
+            Constraint::Length(bar),
         ])
         .split(area);
 
-    widgets::tabs::draw_tabs(frame, chunks[0], app);
+    if tab_h > 0 {
+        widgets::tabs::draw_tabs(frame, chunks[0], app);
+    }
+    if strip_h > 0 {
+        widgets::top_strip::draw_top_strip(frame, chunks[1], app);
+    }
 
     match app.active_tab {
-        Tab::Dashboard => layout::dashboard(frame, chunks[1], app),
-        Tab::System => layout::system(frame, chunks[1], app),
-        Tab::Network => layout::network(frame, chunks[1], app),
-        Tab::Billing => layout::billing(frame, chunks[1], app),
-        Tab::Build => layout::build(frame, chunks[1], app),
+        Tab::Dashboard => layout::dashboard(frame, chunks[2], app),
+        Tab::System => layout::system(frame, chunks[2], app),
+        Tab::Network => layout::network(frame, chunks[2], app),
+        Tab::Billing => layout::billing(frame, chunks[2], app),
+        Tab::Build => layout::build(frame, chunks[2], app),
+    }
+
+    if !app.chrome_hidden {
+        widgets::help::draw_help_bar(frame, chunks[3], app);
     }
 
-    widgets::help::draw_help_bar(frame, chunks[2], app);
+    chunks[2]
+}
+
+/// Popups and menus, drawn last over the whole UI (`content` is the area
+/// below the tab bar, or the zoomed widget).
+fn draw_overlays(frame: &mut Frame, area: Rect, content: Rect, app: &mut App) {
+    // Signal picker popup (over the content area).
+    widgets::signal_menu::draw_signal_menu(frame, content, app);
 
     // Help overlay (centered popup).
     if app.show_help {
         draw_help_overlay(frame, area, app.help_tab);
     }
+
+    // About/version popup.
+    if app.show_about {
+        widgets::buildinfo::draw_about_popup(frame, area, app);
+    }
+
+    // Session kill log popup.
+    widgets::kill_log::draw_kill_log_popup(frame, area, app);
+
+    // Process detail popup.
+    widgets::processes::draw_process_detail(frame, area, app);
+
+    // Color legend popup.
+    widgets::legend::draw_legend_popup(frame, area, app);
+
+    // Cache inspector (--debug).
+    widgets::cache_debug::draw_cache_debug(frame, area, app);
+
+    // Process right-click menu, at the clicked cell.
+    widgets::process_menu::draw_process_menu(frame, area, app);
 }
 
 /// Render a keybinding line: fixed-width key + description.
@@ -63,126 +182,34 @@ fn help_section(title: &str) -> Line<'_> {
     ))
 }
 
-fn help_tab_tui<'a>() -> Vec<Line<'a>> {
-    vec![
-        help_section("Navigation"),
-        Line::from(""),
-        help_line("Tab / Right", "Next tab"),
-        help_line("Shift-Tab / Left", "Previous tab"),
-        help_line("1-5", "Jump to tab"),
-        help_line("Space", "Freeze/resume data"),
-        Line::from(""),
-        help_section("Process Table (System tab)"),
-        Line::from(""),
-        help_line("j/k / Up/Down", "Scroll processes"),
-        help_line("g / Home", "Jump to top"),
-        help_line("G / End", "Jump to bottom"),
-        help_line("/", "Filter by name/PID"),
-        help_line("c / m / p / n", "Sort: CPU/Mem/PID/Name"),
-        help_line("r", "Reverse sort order"),
-        help_line("e", "Toggle full command"),
-        help_line("t", "Toggle tree view"),
-        help_line("PgUp / PgDn", "Jump 10 processes"),
-        help_line("dd", "Kill process (TERM)"),
-        help_line("D", "Force kill (KILL)"),
-        Line::from(""),
-        help_section("Waifu (Dashboard tab)"),
-        Line::from(""),
-        help_line("n / p", "Next / previous image"),
-        help_line("r", "Random image"),
-        help_line("f", "Fetch new from live service"),
-        help_line("i", "Toggle info overlay"),
-        Line::from(""),
-        help_section("Display"),
-        Line::from(""),
-        help_line("+ / -", "Adjust refresh (250ms-5s)"),
-        help_line("?", "This help"),
-        help_line("q / Esc", "Quit"),
-    ]
-}
-
-fn help_tab_shell<'a>() -> Vec<Line<'a>> {
-    vec![
-        help_section("Shell Keybindings"),
-        Line::from(""),
-        help_line("Ctrl+P", "Launch TUI dashboard"),
-        help_line("Ctrl+W", "Launch waifu viewer"),
-        help_line("pp", "prompt-pulse alias"),
-        help_line("pp-tui", "prompt-pulse-tui alias"),
-        help_line("pp-status", "Daemon health check"),
-        help_line("pp-start", "Start daemon"),
-        help_line("pp-stop", "Stop daemon"),
-        help_line("pp-banner", "Show text banner"),
-        Line::from(""),
-        help_section("Starship Prompt"),
-        Line::from(""),
-        help_line("Claude segment", "Purple - API usage & burn rate"),
-        help_line("Billing segment", "Cyan - CIVO + DO costs"),
-        help_line("Infra segment", "Green - Tailscale + K8s"),
-    ]
-}
-
-fn help_tab_lab<'a>() -> Vec<Line<'a>> {
-    vec![
-        help_section("Deployment"),
-        Line::from(""),
-        help_line("just deploy <host>", "Full deployment"),
-        help_line("just nix-switch", "Nix config only"),
-        help_line("just check <host>", "Dry-run with diff"),
-        Line::from(""),
-        help_section("Diagnostics"),
-        Line::from(""),
-        help_line("just doctor", "Run diagnostic checks"),
-        help_line("lab_status", "Show API key status"),
-        help_line("tinyland_build", "Show build info"),
-        Line::from(""),
-        help_section("Development"),
-        Line::from(""),
-        help_line("just test", "Run all tests"),
-        help_line("just molecule <role>", "Molecule test role"),
-        help_line("just test-pbt", "Property-based tests"),
-        help_line("just nix-check", "Nix flake check"),
-        help_line("jb-dev", "DevContainer launcher"),
-    ]
-}
-
-fn help_tab_starship<'a>() -> Vec<Line<'a>> {
-    vec![
-        help_section("Starship Modules"),
-        Line::from(""),
-        help_line("custom.claude", "Claude API usage (purple)"),
-        help_line("custom.billing", "Cloud billing (cyan)"),
-        help_line("custom.infra", "Infra status (green)"),
-        Line::from(""),
-        help_section("Themes"),
-        Line::from(""),
-        help_line("ultra-minimal", "Directory only, fastest"),
-        help_line("minimal", "Dir + git, clean"),
-        help_line("full", "Languages, duration, etc."),
-        help_line("plain", "No special chars"),
-        help_line("monitoring", "With prompt-pulse modules"),
-        Line::from(""),
-        help_section("Configuration"),
-        Line::from(""),
-        help_line("~/.config/starship", "Managed by Nix"),
-        help_line("nix/hosts/base.nix", "Theme selection"),
-        help_line("starship.nix", "Module definitions"),
-    ]
+/// Overlay lines for a help tab, built from the shared keymap data.
+fn help_tab_lines<'a>(help_tab: usize) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+    for (i, section) in keymap::help_tab(help_tab).into_iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(help_section(section.title));
+        lines.push(Line::from(""));
+        for (key, desc) in section.bindings {
+            lines.push(help_line(key, desc));
+        }
+    }
+    lines
 }
 
 fn draw_help_overlay(frame: &mut Frame, area: Rect, help_tab: usize) {
     let popup_width = 56u16.min(area.width.saturating_sub(4));
     let popup_height = 34u16.min(area.height.saturating_sub(4));
 
-    let x = (area.width.saturating_sub(popup_width)) / 2;
-    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
 
     frame.render_widget(Clear, popup_area);
 
     // Tab selector line.
-    let tab_names = ["TUI", "Shell", "Lab", "Starship"];
-    let tab_spans: Vec<Span> = tab_names
+    let tab_spans: Vec<Span> = keymap::HELP_TABS
         .iter()
         .enumerate()
         .map(|(i, name)| {
@@ -205,14 +232,7 @@ fn draw_help_overlay(frame: &mut Frame, area: Rect, help_tab: usize) {
     let mut lines = vec![Line::from(tab_spans), Line::from("")];
 
     // Tab content.
-    let content = match help_tab {
-        0 => help_tab_tui(),
-        1 => help_tab_shell(),
-        2 => help_tab_lab(),
-        3 => help_tab_starship(),
-        _ => help_tab_tui(),
-    };
-    lines.extend(content);
+    lines.extend(help_tab_lines(help_tab));
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
@@ -0,0 +1,128 @@
+//! Shared render helpers for UI tests, plus golden-file snapshots.
+//!
+//! Goldens live in `tests/snapshots/<name>.txt`. Run with
+//! `UPDATE_SNAPSHOTS=1 cargo test` to (re)write them after an intended
+//! layout change, then review the diff like any other code change.
+
+use std::path::PathBuf;
+
+use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, Frame, Terminal};
+
+use crate::app::{App, ProcessInfo, ProcessState};
+use crate::data::sysmetrics::{NetInfo, NetKind};
+
+/// An idle, running process row with only a PID and name; tests override
+/// the fields they care about with `..process(pid, name)`.
+pub fn process(pid: u32, name: &str) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        ppid: 0,
+        name: name.into(),
+        cmd: String::new(),
+        user: String::new(),
+        cpu_usage: 0.0,
+        memory_bytes: 0,
+        state: ProcessState::Run,
+        run_time_secs: 0,
+        tree_depth: 0,
+        cgroup: String::new(),
+        exe: String::new(),
+        group_count: 1,
+    }
+}
+
+/// An up Ethernet interface with the given byte counters and no rate yet.
+pub fn iface(name: &str, rx: u64, tx: u64) -> NetInfo {
+    NetInfo {
+        name: name.into(),
+        kind: NetKind::Ethernet,
+        rx_bytes: rx,
+        tx_bytes: tx,
+        rx_rate: 0,
+        tx_rate: 0,
+        up: Some(true),
+        mac: None,
+    }
+}
+
+/// Set to rewrite golden files instead of comparing against them.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Render the whole UI at `width` x `height`.
+pub fn render_app(width: u16, height: u16, app: &mut App) -> Buffer {
+    app.on_resize(width, height);
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|frame| crate::ui::draw(frame, app)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Buffer text only (styles stripped), one line per row with trailing
+/// spaces trimmed.
+pub fn buffer_to_string(buf: &Buffer) -> String {
+    let width = buf.area.width.max(1) as usize;
+    buf.content()
+        .chunks(width)
+        .map(|row| {
+            let line: String = row.iter().map(|c| c.symbol()).collect();
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render the whole UI and return it as plain text.
+pub fn render_to_string(width: u16, height: u16, app: &mut App) -> String {
+    buffer_to_string(&render_app(width, height, app))
+}
+
+/// Render a single widget into a `width` x `height` area and return it as
+/// plain text. Prefer this for goldens: the full UI includes live host data
+/// (clock, disks, interfaces) that differs between machines.
+pub fn render_widget_to_string(
+    width: u16,
+    height: u16,
+    draw: impl FnOnce(&mut Frame, Rect),
+) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|frame| draw(frame, frame.area())).unwrap();
+    buffer_to_string(terminal.backend().buffer())
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.txt"))
+}
+
+/// Compare `actual` with the golden `name`, or rewrite it in update mode.
+pub fn check_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+    if std::env::var_os(UPDATE_ENV).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{actual}\n")).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "missing snapshot {} ({e}); run with {UPDATE_ENV}=1 to create it",
+            path.display()
+        )
+    });
+    pretty_assertions::assert_eq!(
+        expected.strip_suffix('\n').unwrap_or(&expected),
+        actual,
+        "snapshot {name} changed; rerun with {UPDATE_ENV}=1 if intended"
+    );
+}
+
+/// `assert_snapshot!("name", text)` checks `text` against
+/// `tests/snapshots/name.txt`.
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::ui::testing::check_snapshot($name, &$actual)
+    };
+}
+pub(crate) use assert_snapshot;
@@ -1,7 +1,9 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Borders, Gauge, Paragraph, Row, Table};
 
+use super::{daemon_error, error_style, no_data_text, with_skew_warning};
 use crate::app::App;
+use crate::ui::gradient::gate;
 
 pub fn draw_billing(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
@@ -11,7 +13,16 @@ pub fn draw_billing(frame: &mut Frame, area: Rect, app: &App) {
 
     match &app.billing {
         Some(billing) => {
-            let title = format!(" Cloud Billing (${:.2}/mo) ", billing.total_monthly_usd);
+            let block = with_skew_warning(block, billing.timestamp, app.clock_skew_secs);
+            let hosts = billing.host_count();
+            let title = if hosts > 1 {
+                format!(
+                    " Cloud Billing (${:.2}/mo, {hosts} hosts) ",
+                    billing.total_monthly_usd
+                )
+            } else {
+                format!(" Cloud Billing (${:.2}/mo) ", billing.total_monthly_usd)
+            };
 
             let inner = block.clone().title(title.clone());
 
@@ -50,7 +61,7 @@ pub fn draw_billing(frame: &mut Frame, area: Rect, app: &App) {
             }
         }
         None => {
-            let paragraph = Paragraph::new("No billing data")
+            let paragraph = Paragraph::new(no_data_text("No billing data", app.daemon_silence()))
                 .style(Style::default().fg(Color::DarkGray))
                 .block(block.title(" Cloud Billing "));
             frame.render_widget(paragraph, area);
@@ -59,29 +70,36 @@ pub fn draw_billing(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn draw_providers(frame: &mut Frame, area: Rect, billing: &crate::data::BillingReport) {
-    let rows: Vec<Row> = billing
-        .providers
-        .iter()
-        .enumerate()
-        .map(|(i, p)| {
-            let color = if p.connected {
-                Color::Green
-            } else {
-                Color::Red
-            };
-            let bg = if i % 2 == 1 {
-                Color::Rgb(30, 30, 40)
-            } else {
-                Color::Reset
-            };
+    let mut rows: Vec<Row> = Vec::new();
+    for (i, p) in billing.providers.iter().enumerate() {
+        let color = if p.connected {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        let bg = if i % 2 == 1 {
+            gate(Color::Rgb(30, 30, 40))
+        } else {
+            Color::Reset
+        };
+        rows.push(
             Row::new(vec![
-                p.name.clone(),
+                // Providers merged from another machine's cache carry its label.
+                if p.host.is_empty() {
+                    p.name.clone()
+                } else {
+                    format!("{} @{}", p.name, p.host)
+                },
                 format!("${:.2}", p.month_to_date),
                 format!("{} resources", p.resources.len()),
             ])
-            .style(Style::default().fg(color).bg(bg))
-        })
-        .collect();
+            .style(Style::default().fg(color).bg(bg)),
+        );
+        // Why the provider is disconnected (bad token, API down, ...).
+        if let Some(err) = daemon_error(p.connected, &p.error) {
+            rows.push(Row::new(vec![format!("  \u{21b3} {err}")]).style(error_style()));
+        }
+    }
 
     if !rows.is_empty() {
         let widths = [
@@ -1,8 +1,8 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
 
 use crate::app::App;
-use crate::data::buildinfo::TuiBuildInfo;
+use crate::data::buildinfo::{self, TuiBuildInfo};
 
 pub fn draw_build_info(frame: &mut Frame, area: Rect, app: &App) {
     let build = TuiBuildInfo::current();
@@ -61,6 +61,34 @@ pub fn draw_build_info(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
+/// Small centered about/version popup ('v' from any tab).
+pub fn draw_about_popup(frame: &mut Frame, area: Rect, app: &App) {
+    let fields = buildinfo::about_fields(app.component_versions.daemon.as_ref());
+
+    let popup_width = 48u16.min(area.width.saturating_sub(4));
+    let popup_height = (fields.len() as u16 + 5).min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![section_header("prompt-pulse-tui")];
+    for (key, value) in fields {
+        lines.push(kv_line(key, value));
+    }
+    lines.push(Line::from(""));
+    lines.push(dim_line("  Any key to close."));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" About (v) ")
+        .border_style(Style::default().fg(Color::Magenta));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
 fn section_header(title: &str) -> Line<'static> {
     Line::from(Span::styled(
         format!("  {title}"),
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{
+    Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap,
+};
+
+use super::{render_scrolling_table, selection};
+use crate::app::App;
+use crate::data::CacheFileStatus;
+
+/// `--debug` cache inspector: file list, or the selected file's raw JSON.
+pub fn draw_cache_debug(frame: &mut Frame, area: Rect, app: &mut App) {
+    let selection_style = app.selection_style;
+    let Some(view) = app.cache_debug.as_mut() else {
+        return;
+    };
+
+    let popup_width = (area.width * 9 / 10).max(20).min(area.width);
+    let popup_height = (area.height * 8 / 10).max(6).min(area.height);
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    if let Some(raw) = &view.raw {
+        let name = view
+            .files
+            .get(view.selected)
+            .map_or_else(|| "?".to_string(), |f| f.file_name());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!(" {name} "))
+            .title_bottom(Line::from(" j/k scroll  PgUp/PgDn page  Esc back ").centered())
+            .border_style(Style::default().fg(Color::Magenta));
+        let inner = block.inner(popup_area);
+        let paragraph = Paragraph::new(raw.as_str()).wrap(Wrap { trim: false });
+        // Stop scrolling once the last line is on screen.
+        let max_scroll = paragraph
+            .line_count(inner.width)
+            .saturating_sub(inner.height as usize);
+        view.raw_scroll = view
+            .raw_scroll
+            .min(max_scroll.min(u16::MAX as usize) as u16);
+        let paragraph = paragraph.block(block).scroll((view.raw_scroll, 0));
+        frame.render_widget(paragraph, popup_area);
+        return;
+    }
+
+    let header = Row::new(["File", "Size", "Age", "Status"]).style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows: Vec<Row> = view
+        .files
+        .iter()
+        .map(|f| {
+            let (status, color) = match &f.status {
+                CacheFileStatus::Ok => ("ok".to_string(), Color::Green),
+                CacheFileStatus::Stale => ("stale".to_string(), Color::Yellow),
+                CacheFileStatus::Error(e) => (format!("error: {e}"), Color::Red),
+            };
+            Row::new(vec![
+                Cell::from(f.file_name()),
+                Cell::from(format_size(f.size)),
+                Cell::from(f.age.map(format_age).unwrap_or_else(|| "?".into())),
+                Cell::from(status).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Cache files ({}) [debug] ", view.files.len()))
+        .title_bottom(Line::from(" Enter raw JSON  r rescan  Esc close ").centered())
+        .border_style(Style::default().fg(Color::Magenta));
+
+    if rows.is_empty() {
+        let msg = Paragraph::new(Span::styled(
+            "  No *.json files in the cache directory.",
+            Style::default().fg(Color::DarkGray),
+        ))
+        .block(block);
+        frame.render_widget(msg, popup_area);
+        return;
+    }
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(24),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Min(10),
+        ],
+    )
+    .row_highlight_style(selection(
+        selection_style,
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ));
+    let row_count = view.files.len();
+    let mut state = TableState::default().with_selected(Some(view.selected));
+    render_scrolling_table(
+        frame,
+        popup_area,
+        Some(block),
+        table,
+        header,
+        row_count,
+        &mut state,
+    );
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1}M", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1}K", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes}B")
+    }
+}
+
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m{}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
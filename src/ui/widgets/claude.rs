@@ -1,7 +1,10 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Row, Table};
+use ratatui::widgets::{Block, BorderType, Borders, Cell, Gauge, Paragraph, Row, Table};
 
+use super::{daemon_error, error_style, no_data_text, with_skew_warning};
 use crate::app::App;
+use crate::data::claude::AccountUsage;
+use crate::ui::gradient::gate;
 
 pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
@@ -11,6 +14,7 @@ pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
 
     match &app.claude {
         Some(claude) => {
+            let block = with_skew_warning(block, claude.timestamp, app.clock_skew_secs);
             // Aggregate token counts across all accounts.
             let total_in: i64 = claude
                 .accounts
@@ -52,22 +56,28 @@ pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
                             .map(|m| m.model.clone())
                             .collect::<Vec<_>>()
                             .join(", ");
-                        let color = if a.connected {
-                            Color::Green
-                        } else {
+                        let color = if !a.connected {
                             Color::Red
+                        } else {
+                            a.cap_ratio().map_or(Color::Green, cap_color)
                         };
                         let bg = if i % 2 == 1 {
-                            Color::Rgb(30, 30, 40)
+                            gate(Color::Rgb(30, 30, 40))
                         } else {
                             Color::Reset
                         };
                         let acct_tokens =
                             a.current_month.input_tokens + a.current_month.output_tokens;
+                        // A disconnected account shows the daemon's reason
+                        // in place of its (stale) model list.
+                        let models = match daemon_error(a.connected, &a.error) {
+                            Some(err) => Cell::from(err).style(error_style()),
+                            None => Cell::from(models),
+                        };
                         Row::new(vec![
-                            a.name.clone(),
-                            format!("${:.2}", a.current_month.cost_usd),
-                            format_tokens(acct_tokens),
+                            Cell::from(a.name.clone()),
+                            Cell::from(format!("${:.2}", a.current_month.cost_usd)),
+                            Cell::from(format_tokens(acct_tokens)),
                             models,
                         ])
                         .style(Style::default().fg(color).bg(bg))
@@ -81,10 +91,26 @@ pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
                     Constraint::Min(16),
                 ];
 
-                let table = Table::new(rows, widths)
-                    .header(header)
-                    .block(block.title(title));
-                frame.render_widget(table, area);
+                // One spend-vs-cap gauge per capped account, under the table.
+                let capped: Vec<&AccountUsage> = claude
+                    .accounts
+                    .iter()
+                    .filter(|a| a.cap().is_some())
+                    .collect();
+                let block = block.title(title);
+                let inner = block.inner(area);
+                frame.render_widget(block, area);
+                let gauge_rows = (capped.len() as u16).min(inner.height.saturating_sub(3));
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(3), Constraint::Length(gauge_rows)])
+                    .split(inner);
+
+                let table = Table::new(rows, widths).header(header);
+                frame.render_widget(table, chunks[0]);
+                for (a, row) in capped.iter().zip(chunks[1].rows()) {
+                    draw_cap_gauge(frame, row, a);
+                }
             } else {
                 let text = format!("Total: ${:.2}", claude.total_cost_usd);
                 let paragraph = Paragraph::new(text)
@@ -94,7 +120,7 @@ pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
             }
         }
         None => {
-            let paragraph = Paragraph::new("No Claude data")
+            let paragraph = Paragraph::new(no_data_text("No Claude data", app.daemon_silence()))
                 .style(Style::default().fg(Color::DarkGray))
                 .block(block.title(" Claude "));
             frame.render_widget(paragraph, area);
@@ -102,9 +128,50 @@ pub fn draw_claude(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Spend against the account's monthly cap: "work $150.00/$200 (75%)",
+/// plus the projected month-end spend when that would overshoot.
+fn draw_cap_gauge(frame: &mut Frame, area: Rect, account: &AccountUsage) {
+    let (Some(cap), Some(ratio)) = (account.cap(), account.cap_ratio()) else {
+        return;
+    };
+    let mut label = format!(
+        "{} ${:.2}/${cap:.0} ({:.0}%)",
+        account.name,
+        account.current_month.cost_usd,
+        ratio * 100.0
+    );
+    if account.projected_monthly > cap {
+        label.push_str(&format!(" proj ${:.0}", account.projected_monthly));
+    }
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(cap_color(ratio)))
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(label);
+    frame.render_widget(gauge, area);
+}
+
+/// Cap usage color, matching the personal-plan gauge: red from 90%,
+/// yellow from 70%.
+fn cap_color(ratio: f64) -> Color {
+    let color = if ratio >= 0.90 {
+        Color::Red
+    } else if ratio >= 0.70 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    gate(color)
+}
+
+/// Compact token count (K/M/B/T). Thresholds sit just below each unit so
+/// rounding never prints "1000K" or "1000.0M".
 fn format_tokens(tokens: i64) -> String {
     let t = tokens.unsigned_abs();
-    if t >= 1_000_000 {
+    if t >= 999_950_000_000 {
+        format!("{:.1}T", t as f64 / 1_000_000_000_000.0)
+    } else if t >= 999_950_000 {
+        format!("{:.1}B", t as f64 / 1_000_000_000.0)
+    } else if t >= 999_500 {
         format!("{:.1}M", t as f64 / 1_000_000.0)
     } else if t >= 1_000 {
         format!("{:.0}K", t as f64 / 1_000.0)
@@ -112,3 +179,38 @@ fn format_tokens(tokens: i64) -> String {
         format!("{t}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cap_gauge_label() {
+        let account: AccountUsage = serde_json::from_value(serde_json::json!({
+            "name": "work",
+            "monthly_cap": 200.0,
+            "projected_monthly": 260.0,
+            "current_month": { "cost_usd": 150.0 }
+        }))
+        .unwrap();
+        let out = crate::ui::testing::render_widget_to_string(50, 1, |frame, area| {
+            draw_cap_gauge(frame, area, &account);
+        });
+        assert!(out.contains("work $150.00/$200 (75%) proj $260"), "{out}");
+        assert_eq!(cap_color(0.75), Color::Yellow);
+    }
+
+    #[test]
+    fn test_format_tokens_boundaries() {
+        assert_eq!(format_tokens(999), "999");
+        assert_eq!(format_tokens(1_000), "1K");
+        assert_eq!(format_tokens(999_499), "999K");
+        assert_eq!(format_tokens(999_999), "1.0M");
+        assert_eq!(format_tokens(1_000_000), "1.0M");
+        assert_eq!(format_tokens(999_949_999), "999.9M");
+        assert_eq!(format_tokens(999_999_999), "1.0B");
+        assert_eq!(format_tokens(1_500_000_000), "1.5B");
+        assert_eq!(format_tokens(2_500_000_000_000), "2.5T");
+        assert_eq!(format_tokens(-1_500_000_000), "1.5B");
+    }
+}
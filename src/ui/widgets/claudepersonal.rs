@@ -2,6 +2,9 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Borders, Gauge, Paragraph};
 
 use crate::app::App;
+use crate::ui::gradient::gate;
+use crate::ui::widgets::no_data_text;
+use crate::ui::widgets::sparkline::render_series;
 
 pub fn draw_claude_personal(frame: &mut Frame, area: Rect, app: &App) {
     let (title, gauge_ratio, gauge_color, status_text) = match &app.claude_personal {
@@ -24,14 +27,10 @@ pub fn draw_claude_personal(frame: &mut Frame, area: Rect, app: &App) {
             };
             let remaining = (report.message_limit - report.messages_in_window).max(0);
             let mut status = format!("{} remaining in {}h window", remaining, report.window_hours);
-            if report.next_slot_secs > 0 {
-                let hours = report.next_slot_secs / 3600;
-                let mins = (report.next_slot_secs % 3600) / 60;
-                if hours > 0 {
-                    status.push_str(&format!("  Reset: {}h{:02}m", hours, mins));
-                } else {
-                    status.push_str(&format!("  Reset: {}m", mins));
-                }
+            // Live countdown from the last cache read.
+            let next_slot = app.claude_personal_countdown();
+            if next_slot > 0 {
+                status.push_str(&format!("  Reset: {}", format_countdown(next_slot)));
             }
             (title, ratio, color, status)
         }
@@ -50,7 +49,7 @@ pub fn draw_claude_personal(frame: &mut Frame, area: Rect, app: &App) {
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
-        .border_style(Style::default().fg(Color::Rgb(124, 58, 237))); // purple
+        .border_style(Style::default().fg(gate(Color::Rgb(124, 58, 237)))); // purple
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -59,11 +58,17 @@ pub fn draw_claude_personal(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
-    if app.claude_personal.is_some() {
-        // Split inner into gauge + status line.
+    if let Some(report) = &app.claude_personal {
+        // Split inner into gauge + status line + exhaust ETA + message-rate graph.
+        let eta_rows = u16::from(report.exhaust_eta_secs.is_some());
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(eta_rows),
+                Constraint::Min(0),
+            ])
             .split(inner);
 
         let gauge = Gauge::default()
@@ -75,10 +80,50 @@ pub fn draw_claude_personal(frame: &mut Frame, area: Rect, app: &App) {
             let status = Paragraph::new(status_text).style(Style::default().fg(Color::Gray));
             frame.render_widget(status, chunks[1]);
         }
+
+        // Red when the limit arrives before the oldest message frees a slot.
+        if let (Some(eta), true) = (report.exhaust_eta_secs, chunks[2].height > 0) {
+            let color = if eta < report.first_slot_secs {
+                Color::Red
+            } else {
+                Color::Gray
+            };
+            let line = format!("At this rate, limit in {}", format_countdown(eta));
+            frame.render_widget(
+                Paragraph::new(line).style(Style::default().fg(color)),
+                chunks[2],
+            );
+        }
+
+        // Messages per 5-minute bucket (oldest left), when height permits.
+        if chunks[3].height > 0 && !report.buckets.is_empty() {
+            let data: Vec<u64> = report.buckets.iter().map(|&b| b.max(0) as u64).collect();
+            render_series(
+                frame,
+                chunks[3],
+                None,
+                &data,
+                None,
+                Color::Rgb(124, 58, 237),
+                app.sparkline_style,
+                app.sparkline_newest,
+            );
+        }
     } else {
-        let paragraph = Paragraph::new(status_text)
+        let paragraph = Paragraph::new(no_data_text(&status_text, app.daemon_silence()))
             .style(Style::default().fg(Color::DarkGray))
             .alignment(Alignment::Center);
         frame.render_widget(paragraph, inner);
     }
 }
+
+/// "1h05m" above an hour, "4m09s" below.
+fn format_countdown(secs: i64) -> String {
+    let hours = secs / 3600;
+    let mins = (secs % 3600) / 60;
+    if hours > 0 {
+        format!("{hours}h{mins:02}m")
+    } else {
+        format!("{mins}m{:02}s", secs % 60)
+    }
+}
@@ -1,25 +1,33 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, Gauge};
 
+use super::sparkline::render_series;
 use crate::app::App;
 
-pub fn draw_cpu_bars(frame: &mut Frame, area: Rect, app: &App) {
+/// Shared CPU block: "CPU (x% avg, N cores @ f GHz)".
+fn cpu_block(app: &App) -> Block<'static> {
     let snap = app.sys.snapshot();
-
+    let cpu_total = app.cpu_display_total();
     let freq_tag = if snap.cpu_freq_mhz > 0 {
         let ghz = snap.cpu_freq_mhz as f64 / 1000.0;
         format!(" @ {ghz:.1}GHz")
     } else {
         String::new()
     };
-    let block = Block::default()
+    Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(format!(
             " CPU ({:.1}% avg, {} cores{freq_tag}) ",
-            snap.cpu_total, snap.cpu_count
+            cpu_total, snap.cpu_count
         ))
-        .border_style(Style::default().fg(Color::Blue));
+        .border_style(Style::default().fg(Color::Blue))
+}
+
+pub fn draw_cpu_bars(frame: &mut Frame, area: Rect, app: &App) {
+    let snap = app.sys.snapshot();
+    let cpu_total = app.cpu_display_total();
+    let block = cpu_block(app);
 
     // If enough height, show per-core bar chart. Otherwise, show aggregate gauge.
     if area.height >= 6 && snap.cpu_usage.len() > 1 {
@@ -28,7 +36,7 @@ pub fn draw_cpu_bars(frame: &mut Frame, area: Rect, app: &App) {
             .iter()
             .enumerate()
             .map(|(i, &usage)| {
-                let color = usage_color(usage);
+                let color = app.gradient.color(usage as f64);
                 Bar::default()
                     .label(Line::from(format!("{i}")))
                     .value(usage as u64)
@@ -47,24 +55,42 @@ pub fn draw_cpu_bars(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         let gauge = Gauge::default()
             .block(block)
-            .gauge_style(Style::default().fg(usage_color(snap.cpu_total)))
-            .ratio((snap.cpu_total as f64 / 100.0).clamp(0.0, 1.0))
-            .label(format!("{:.1}%", snap.cpu_total));
+            .gauge_style(Style::default().fg(app.gradient.color(cpu_total as f64)))
+            .ratio((cpu_total as f64 / 100.0).clamp(0.0, 1.0))
+            .label(format!("{cpu_total:.1}%"));
 
         frame.render_widget(gauge, area);
     }
 }
 
-fn usage_color(pct: f32) -> Color {
-    if pct >= 90.0 {
-        Color::Red
-    } else if pct >= 80.0 {
-        Color::Rgb(255, 100, 0) // orange-red
-    } else if pct >= 65.0 {
-        Color::Yellow
-    } else if pct >= 40.0 {
-        Color::Rgb(150, 255, 0) // yellow-green
-    } else {
-        Color::Green
-    }
+/// Aggregate CPU (Dashboard `U` view): one gauge over the CPU history,
+/// calmer than per-core bars on many-core machines.
+pub fn draw_cpu_aggregate(frame: &mut Frame, area: Rect, app: &App) {
+    let cpu_total = app.cpu_display_total();
+    let color = app.gradient.color(cpu_total as f64);
+    let block = cpu_block(app);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(color))
+        .ratio((cpu_total as f64 / 100.0).clamp(0.0, 1.0))
+        .label(format!("{cpu_total:.1}%"));
+    frame.render_widget(gauge, chunks[0]);
+
+    let data: Vec<u64> = app.cpu_history.iter().map(|v| *v as u64).collect();
+    render_series(
+        frame,
+        chunks[1],
+        None,
+        &data,
+        Some(100),
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
@@ -65,25 +65,25 @@ pub fn draw_disks(frame: &mut Frame, area: Rect, app: &App) {
             break;
         }
 
-        let color = pct_gradient(disk.percent);
+        let color = app.gradient.color(disk.percent);
         let icon = if disk.is_removable { "\u{23cf} " } else { "" };
 
         let avail = disk.total.saturating_sub(disk.used);
-        let fs_tag = if disk.fs_type.is_empty() {
-            String::new()
-        } else {
-            format!(" [{}]", disk.fs_type)
+        let precision = app.disk_precision;
+        // Compact I/O suffix once two samples exist (Linux only).
+        let io_tag = match (disk.read_rate, disk.write_rate) {
+            (Some(r), Some(w)) => format!("  R {} W {}", format_io_rate(r), format_io_rate(w)),
+            _ => String::new(),
         };
-        let label = format!(
-            "{}{}{}: {} / {} ({:.0}%) {} free",
-            icon,
-            truncate_mount(&disk.mount, 18),
-            fs_tag,
-            format_bytes(disk.used),
-            format_bytes(disk.total),
-            disk.percent,
-            format_bytes(avail),
-        );
+        let fields = [
+            ("mount", truncate_mount(&disk.mount, 18).to_string()),
+            ("fs", disk.fs_type.clone()),
+            ("used", format_bytes(disk.used, precision)),
+            ("total", format_bytes(disk.total, precision)),
+            ("free", format_bytes(avail, precision)),
+            ("percent", format!("{:.0}", disk.percent)),
+        ];
+        let label = format!("{icon}{}{io_tag}", render_label(&app.disk_label, &fields));
 
         let gauge = Gauge::default()
             .gauge_style(Style::default().fg(color))
@@ -94,20 +94,6 @@ pub fn draw_disks(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
-fn pct_gradient(pct: f64) -> Color {
-    if pct >= 90.0 {
-        Color::Red
-    } else if pct >= 80.0 {
-        Color::Rgb(255, 100, 0)
-    } else if pct >= 65.0 {
-        Color::Yellow
-    } else if pct >= 40.0 {
-        Color::Rgb(150, 255, 0)
-    } else {
-        Color::Green
-    }
-}
-
 fn truncate_mount(mount: &str, max_len: usize) -> &str {
     if mount.len() <= max_len {
         mount
@@ -116,26 +102,136 @@ fn truncate_mount(mount: &str, max_len: usize) -> &str {
     }
 }
 
-fn format_bytes(bytes: u64) -> String {
-    const GIB: u64 = 1024 * 1024 * 1024;
+/// Fill `{name}` placeholders in a `display.disk_label` template in one
+/// left-to-right pass, so values containing braces (a mount path like
+/// `/mnt/{fs}`) are never substituted again. An empty field drops its
+/// `[{fs}]` group (and the space before it) rather than leaving "[]";
+/// unknown placeholders stay as typed.
+fn render_label(template: &str, fields: &[(&str, String)]) -> String {
+    let mut label = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let (before, tail) = rest.split_at(open);
+        label.push_str(before);
+        let Some(close) = tail.find('}') else {
+            rest = tail;
+            break;
+        };
+        let after = &tail[close + 1..];
+        match fields.iter().find(|(name, _)| *name == &tail[1..close]) {
+            Some((_, value))
+                if value.is_empty() && before.ends_with('[') && after.starts_with(']') =>
+            {
+                label.pop();
+                if label.ends_with(' ') {
+                    label.pop();
+                }
+                rest = &after[1..];
+            }
+            Some((_, value)) => {
+                label.push_str(value);
+                rest = after;
+            }
+            None => {
+                label.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    label.push_str(rest);
+    label
+}
+
+/// Size with `precision` decimals in the largest unit that keeps it >= 1:
+/// T, G, or M (small SD cards and boot partitions stay readable).
+fn format_bytes(bytes: u64, precision: usize) -> String {
+    const MIB: u64 = 1024 * 1024;
+    const GIB: u64 = 1024 * MIB;
     const TIB: u64 = 1024 * GIB;
-    if bytes >= TIB {
-        format!("{:.1}T", bytes as f64 / TIB as f64)
+    let (unit, suffix) = if bytes >= TIB {
+        (TIB, "T")
+    } else if bytes >= GIB {
+        (GIB, "G")
+    } else {
+        (MIB, "M")
+    };
+    format!("{:.precision$}{suffix}", bytes as f64 / unit as f64)
+}
+
+fn format_io_rate(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    const KIB: u64 = 1024;
+    if bytes >= MIB {
+        format!("{:.1}M/s", bytes as f64 / MIB as f64)
+    } else if bytes >= KIB {
+        format!("{:.0}K/s", bytes as f64 / KIB as f64)
     } else {
-        format!("{:.1}G", bytes as f64 / GIB as f64)
+        format!("{bytes}B/s")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::gradient::Gradient;
 
     #[test]
     fn test_pct_gradient_disk_thresholds() {
         // Low usage should be Green, high usage Red
-        let low = pct_gradient(20.0);
-        let high = pct_gradient(95.0);
+        let low = Gradient::default().color(20.0);
+        let high = Gradient::default().color(95.0);
         assert_eq!(low, Color::Green);
         assert_eq!(high, Color::Red);
     }
+
+    #[test]
+    fn test_format_bytes_adapts_to_small_disks() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        assert_eq!(format_bytes(500 * GIB, 1), "500.0G");
+        assert_eq!(format_bytes(2048 * GIB, 1), "2.0T");
+        assert_eq!(format_bytes(256 * 1024 * 1024, 1), "256.0M");
+        assert_eq!(format_bytes(GIB + GIB / 4, 2), "1.25G");
+        assert_eq!(format_bytes(GIB + GIB / 4, 0), "1G");
+    }
+
+    #[test]
+    fn test_render_label() {
+        use crate::config::DEFAULT_DISK_LABEL;
+        let mut fields = [
+            ("mount", "/".to_string()),
+            ("fs", "ext4".to_string()),
+            ("used", "20.0G".to_string()),
+            ("total", "100.0G".to_string()),
+            ("free", "80.0G".to_string()),
+            ("percent", "20".to_string()),
+        ];
+        assert_eq!(
+            render_label(DEFAULT_DISK_LABEL, &fields),
+            "/ [ext4]: 20.0G / 100.0G (20%) 80.0G free"
+        );
+        fields[1].1.clear();
+        assert_eq!(
+            render_label(DEFAULT_DISK_LABEL, &fields),
+            "/: 20.0G / 100.0G (20%) 80.0G free"
+        );
+        assert_eq!(
+            render_label("{mount} {free} left {oops}", &fields),
+            "/ 80.0G left {oops}"
+        );
+
+        // Values are inserted as-is, never re-scanned for placeholders.
+        fields[0].1 = "/mnt/{fs}-{used}".to_string();
+        fields[1].1 = "xfs".to_string();
+        assert_eq!(
+            render_label("{mount} [{fs}] {used} {unclosed", &fields),
+            "/mnt/{fs}-{used} [xfs] 20.0G {unclosed"
+        );
+    }
+
+    #[test]
+    fn test_format_io_rate_units() {
+        assert_eq!(format_io_rate(0), "0B/s");
+        assert_eq!(format_io_rate(4096), "4K/s");
+        assert_eq!(format_io_rate(3 * 1024 * 1024 / 2), "1.5M/s");
+    }
 }
@@ -0,0 +1,164 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders};
+
+use super::sparkline::render_collecting;
+use crate::app::App;
+use crate::ui::gradient::Gradient;
+
+/// Widest cell a core gets when there is room to spare.
+const MAX_CELL_WIDTH: u16 = 6;
+
+/// One cell per core, colored by current usage and shaded by level so it
+/// still reads without color. Cores wrap onto further rows when the area is
+/// narrower than the core count.
+pub struct CoreHeatmap<'a> {
+    usage: &'a [f32],
+    gradient: &'a Gradient,
+    hovered: Option<usize>,
+}
+
+impl<'a> CoreHeatmap<'a> {
+    pub fn new(usage: &'a [f32], gradient: &'a Gradient) -> Self {
+        Self {
+            usage,
+            gradient,
+            hovered: None,
+        }
+    }
+
+    pub fn hovered(mut self, core: Option<usize>) -> Self {
+        self.hovered = core;
+        self
+    }
+}
+
+impl Widget for CoreHeatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (cell_w, per_row) = cell_layout(self.usage.len(), area.width);
+        if cell_w == 0 {
+            return;
+        }
+        for (core, &pct) in self.usage.iter().enumerate() {
+            let row = (core / per_row) as u16;
+            if row >= area.height {
+                break;
+            }
+            let x = area.x + (core % per_row) as u16 * cell_w;
+            let mut style = Style::default().fg(self.gradient.color(pct as f64));
+            if self.hovered == Some(core) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            let glyph = shade(pct);
+            for dx in 0..cell_w {
+                if let Some(cell) = buf.cell_mut((x + dx, area.y + row)) {
+                    cell.set_char(glyph).set_style(style);
+                }
+            }
+        }
+    }
+}
+
+/// Cell width and cells per row for `cores` cores in `width` columns.
+fn cell_layout(cores: usize, width: u16) -> (u16, usize) {
+    if cores == 0 || width == 0 {
+        return (0, 0);
+    }
+    let cell_w = (width / cores.min(u16::MAX as usize) as u16).clamp(1, MAX_CELL_WIDTH);
+    (cell_w, (width / cell_w) as usize)
+}
+
+/// Core under `pos`, if the pointer is over a drawn cell.
+fn core_at(area: Rect, cores: usize, pos: Position) -> Option<usize> {
+    if !area.contains(pos) {
+        return None;
+    }
+    let (cell_w, per_row) = cell_layout(cores, area.width);
+    let col = ((pos.x - area.x) / cell_w.max(1)) as usize;
+    if col >= per_row {
+        return None;
+    }
+    let core = (pos.y - area.y) as usize * per_row + col;
+    (core < cores).then_some(core)
+}
+
+/// Shade glyph by usage quartile.
+fn shade(pct: f32) -> char {
+    match pct {
+        p if p >= 75.0 => '\u{2588}',
+        p if p >= 50.0 => '\u{2593}',
+        p if p >= 25.0 => '\u{2592}',
+        _ => '\u{2591}',
+    }
+}
+
+/// Per-core CPU as a heatmap strip (`display.cpu_view = "heatmap"`). The
+/// title names the core under the mouse, or the busiest core otherwise.
+pub fn draw_cpu_heatmap(frame: &mut Frame, area: Rect, app: &App) {
+    let snap = app.sys.snapshot();
+    let usage = &snap.cpu_usage;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::DarkGray));
+    if usage.is_empty() {
+        render_collecting(frame, area, Some(block.title(" Cores ")));
+        return;
+    }
+
+    let inner = block.inner(area);
+    let hovered = app
+        .mouse_pos
+        .and_then(|pos| core_at(inner, usage.len(), pos));
+    let title = match hovered {
+        Some(core) => format!(" Cores ({}) C{core} {:.0}% ", usage.len(), usage[core]),
+        None => {
+            let (hot, pct) = usage
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(i, &p)| (i, p))
+                .unwrap_or((0, 0.0));
+            format!(" Cores ({}) hot: C{hot} {pct:.0}% ", usage.len())
+        }
+    };
+    frame.render_widget(block.title(title), area);
+    frame.render_widget(
+        CoreHeatmap::new(usage, &app.gradient).hovered(hovered),
+        inner,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_layout() {
+        // Few cores: wide cells, capped.
+        assert_eq!(cell_layout(4, 80), (6, 13));
+        assert_eq!(cell_layout(16, 80), (5, 16));
+        // More cores than columns: one column each, wrapping.
+        assert_eq!(cell_layout(128, 60), (1, 60));
+        assert_eq!(cell_layout(0, 60), (0, 0));
+    }
+
+    #[test]
+    fn test_core_at_wraps_rows() {
+        let area = Rect::new(10, 5, 60, 3);
+        assert_eq!(core_at(area, 128, Position::new(10, 5)), Some(0));
+        assert_eq!(core_at(area, 128, Position::new(12, 6)), Some(62));
+        // Past the last core on the third row.
+        assert_eq!(core_at(area, 128, Position::new(30, 7)), None);
+        assert_eq!(core_at(area, 128, Position::new(5, 5)), None);
+    }
+
+    #[test]
+    fn test_heatmap_paints_one_cell_per_core() {
+        let usage = [10.0, 30.0, 60.0, 90.0];
+        let gradient = Gradient::default();
+        let out = crate::ui::testing::render_widget_to_string(4, 1, |frame, area| {
+            frame.render_widget(CoreHeatmap::new(&usage, &gradient), area);
+        });
+        assert_eq!(out.trim_end(), "\u{2591}\u{2592}\u{2593}\u{2588}");
+    }
+}
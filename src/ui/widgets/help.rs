@@ -1,7 +1,8 @@
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
-use crate::app::{App, Tab};
+use super::format_interval;
+use crate::app::{App, Tab, Trend};
 
 pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
     // Filter mode: show filter input prompt.
@@ -24,7 +25,18 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
-    let mut keys = vec![
+    let mut keys = Vec::new();
+    // First launch: point new users at the help overlay for a few seconds.
+    if app.first_run_hint() {
+        keys.push(Span::styled(
+            " Press ? for help ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    keys.extend([
         Span::styled(
             " q",
             Style::default()
@@ -46,10 +58,10 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(" Jump ", Style::default().fg(Color::DarkGray)),
-    ];
+    ]);
 
     // Context-sensitive hints for Dashboard tab (waifu area visible).
-    if app.active_tab == Tab::Dashboard && app.wants_waifu() {
+    if app.active_tab == Tab::Dashboard && app.dashboard_shows_waifu() {
         if app.has_waifu() {
             keys.extend([
                 Span::styled(
@@ -140,6 +152,33 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" Kill ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                "s",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" Signal ", Style::default().fg(Color::DarkGray)),
+        ]);
+    }
+
+    // Context-sensitive hints for Network tab.
+    if app.active_tab == Tab::Network {
+        keys.extend([
+            Span::styled(
+                "F",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" FQDN ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                "I",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" Idle ", Style::default().fg(Color::DarkGray)),
         ]);
     }
 
@@ -176,11 +215,7 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         Color::DarkGray
     };
-    let rate_label = if app.refresh_ms >= 1000 {
-        format!("{:.1}s", app.refresh_ms as f64 / 1000.0)
-    } else {
-        format!("{}ms", app.refresh_ms)
-    };
+    let rate_label = format_interval(app.refresh_ms);
     keys.push(Span::styled(
         format!("  [{rate_label}]"),
         Style::default().fg(rate_color),
@@ -205,13 +240,21 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
     // Show waifu fetch indicator.
     if app.waifu_fetching {
         keys.push(Span::styled(
-            " [FETCHING]",
+            format!(" [{} FETCHING]", app.spinner_char()),
             Style::default()
                 .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ));
     }
 
+    // Show pending quit confirmation.
+    if app.quit_pending() {
+        keys.push(Span::styled(
+            " [q again to quit]",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+
     // Show pending kill indicator.
     if app.pending_kill.is_some() {
         keys.push(Span::styled(
@@ -220,6 +263,27 @@ pub fn draw_help_bar(frame: &mut Frame, area: Rect, app: &App) {
         ));
     }
 
+    // Memory pressure badge (cross-tab), with the recent trend.
+    if let Some((pct, trend)) = app.memory_pressure() {
+        let color = if trend == Trend::Rising {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
+        keys.push(Span::styled(
+            format!(" [MEM {pct:.0}%{}]", trend.arrow()),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    // Transient status message (e.g. signal delivery result).
+    if let Some(msg) = app.status_message() {
+        keys.push(Span::styled(
+            format!(" {msg}"),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
     let line = Line::from(keys);
     let help = Paragraph::new(line);
     frame.render_widget(help, area);
@@ -2,6 +2,8 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
 
 use crate::app::App;
+use crate::config::IpVersion;
+use crate::ui::gradient::gate;
 
 pub fn draw_host_info(frame: &mut Frame, area: Rect, app: &App) {
     let snap = app.sys.snapshot();
@@ -9,7 +11,7 @@ pub fn draw_host_info(frame: &mut Frame, area: Rect, app: &App) {
     let uptime = format_uptime(snap.uptime_secs);
     let cpu_count = snap.cpu_count.max(1) as f64;
     let load_ratio = snap.load_avg[0] / cpu_count;
-    let load_color = if load_ratio >= 1.5 {
+    let load_color = gate(if load_ratio >= 1.5 {
         Color::Red
     } else if load_ratio >= 1.0 {
         Color::Rgb(255, 100, 0)
@@ -17,7 +19,7 @@ pub fn draw_host_info(frame: &mut Frame, area: Rect, app: &App) {
         Color::Yellow
     } else {
         Color::Gray
-    };
+    });
     let load = format!(
         "Load: {:.2} / {:.2} / {:.2}",
         snap.load_avg[0], snap.load_avg[1], snap.load_avg[2]
@@ -73,6 +75,11 @@ pub fn draw_host_info(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default().fg(Color::Yellow),
             ));
         }
+        cpu_spans.push(Span::raw("  "));
+        cpu_spans.push(Span::styled(
+            format!("{:.0}%", app.cpu_display_total()),
+            Style::default().fg(Color::Cyan),
+        ));
         // Show max temperature if available.
         if !snap.temperatures.is_empty() {
             let max_temp = snap
@@ -116,9 +123,13 @@ pub fn draw_host_info(frame: &mut Frame, area: Rect, app: &App) {
     ]));
 
     // IP + process count + memory pressure.
+    let ip = match app.ip_version {
+        IpVersion::V6 if !snap.local_ip6.is_empty() => &snap.local_ip6,
+        _ => &snap.local_ip,
+    };
     let mut ip_spans = vec![
         Span::raw("IP: "),
-        Span::styled(&snap.local_ip, Style::default().fg(Color::Cyan)),
+        Span::styled(ip, Style::default().fg(Color::Cyan)),
         Span::raw("  Procs: "),
         Span::styled(
             format!("{}", snap.process_count),
@@ -1,7 +1,9 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Row, Table};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Row, Table, TableState};
 
+use super::{daemon_error, error_style, no_data_text, render_scrolling_table, with_skew_warning};
 use crate::app::App;
+use crate::ui::gradient::gate;
 
 pub fn draw_k8s(frame: &mut Frame, area: Rect, app: &App) {
     match &app.k8s {
@@ -28,6 +30,7 @@ pub fn draw_k8s(frame: &mut Frame, area: Rect, app: &App) {
                     k8s.clusters.len()
                 ))
                 .border_style(Style::default().fg(title_color));
+            let block = with_skew_warning(block, k8s.timestamp, app.clock_skew_secs);
 
             let header = Row::new(vec!["Cluster", "Nodes", "Pods", "Status"]).style(
                 Style::default()
@@ -35,39 +38,41 @@ pub fn draw_k8s(frame: &mut Frame, area: Rect, app: &App) {
                     .add_modifier(Modifier::BOLD),
             );
 
-            let rows: Vec<Row> = k8s
-                .clusters
-                .iter()
-                .enumerate()
-                .map(|(i, c)| {
-                    let status_color = if !c.connected {
-                        Color::Red
-                    } else if c.failed_pods > 0 {
-                        Color::Yellow
-                    } else {
-                        Color::Green
-                    };
-                    let status = if !c.connected {
-                        "disconnected".to_string()
-                    } else if c.failed_pods > 0 {
-                        format!("{} failed", c.failed_pods)
-                    } else {
-                        "healthy".to_string()
-                    };
-                    let bg = if i % 2 == 1 {
-                        Color::Rgb(30, 30, 40)
-                    } else {
-                        Color::Reset
-                    };
+            let mut rows: Vec<Row> = Vec::new();
+            for (i, c) in k8s.clusters.iter().enumerate() {
+                let status_color = if !c.connected {
+                    Color::Red
+                } else if c.failed_pods > 0 {
+                    Color::Yellow
+                } else {
+                    Color::Green
+                };
+                let status = if !c.connected {
+                    "disconnected".to_string()
+                } else if c.failed_pods > 0 {
+                    format!("{} failed", c.failed_pods)
+                } else {
+                    "healthy".to_string()
+                };
+                let bg = if i % 2 == 1 {
+                    gate(Color::Rgb(30, 30, 40))
+                } else {
+                    Color::Reset
+                };
+                rows.push(
                     Row::new(vec![
                         c.context.clone(),
                         format!("{}", c.nodes.len()),
                         format!("{}/{}", c.running_pods, c.total_pods),
                         status,
                     ])
-                    .style(Style::default().fg(status_color).bg(bg))
-                })
-                .collect();
+                    .style(Style::default().fg(status_color).bg(bg)),
+                );
+                // Why the daemon couldn't reach it, under the cluster row.
+                if let Some(err) = daemon_error(c.connected, &c.error) {
+                    rows.push(Row::new(vec![format!("  \u{21b3} {err}")]).style(error_style()));
+                }
+            }
 
             let widths = [
                 Constraint::Min(20),
@@ -76,8 +81,17 @@ pub fn draw_k8s(frame: &mut Frame, area: Rect, app: &App) {
                 Constraint::Length(14),
             ];
 
-            let table = Table::new(rows, widths).header(header).block(block);
-            frame.render_widget(table, area);
+            let row_count = rows.len();
+            let table = Table::new(rows, widths);
+            render_scrolling_table(
+                frame,
+                area,
+                Some(block),
+                table,
+                header,
+                row_count,
+                &mut TableState::default(),
+            );
         }
         _ => {
             let block = Block::default()
@@ -85,7 +99,7 @@ pub fn draw_k8s(frame: &mut Frame, area: Rect, app: &App) {
                 .border_type(BorderType::Rounded)
                 .title(" Kubernetes ")
                 .border_style(Style::default().fg(Color::Blue));
-            let paragraph = Paragraph::new("No cluster data")
+            let paragraph = Paragraph::new(no_data_text("No cluster data", app.daemon_silence()))
                 .style(Style::default().fg(Color::DarkGray))
                 .block(block);
             frame.render_widget(paragraph, area);
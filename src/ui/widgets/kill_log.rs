@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+/// Centered popup listing processes killed this session (newest first).
+pub fn draw_kill_log_popup(frame: &mut Frame, area: Rect, app: &App) {
+    if !app.show_kill_log {
+        return;
+    }
+
+    let popup_width = 56u16.min(area.width.saturating_sub(4));
+    let popup_height = (app.kill_log.len().max(1) as u16 + 4).min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = if app.kill_log.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No processes killed this session.",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        app.kill_log
+            .iter()
+            .rev()
+            .map(|(at, pid, name, force)| {
+                let (sig, sig_color) = if *force {
+                    ("KILL", Color::Red)
+                } else {
+                    ("TERM", Color::Yellow)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {:>8} ", format_ago(at.elapsed())),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{sig:<5}"), Style::default().fg(sig_color)),
+                    Span::styled(format!("{pid:>7} "), Style::default().fg(Color::Cyan)),
+                    Span::styled(name.clone(), Style::default().fg(Color::White)),
+                ])
+            })
+            .collect()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Any key to close.",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Kill log ({}) ", app.kill_log.len()))
+        .border_style(Style::default().fg(Color::Red));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+/// Coarse relative time: "42s ago", "3m ago", "2h ago".
+fn format_ago(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{secs}s ago")
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else {
+        format!("{}h ago", secs / 3600)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ago() {
+        assert_eq!(format_ago(Duration::from_secs(5)), "5s ago");
+        assert_eq!(format_ago(Duration::from_secs(190)), "3m ago");
+        assert_eq!(format_ago(Duration::from_secs(7300)), "2h ago");
+    }
+}
@@ -0,0 +1,118 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
+
+use super::format_rate;
+use crate::app::App;
+use crate::ui::gradient::{Gradient, LEVEL_COLORS, NET_RATE_COLORS, NET_RATE_STOPS};
+
+/// Centered popup explaining the color gradients, built from the live
+/// gradients (so `[theme]` overrides show) and the network rate stops.
+pub fn draw_legend_popup(frame: &mut Frame, area: Rect, app: &App) {
+    if !app.show_legend {
+        return;
+    }
+
+    let lines = vec![
+        section_header("Usage: CPU, memory, disk, load, pressure"),
+        gradient_line(&app.gradient, "%"),
+        Line::from(""),
+        section_header("Temperature"),
+        gradient_line(&app.temp_gradient, "\u{b0}C"),
+        Line::from(""),
+        section_header("Network rate"),
+        net_rate_line(app.net_idle_threshold),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Any key to close.",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let popup_width = 64u16.min(area.width.saturating_sub(4));
+    let popup_height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Color legend (l) ")
+        .border_style(Style::default().fg(Color::Cyan));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn section_header(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("  {title}"),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// One swatch per gradient level: "■ <40%  ■ 40%  ■ 65%  ■ 80%  ■ 90%+".
+fn gradient_line(gradient: &Gradient, unit: &str) -> Line<'static> {
+    let stops = gradient.stops;
+    let mut labels = vec![format!("<{:.0}{unit}", stops[0])];
+    labels.extend(stops[..3].iter().map(|s| format!("{s:.0}{unit}")));
+    labels.push(format!("{:.0}{unit}+", stops[3]));
+    swatches(LEVEL_COLORS.iter().copied().zip(labels))
+}
+
+/// Network swatches: idle, then one per rate stop.
+fn net_rate_line(idle_below: u64) -> Line<'static> {
+    let idle = if idle_below > NET_RATE_STOPS[0] {
+        format!("<{}", format_rate(idle_below, 0))
+    } else {
+        format!("<{}", format_rate(NET_RATE_STOPS[0], 0))
+    };
+    let levels = NET_RATE_COLORS
+        .iter()
+        .copied()
+        .zip(NET_RATE_STOPS.iter().map(|s| format_rate(*s, 0)));
+    swatches(std::iter::once((Color::DarkGray, idle)).chain(levels))
+}
+
+fn swatches(items: impl Iterator<Item = (Color, String)>) -> Line<'static> {
+    let mut spans = vec![Span::raw("  ")];
+    for (color, label) in items {
+        spans.push(Span::styled("\u{25a0} ", Style::default().fg(color)));
+        spans.push(Span::raw(format!("{label}  ")));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::gradient::TEMP_STOPS;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_gradient_line_uses_stops() {
+        let text = line_text(&gradient_line(&Gradient::default(), "%"));
+        assert_eq!(text.trim(), "■ <40%  ■ 40%  ■ 65%  ■ 80%  ■ 90%+");
+
+        let custom = Gradient::new([50.0, 60.0, 70.0, 85.0]);
+        assert!(line_text(&gradient_line(&custom, "%")).contains("■ 85%+"));
+
+        let temp = line_text(&gradient_line(&Gradient::new(TEMP_STOPS), "\u{b0}C"));
+        assert!(temp.contains("<45°C"), "{temp}");
+    }
+
+    #[test]
+    fn test_net_rate_line() {
+        let text = line_text(&net_rate_line(0));
+        assert!(text.contains("<1 KB/s"), "{text}");
+        assert!(text.contains("10.0 MB/s"), "{text}");
+        // A configured idle threshold above the first stop is what "idle" means.
+        assert!(line_text(&net_rate_line(8192)).contains("<8 KB/s"));
+    }
+}
@@ -2,19 +2,15 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Borders, Gauge};
 
 use crate::app::App;
+use crate::config::MemWarnMode;
+use crate::ui::gradient::gate;
 
 pub fn draw_memory(frame: &mut Frame, area: Rect, app: &App) {
     let snap = app.sys.snapshot();
 
     // Memory pressure warning: change border + title when under pressure.
-    let avail_gib = snap.mem_available as f64 / (1024.0 * 1024.0 * 1024.0);
-    let (border_color, title) = if snap.mem_percent >= 90.0 {
-        (Color::Red, format!(" Memory [!{:.0}%] ", snap.mem_percent))
-    } else if snap.mem_percent >= 80.0 || avail_gib < 2.0 {
-        (Color::Yellow, format!(" Memory [{:.1}G free] ", avail_gib))
-    } else {
-        (Color::Blue, " Memory ".to_string())
-    };
+    let (border_color, title) =
+        memory_warning(app.mem_warn_mode, snap.mem_percent, snap.mem_available);
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -40,17 +36,7 @@ pub fn draw_memory(frame: &mut Frame, area: Rect, app: &App) {
         snap.mem_percent,
         format_bytes(snap.mem_available),
     );
-    let ram_color = if snap.mem_percent >= 90.0 {
-        Color::Red
-    } else if snap.mem_percent >= 80.0 {
-        Color::Rgb(255, 100, 0)
-    } else if snap.mem_percent >= 65.0 {
-        Color::Yellow
-    } else if snap.mem_percent >= 40.0 {
-        Color::Rgb(150, 255, 0)
-    } else {
-        Color::Green
-    };
+    let ram_color = app.gradient.color(snap.mem_percent);
 
     let ram = Gauge::default()
         .gauge_style(Style::default().fg(ram_color))
@@ -68,7 +54,7 @@ pub fn draw_memory(frame: &mut Frame, area: Rect, app: &App) {
             format_bytes(snap.swap_total),
             swap_pct,
         );
-        let swap_color = if swap_pct >= 90.0 {
+        let swap_color = gate(if swap_pct >= 90.0 {
             Color::Red
         } else if swap_pct >= 70.0 {
             Color::Rgb(255, 100, 0)
@@ -76,7 +62,7 @@ pub fn draw_memory(frame: &mut Frame, area: Rect, app: &App) {
             Color::Yellow
         } else {
             Color::Magenta
-        };
+        });
         let swap = Gauge::default()
             .gauge_style(Style::default().fg(swap_color))
             .ratio(swap_ratio)
@@ -85,6 +71,28 @@ pub fn draw_memory(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Border color and title for the memory block. Percent mode warns on
+/// utilization; absolute mode only on free memory, so large machines that
+/// run near full by design stay quiet.
+fn memory_warning(mode: MemWarnMode, mem_percent: f64, available: u64) -> (Color, String) {
+    let avail_gib = available as f64 / (1024.0 * 1024.0 * 1024.0);
+    match mode {
+        MemWarnMode::Percent if mem_percent >= 90.0 => {
+            (Color::Red, format!(" Memory [!{mem_percent:.0}%] "))
+        }
+        MemWarnMode::Percent if mem_percent >= 80.0 || avail_gib < 2.0 => {
+            (Color::Yellow, format!(" Memory [{avail_gib:.1}G free] "))
+        }
+        MemWarnMode::Absolute { free_gib } if avail_gib < free_gib / 4.0 => {
+            (Color::Red, format!(" Memory [!{avail_gib:.1}G free] "))
+        }
+        MemWarnMode::Absolute { free_gib } if avail_gib < free_gib => {
+            (Color::Yellow, format!(" Memory [{avail_gib:.1}G free] "))
+        }
+        _ => (Color::Blue, " Memory ".to_string()),
+    }
+}
+
 fn format_bytes(bytes: u64) -> String {
     const GIB: u64 = 1024 * 1024 * 1024;
     const MIB: u64 = 1024 * 1024;
@@ -94,3 +102,37 @@ fn format_bytes(bytes: u64) -> String {
         format!("{:.0} MiB", bytes as f64 / MIB as f64)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn test_memory_warning_percent() {
+        let (color, title) = memory_warning(MemWarnMode::Percent, 92.0, 20 * GIB);
+        assert_eq!((color, title.as_str()), (Color::Red, " Memory [!92%] "));
+        assert_eq!(
+            memory_warning(MemWarnMode::Percent, 50.0, GIB).0,
+            Color::Yellow
+        );
+        assert_eq!(
+            memory_warning(MemWarnMode::Percent, 50.0, 8 * GIB).0,
+            Color::Blue
+        );
+    }
+
+    #[test]
+    fn test_memory_warning_absolute_ignores_percent() {
+        let mode = MemWarnMode::Absolute { free_gib: 16.0 };
+        // 256 GB box at 80%: ~50G free is fine.
+        assert_eq!(memory_warning(mode, 80.0, 50 * GIB).0, Color::Blue);
+        let (color, title) = memory_warning(mode, 95.0, 10 * GIB);
+        assert_eq!(
+            (color, title.as_str()),
+            (Color::Yellow, " Memory [10.0G free] ")
+        );
+        assert_eq!(memory_warning(mode, 99.0, 3 * GIB).0, Color::Red);
+    }
+}
@@ -1,17 +1,298 @@
 pub mod billing_widget;
 pub mod buildinfo;
+pub mod cache_debug;
 pub mod claude;
 pub mod claudepersonal;
 pub mod cpu;
 pub mod disk;
+pub mod heatmap;
 pub mod help;
 pub mod host;
 pub mod k8s;
+pub mod kill_log;
+pub mod legend;
 pub mod memory;
 pub mod network;
+pub mod pressure;
+pub mod process_menu;
 pub mod processes;
+pub mod signal_menu;
 pub mod sparkline;
 pub mod tabs;
 pub mod tailscale;
 pub mod temperature;
+pub mod top_strip;
 pub mod waifu;
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{
+    Block, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState,
+};
+use ratatui::Frame;
+
+use crate::config::SelectionStyle;
+
+/// Selected-row style: the widget's own `highlight`, or plain bold reverse
+/// video when `display.selection_style = "reverse"`.
+pub fn selection(style: SelectionStyle, highlight: Style) -> Style {
+    match style {
+        SelectionStyle::Highlight => highlight,
+        SelectionStyle::Reverse => {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        }
+    }
+}
+
+/// Render `table` under a pinned one-line `header`, inside `block` when
+/// given, with a scrollbar beside the body rows once `row_count` overflows
+/// them: on the block's right border, or in a column taken from the table
+/// when there is no block. Scrollable tables go through here so the header
+/// stays put whatever the offset in `state`, and the scrollbar track starts
+/// below it. Tables without scroll keys pass a fresh state; their bar only
+/// marks that rows are cut off.
+pub fn render_scrolling_table<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    block: Option<Block<'a>>,
+    table: Table<'a>,
+    header: Row<'a>,
+    row_count: usize,
+    state: &mut TableState,
+) {
+    let bordered = block.is_some();
+    let mut inner = match block {
+        Some(block) => {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        }
+        None => area,
+    };
+    let overflows = row_count > inner.height.saturating_sub(1) as usize;
+    // Without a border to sit on, the scrollbar gets its own column.
+    if overflows && !bordered {
+        inner.width = inner.width.saturating_sub(1);
+    }
+    frame.render_stateful_widget(table.header(header), inner, state);
+
+    // Body rows only; on the right border when there is one.
+    let body = Rect {
+        y: inner.y.saturating_add(1),
+        height: inner.height.saturating_sub(1),
+        ..area
+    };
+    if overflows {
+        let position = state.selected().unwrap_or_else(|| state.offset());
+        let mut scrollbar_state = ScrollbarState::new(row_count).position(position);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None);
+        frame.render_stateful_widget(scrollbar, body, &mut scrollbar_state);
+    }
+}
+
+/// Refresh interval label: "250ms", "1.5s", or whole seconds from 10 s ("30s").
+pub fn format_interval(ms: u64) -> String {
+    if ms < 1000 {
+        format!("{ms}ms")
+    } else if ms < 10_000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format!("{}s", (ms + 500) / 1000)
+    }
+}
+
+/// Human-readable network rate; anything below `idle_below` bytes/s (and
+/// silence) reads "idle".
+pub fn format_rate(bytes_per_sec: u64, idle_below: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    const KIB: u64 = 1024;
+    if bytes_per_sec == 0 || bytes_per_sec < idle_below {
+        "idle".into()
+    } else if bytes_per_sec >= MIB {
+        format!("{:.1} MB/s", bytes_per_sec as f64 / MIB as f64)
+    } else if bytes_per_sec >= KIB {
+        format!("{:.0} KB/s", bytes_per_sec as f64 / KIB as f64)
+    } else {
+        format!("{} B/s", bytes_per_sec)
+    }
+}
+
+/// Longest daemon error string shown inline before it is cut with "...".
+const ERROR_MAX_CHARS: usize = 60;
+
+/// Daemon-side `error` of a disconnected provider/account/cluster, trimmed
+/// to one short line (None when there is nothing to show).
+pub fn daemon_error(connected: bool, error: &str) -> Option<String> {
+    let error = error.lines().next().unwrap_or("").trim();
+    if connected || error.is_empty() {
+        return None;
+    }
+    if error.chars().count() > ERROR_MAX_CHARS {
+        let cut: String = error.chars().take(ERROR_MAX_CHARS - 1).collect();
+        Some(format!("{cut}\u{2026}"))
+    } else {
+        Some(error.to_string())
+    }
+}
+
+/// Seconds a daemon report's `timestamp` is ahead of this host's clock,
+/// when that exceeds `tolerance` (0 turns the check off). A report "from
+/// the future" means the daemon host and this one disagree on the time.
+pub fn clock_skew(ts: Option<DateTime<Utc>>, now: DateTime<Utc>, tolerance: u64) -> Option<i64> {
+    let ahead = ts?.signed_duration_since(now).num_seconds();
+    (tolerance > 0 && ahead > tolerance as i64).then_some(ahead)
+}
+
+/// Add a right-aligned "clock skew?" title to a daemon widget's block when
+/// its report is timestamped in the future.
+pub fn with_skew_warning(block: Block<'_>, ts: Option<DateTime<Utc>>, tolerance: u64) -> Block<'_> {
+    match clock_skew(ts, Utc::now(), tolerance) {
+        Some(ahead) => block.title(
+            Line::from(Span::styled(
+                format!(" clock skew? +{ahead}s "),
+                Style::default().fg(Color::Yellow),
+            ))
+            .right_aligned(),
+        ),
+        None => block,
+    }
+}
+
+/// Placeholder for a daemon widget with no data: `msg`, plus "daemon silent
+/// for 2m, retrying in 3s" while every cache read comes back empty
+/// (`silence` is `App::daemon_silence`).
+pub fn no_data_text(msg: &str, silence: Option<(Duration, Duration)>) -> Text<'static> {
+    let mut text = Text::from(msg.to_string());
+    if let Some((silent_for, retry_in)) = silence {
+        text.push_line(Span::styled(
+            format!(
+                "daemon silent for {}, retrying in {}",
+                format_silence(silent_for),
+                format_silence(retry_in)
+            ),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    text
+}
+
+/// Coarse duration for the daemon-silence line: "3s", "2m", "1h".
+fn format_silence(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
+
+/// Dim red, for inline daemon errors.
+pub fn error_style() -> Style {
+    Style::default().fg(Color::Red).add_modifier(Modifier::DIM)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_interval() {
+        assert_eq!(format_interval(250), "250ms");
+        assert_eq!(format_interval(1500), "1.5s");
+        assert_eq!(format_interval(30_000), "30s");
+    }
+
+    #[test]
+    fn test_format_rate_idle_threshold() {
+        assert_eq!(format_rate(0, 0), "idle");
+        assert_eq!(format_rate(500, 0), "500 B/s");
+        assert_eq!(format_rate(500, 1024), "idle");
+        assert_eq!(format_rate(2048, 1024), "2 KB/s");
+    }
+
+    #[test]
+    fn test_borderless_scrollbar_gets_its_own_column() {
+        let draw = |rows: usize| {
+            crate::ui::testing::render_widget_to_string(6, 3, |frame, area| {
+                let table = Table::new(
+                    (0..rows).map(|_| Row::new(["xxxxxx"])),
+                    [ratatui::layout::Constraint::Fill(1)],
+                );
+                let header = Row::new(["HHHHHH"]);
+                render_scrolling_table(
+                    frame,
+                    area,
+                    None,
+                    table,
+                    header,
+                    rows,
+                    &mut TableState::default(),
+                );
+            })
+        };
+        // Fits: the table keeps the full width.
+        assert_eq!(draw(2).lines().nth(1), Some("xxxxxx"));
+        // Overflows: the last column is the scrollbar's, not the table's.
+        let out = draw(5);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0].trim_end(), "HHHHH");
+        assert!(lines[1].starts_with("xxxxx") && !lines[1].ends_with('x'));
+    }
+
+    #[test]
+    fn test_selection_reverse_drops_colors() {
+        let hl = Style::default().bg(Color::Yellow);
+        assert_eq!(selection(SelectionStyle::Highlight, hl), hl);
+        let rev = selection(SelectionStyle::Reverse, hl);
+        assert_eq!(rev.bg, None);
+        assert!(rev.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_no_data_text_daemon_silence() {
+        assert_eq!(no_data_text("No billing data", None).lines.len(), 1);
+        let text = no_data_text(
+            "No billing data",
+            Some((Duration::from_secs(130), Duration::from_secs(3))),
+        );
+        let second: String = text.lines[1]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(second, "daemon silent for 2m, retrying in 3s");
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let now = Utc::now();
+        let ahead = |secs| Some(now + chrono::Duration::seconds(secs));
+        assert_eq!(clock_skew(ahead(3), now, 5), None);
+        assert_eq!(clock_skew(ahead(90), now, 5), Some(90));
+        assert_eq!(clock_skew(ahead(-600), now, 5), None);
+        assert_eq!(clock_skew(ahead(90), now, 0), None, "0 disables");
+        assert_eq!(clock_skew(None, now, 5), None);
+    }
+
+    #[test]
+    fn test_daemon_error_only_when_disconnected() {
+        assert_eq!(daemon_error(true, "boom"), None);
+        assert_eq!(daemon_error(false, "  "), None);
+        assert_eq!(
+            daemon_error(false, "401 Unauthorized\nbody"),
+            Some("401 Unauthorized".into())
+        );
+        let long = "x".repeat(100);
+        let cut = daemon_error(false, &long).unwrap();
+        assert_eq!(cut.chars().count(), ERROR_MAX_CHARS);
+        assert!(cut.ends_with('\u{2026}'));
+    }
+}
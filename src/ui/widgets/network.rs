@@ -1,48 +1,48 @@
+use std::collections::HashMap;
+
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Cell, Row, Table};
+use ratatui::widgets::{Block, BorderType, Borders, Cell, Row, Table, TableState};
 
+use super::{format_rate, render_scrolling_table};
 use crate::app::App;
+use crate::data::sysmetrics::NetInfo;
+use crate::ui::gradient::{gate, net_rate_color};
 
 pub fn draw_network(frame: &mut Frame, area: Rect, app: &App) {
     let snap = app.sys.snapshot();
+    let idle = app.net_idle_threshold;
+    // MAC addresses only fit when the panel is wide.
+    let show_mac = area.width >= 100;
 
-    let header = Row::new(vec![
-        Cell::from("Interface").style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from("RX/s").style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from("TX/s").style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from("Total RX").style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Cell::from("Total TX").style(
+    let mut labels = vec!["Interface", "RX/s", "TX/s", "Total RX", "Total TX", "State"];
+    if show_mac {
+        labels.push("MAC");
+    }
+    let header = Row::new(labels.into_iter().map(|label| {
+        Cell::from(label).style(
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
-        ),
-    ]);
+        )
+    }));
+
+    // Optionally fold idle virtual interfaces into a single summary row.
+    let idle_virtual = if app.hide_idle_interfaces {
+        snap.networks.iter().filter(|n| n.is_idle_virtual()).count()
+    } else {
+        0
+    };
 
     let mut rows: Vec<Row> = snap
         .networks
         .iter()
+        .filter(|n| idle_virtual == 0 || !n.is_idle_virtual())
         .enumerate()
         .map(|(i, n)| {
-            let rx_color = rate_color(n.rx_rate);
-            let tx_color = rate_color(n.tx_rate);
+            let rx_color = net_rate_color(n.rx_rate, idle);
+            let tx_color = net_rate_color(n.tx_rate, idle);
             let bg = if i % 2 == 1 {
-                Color::Rgb(30, 30, 40)
+                gate(Color::Rgb(30, 30, 40))
             } else {
                 Color::Reset
             };
@@ -67,20 +67,47 @@ pub fn draw_network(frame: &mut Frame, area: Rect, app: &App) {
             } else {
                 ""
             };
-            Row::new(vec![
+            let (state, state_color) = match n.up {
+                Some(true) => ("up", Color::Green),
+                Some(false) => ("down", Color::Red),
+                None => ("-", Color::DarkGray),
+            };
+            let mut cells = vec![
                 Cell::from(format!("{} {}", n.kind.icon(), n.name))
                     .style(Style::default().fg(kind_color)),
-                Cell::from(format!("{}{rx_arrow}", format_rate(n.rx_rate)))
+                Cell::from(format!("{}{rx_arrow}", format_rate(n.rx_rate, idle)))
                     .style(Style::default().fg(rx_color)),
-                Cell::from(format!("{}{tx_arrow}", format_rate(n.tx_rate)))
+                Cell::from(format!("{}{tx_arrow}", format_rate(n.tx_rate, idle)))
                     .style(Style::default().fg(tx_color)),
                 Cell::from(format_bytes(n.rx_bytes)).style(Style::default().fg(Color::DarkGray)),
                 Cell::from(format_bytes(n.tx_bytes)).style(Style::default().fg(Color::DarkGray)),
-            ])
-            .style(Style::default().bg(bg))
+                Cell::from(state).style(Style::default().fg(state_color)),
+            ];
+            if show_mac {
+                cells.push(
+                    Cell::from(n.mac.clone().unwrap_or_default())
+                        .style(Style::default().fg(Color::DarkGray)),
+                );
+            }
+            // Down interfaces are dimmed.
+            let mut row_style = Style::default().bg(bg);
+            if n.up == Some(false) {
+                row_style = row_style.add_modifier(Modifier::DIM);
+            }
+            Row::new(cells).style(row_style)
         })
         .collect();
 
+    if idle_virtual > 0 {
+        rows.push(
+            Row::new(vec![Cell::from(format!("  {idle_virtual} idle virtual"))]).style(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        );
+    }
+
     // Totals row.
     if snap.networks.len() > 1 {
         let total_rx_rate: u64 = snap.networks.iter().map(|n| n.rx_rate).sum();
@@ -90,30 +117,34 @@ pub fn draw_network(frame: &mut Frame, area: Rect, app: &App) {
         rows.push(
             Row::new(vec![
                 Cell::from("TOTAL").style(Style::default().add_modifier(Modifier::BOLD)),
-                Cell::from(format_rate(total_rx_rate)).style(
+                Cell::from(format_rate(total_rx_rate, idle)).style(
                     Style::default()
-                        .fg(rate_color(total_rx_rate))
+                        .fg(net_rate_color(total_rx_rate, idle))
                         .add_modifier(Modifier::BOLD),
                 ),
-                Cell::from(format_rate(total_tx_rate)).style(
+                Cell::from(format_rate(total_tx_rate, idle)).style(
                     Style::default()
-                        .fg(rate_color(total_tx_rate))
+                        .fg(net_rate_color(total_tx_rate, idle))
                         .add_modifier(Modifier::BOLD),
                 ),
                 Cell::from(format_bytes(total_rx)).style(Style::default().fg(Color::Gray)),
                 Cell::from(format_bytes(total_tx)).style(Style::default().fg(Color::Gray)),
             ])
-            .style(Style::default().bg(Color::Rgb(40, 40, 50))),
+            .style(Style::default().bg(gate(Color::Rgb(40, 40, 50)))),
         );
     }
 
-    let widths = [
+    let mut widths = vec![
         Constraint::Min(12),
         Constraint::Length(10),
         Constraint::Length(10),
         Constraint::Length(10),
         Constraint::Length(10),
+        Constraint::Length(5),
     ];
+    if show_mac {
+        widths.push(Constraint::Length(17));
+    }
 
     // Show aggregate bandwidth in title.
     let total_rx_rate: u64 = snap.networks.iter().map(|n| n.rx_rate).sum();
@@ -122,56 +153,60 @@ pub fn draw_network(frame: &mut Frame, area: Rect, app: &App) {
     let net_title = if total_rx_rate > 0 || total_tx_rate > 0 {
         format!(
             " Network [rx:{} tx:{}] ",
-            format_rate(total_rx_rate),
-            format_rate(total_tx_rate)
+            format_rate(total_rx_rate, idle),
+            format_rate(total_tx_rate, idle)
         )
     } else {
         format!(" Network ({}) ", snap.networks.len())
     };
-    let border_color = if max_rate >= 10 * 1024 * 1024 {
-        Color::Magenta
-    } else if max_rate >= 1024 * 1024 {
-        Color::Yellow
+    let (session_rx, session_tx) = session_totals(&snap.networks, &app.session_net_baseline);
+    let net_title = if session_rx > 0 || session_tx > 0 {
+        format!(
+            "{net_title}session: \u{2193}{} \u{2191}{} ",
+            format_bytes(session_rx),
+            format_bytes(session_tx)
+        )
     } else {
-        Color::Blue
+        net_title
+    };
+    // Border only lights up for the top two network rate levels.
+    let border_color = match net_rate_color(max_rate, 0) {
+        c @ (Color::Magenta | Color::Yellow) => c,
+        _ => Color::Blue,
     };
 
-    let table = Table::new(rows, widths).header(header).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .title(net_title)
-            .border_style(Style::default().fg(border_color)),
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(net_title)
+        .border_style(Style::default().fg(border_color));
+    let row_count = rows.len();
+    let table = Table::new(rows, widths);
+    render_scrolling_table(
+        frame,
+        area,
+        Some(block),
+        table,
+        header,
+        row_count,
+        &mut TableState::default(),
     );
-
-    frame.render_widget(table, area);
-}
-
-fn rate_color(bytes_per_sec: u64) -> Color {
-    const MIB: u64 = 1024 * 1024;
-    if bytes_per_sec >= 10 * MIB {
-        Color::Red
-    } else if bytes_per_sec >= MIB {
-        Color::Yellow
-    } else if bytes_per_sec > 0 {
-        Color::Green
-    } else {
-        Color::DarkGray
-    }
 }
 
-fn format_rate(bytes_per_sec: u64) -> String {
-    const MIB: u64 = 1024 * 1024;
-    const KIB: u64 = 1024;
-    if bytes_per_sec >= MIB {
-        format!("{:.1} MB/s", bytes_per_sec as f64 / MIB as f64)
-    } else if bytes_per_sec >= KIB {
-        format!("{:.0} KB/s", bytes_per_sec as f64 / KIB as f64)
-    } else if bytes_per_sec > 0 {
-        format!("{} B/s", bytes_per_sec)
-    } else {
-        "idle".into()
-    }
+/// Bytes moved since the TUI started: current counters minus the startup
+/// baseline, summed over interfaces. A counter that went backwards (driver
+/// reload, interface recreated) counts as zero until the app re-bases it.
+fn session_totals(networks: &[NetInfo], baseline: &HashMap<String, (u64, u64)>) -> (u64, u64) {
+    networks.iter().fold((0, 0), |(rx, tx), n| {
+        let (base_rx, base_tx) = baseline
+            .get(&n.name)
+            .copied()
+            .unwrap_or((n.rx_bytes, n.tx_bytes));
+        (
+            rx + n.rx_bytes.saturating_sub(base_rx),
+            tx + n.tx_bytes.saturating_sub(base_tx),
+        )
+    })
 }
 
 fn format_bytes(bytes: u64) -> String {
@@ -188,3 +223,25 @@ fn format_bytes(bytes: u64) -> String {
         format!("{bytes} B")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::testing::iface;
+
+    #[test]
+    fn test_session_totals_subtract_baseline() {
+        let baseline = HashMap::from([
+            ("eth0".to_string(), (1000, 500)),
+            ("wg0".to_string(), (9000, 9000)),
+        ]);
+        let networks = [
+            iface("eth0", 3000, 800),
+            // Counter reset since launch: no negative traffic.
+            iface("wg0", 10, 20),
+            // Not in the baseline yet: nothing counted until the next tick.
+            iface("docker0", 5000, 5000),
+        ];
+        assert_eq!(session_totals(&networks, &baseline), (2000, 300));
+    }
+}
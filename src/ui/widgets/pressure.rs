@@ -0,0 +1,27 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Gauge};
+
+use crate::app::App;
+
+/// Composite system pressure (CPU, memory, swap, load, temperature) as one
+/// 0-100 gauge, colored with the usage gradient.
+pub fn draw_pressure_gauge(frame: &mut Frame, area: Rect, app: &App) {
+    let score = app.pressure_score;
+    let color = app.gradient.color(score);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Pressure ")
+        .border_style(Style::default().fg(Color::Blue));
+
+    let gauge = Gauge::default()
+        .block(block)
+        .gauge_style(Style::default().fg(color).bg(Color::DarkGray))
+        .ratio((score / 100.0).clamp(0.0, 1.0))
+        .label(Span::styled(
+            format!("{score:.0}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+    frame.render_widget(gauge, area);
+}
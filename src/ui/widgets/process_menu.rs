@@ -0,0 +1,79 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState};
+
+use super::selection;
+use crate::app::{App, PROCESS_MENU};
+
+/// Right-click action menu for the selected process, opened at the clicked
+/// cell and nudged back inside `area` near the edges. Publishes its rect
+/// for mouse hit-testing.
+pub fn draw_process_menu(frame: &mut Frame, area: Rect, app: &mut App) {
+    let Some(menu) = app.process_menu else {
+        return;
+    };
+
+    let popup_area = menu_area(menu.anchor, area);
+    app.process_menu_rect = Some(popup_area);
+    frame.render_widget(Clear, popup_area);
+
+    let title = match app.processes.get(app.process_scroll) {
+        Some(p) if p.group_count > 1 => format!(" {} ({} processes) ", p.name, p.group_count),
+        Some(p) => format!(" {} ({}) ", p.name, p.pid),
+        None => " Process ".to_string(),
+    };
+
+    let items: Vec<ListItem> = PROCESS_MENU
+        .iter()
+        .map(|(_, label)| ListItem::new(format!(" {label}")))
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(selection(
+            app.selection_style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let mut state = ListState::default().with_selected(Some(menu.selected));
+    frame.render_stateful_widget(list, popup_area, &mut state);
+}
+
+/// Menu rect with its top-left at `anchor`, shifted left/up to stay in `area`.
+fn menu_area(anchor: Position, area: Rect) -> Rect {
+    let width = 24u16.min(area.width);
+    let height = (PROCESS_MENU.len() as u16 + 2).min(area.height);
+    let x = anchor.x.min(area.right().saturating_sub(width)).max(area.x);
+    let y = anchor
+        .y
+        .min(area.bottom().saturating_sub(height))
+        .max(area.y);
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_area_stays_on_screen() {
+        let area = Rect::new(0, 0, 80, 24);
+        assert_eq!(
+            menu_area(Position::new(10, 5), area),
+            Rect::new(10, 5, 24, 6)
+        );
+        // Near the bottom-right corner it opens up and to the left.
+        assert_eq!(
+            menu_area(Position::new(75, 22), area),
+            Rect::new(56, 18, 24, 6)
+        );
+    }
+}
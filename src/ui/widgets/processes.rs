@@ -1,10 +1,13 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{
-    Block, BorderType, Borders, Cell, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
-    TableState,
+    Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, TableState,
 };
 
-use crate::app::{App, ProcessSort};
+use super::sparkline::render_series;
+use super::{render_scrolling_table, selection};
+use crate::app::{App, ProcessChange, ProcessColumn, ProcessSort};
+use crate::config::CpuNormalize;
+use crate::ui::gradient::gate;
 
 pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
     let sort_indicator = |col: ProcessSort| -> &str {
@@ -29,22 +32,80 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         };
         Style::default().fg(fg).add_modifier(Modifier::BOLD)
     };
-    let header = Row::new(vec![
-        Cell::from("S").style(header_style(None)),
-        Cell::from(format!("PID{}", sort_indicator(ProcessSort::Pid)))
-            .style(header_style(Some(ProcessSort::Pid))),
-        Cell::from("User").style(header_style(None)),
-        Cell::from(format!(
-            "{name_header}{}",
-            sort_indicator(ProcessSort::Name)
-        ))
-        .style(header_style(Some(ProcessSort::Name))),
-        Cell::from(format!("CPU%{}", sort_indicator(ProcessSort::Cpu)))
-            .style(header_style(Some(ProcessSort::Cpu))),
-        Cell::from(format!("Mem{}", sort_indicator(ProcessSort::Memory)))
-            .style(header_style(Some(ProcessSort::Memory))),
-        Cell::from("Time").style(header_style(None)),
-    ]);
+    let mut columns = app.process_columns.clone();
+    // Container/unit column joins automatically on wide tables when any row has one.
+    let has_cgroups = app.processes.iter().any(|p| !p.cgroup.is_empty());
+    if area.width >= 100 && has_cgroups && !columns.contains(&ProcessColumn::Cgroup) {
+        let at = columns
+            .iter()
+            .position(|c| *c == ProcessColumn::Name)
+            .map_or(columns.len(), |i| i + 1);
+        columns.insert(at, ProcessColumn::Cgroup);
+    }
+    // FD counts, VmSwap, and nice come from /proc; other platforms drop the columns.
+    if !cfg!(target_os = "linux") {
+        columns.retain(|c| {
+            !matches!(
+                c,
+                ProcessColumn::Fds | ProcessColumn::Swap | ProcessColumn::Nice
+            )
+        });
+    }
+    let header = Row::new(
+        columns
+            .iter()
+            .map(|col| {
+                let label = match col {
+                    ProcessColumn::Name => name_header,
+                    // Whole-machine scale gets its own label so 100% isn't read as one core.
+                    ProcessColumn::Cpu if app.cpu_normalize == CpuNormalize::Total => "Tot%",
+                    _ => column_label(*col),
+                };
+                let indicator = col.sort().map(sort_indicator).unwrap_or("");
+                Cell::from(format!("{label}{indicator}")).style(header_style(col.sort()))
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    // Visible rows = area minus borders (2) and header (1).
+    let page_rows = area.height.saturating_sub(3) as usize;
+    app.process_visible_rows = page_rows;
+    let offset = scroll_offset(
+        app.process_scroll,
+        app.processes.len(),
+        page_rows,
+        app.cfg.display.scrolloff,
+    );
+    let body = Rect::new(
+        area.x + 1,
+        area.y + 2,
+        area.width.saturating_sub(2),
+        page_rows as u16,
+    );
+    app.process_rows_rect = Some((body, offset));
+    // Counting FDs and reading VmSwap or nice cost a /proc read per process,
+    // so only rows on screen are read.
+    let wants_fds = columns.contains(&ProcessColumn::Fds);
+    let wants_swap = columns.contains(&ProcessColumn::Swap);
+    let wants_nice = columns.contains(&ProcessColumn::Nice);
+    if wants_fds || wants_swap || wants_nice {
+        let visible: Vec<u32> = app
+            .processes
+            .iter()
+            .skip(offset)
+            .take(page_rows)
+            .map(|p| p.pid)
+            .collect();
+        if wants_fds {
+            app.refresh_fd_counts(&visible);
+        }
+        if wants_swap {
+            app.refresh_swap(&visible);
+        }
+        if wants_nice {
+            app.refresh_nice(&visible);
+        }
+    }
 
     let name_max: usize = if app.show_cmd { 40 } else { 20 };
     let total_mem = app.sys.snapshot().mem_total;
@@ -53,19 +114,11 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         .iter()
         .enumerate()
         .map(|(i, p)| {
-            let cpu_color = if p.cpu_usage >= 90.0 {
-                Color::Red
-            } else if p.cpu_usage >= 70.0 {
-                Color::Rgb(255, 100, 0)
-            } else if p.cpu_usage >= 50.0 {
-                Color::Yellow
-            } else if p.cpu_usage >= 20.0 {
-                Color::Rgb(150, 255, 0)
-            } else {
-                Color::Green
-            };
-            let bg = if i % 2 == 1 {
-                Color::Rgb(30, 30, 40) // subtle alternating row
+            let cpu_color = cpu_color(p.cpu_usage);
+            let bg = if app.is_new_process(p.pid) {
+                gate(Color::Rgb(20, 50, 30)) // appeared since launch
+            } else if i % 2 == 1 {
+                gate(Color::Rgb(30, 30, 40)) // subtle alternating row
             } else {
                 Color::Reset
             };
@@ -75,7 +128,13 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
                 _ => Color::DarkGray,
             };
             let display_name = if app.show_cmd { &p.cmd } else { &p.name };
-            let user_display = truncate_name(&p.user, 8);
+            let display_name = if p.group_count > 1 {
+                &format!("{display_name} ({})", p.group_count)
+            } else {
+                display_name
+            };
+            let user_cell =
+                Cell::from(truncate_name(&p.user, 8)).style(Style::default().fg(Color::DarkGray));
             // Tree indentation prefix.
             let tree_prefix = if app.tree_mode && p.tree_depth > 0 {
                 let indent = "  ".repeat(p.tree_depth.min(4));
@@ -109,31 +168,65 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
             } else {
                 Cell::from(name_with_tree)
             };
-            Row::new(vec![
-                Cell::from(p.state.label()).style(Style::default().fg(state_color)),
-                Cell::from(format!("{}", p.pid)),
-                Cell::from(user_display).style(Style::default().fg(Color::DarkGray)),
-                name_cell,
-                Cell::from(format!("{:.1}", p.cpu_usage)).style(Style::default().fg(cpu_color)),
-                Cell::from(format_mem(p.memory_bytes, total_mem)),
-                Cell::from(format_duration(p.run_time_secs))
-                    .style(Style::default().fg(Color::DarkGray)),
-            ])
-            .style(Style::default().bg(bg))
+            let cells: Vec<Cell> = columns
+                .iter()
+                .map(|col| match col {
+                    // Grouped rows ('X') would only show the
+                    // representative PID's values; leave them blank.
+                    ProcessColumn::Pid
+                    | ProcessColumn::Fds
+                    | ProcessColumn::Swap
+                    | ProcessColumn::Nice
+                        if p.group_count > 1 =>
+                    {
+                        Cell::from("-").style(Style::default().fg(Color::DarkGray))
+                    }
+                    ProcessColumn::State => {
+                        Cell::from(p.state.label()).style(Style::default().fg(state_color))
+                    }
+                    ProcessColumn::Pid => Cell::from(format!("{}", p.pid)),
+                    ProcessColumn::User => user_cell.clone(),
+                    ProcessColumn::Name => name_cell.clone(),
+                    ProcessColumn::Cpu => Cell::from(format!("{:.1}", p.cpu_usage))
+                        .style(Style::default().fg(cpu_color)),
+                    ProcessColumn::Mem => Cell::from(format_mem(p.memory_bytes, total_mem)),
+                    ProcessColumn::Time => Cell::from(format_duration(p.run_time_secs))
+                        .style(Style::default().fg(Color::DarkGray)),
+                    ProcessColumn::Cgroup => Cell::from(truncate_name(&p.cgroup, 16))
+                        .style(Style::default().fg(Color::Magenta)),
+                    ProcessColumn::Fds => match app.fd_count(p.pid) {
+                        Some(n) => {
+                            Cell::from(n.to_string()).style(Style::default().fg(fd_color(n)))
+                        }
+                        None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                    },
+                    ProcessColumn::Swap => match app.swap_bytes(p.pid) {
+                        Some(bytes) if bytes > 0 => Cell::from(format_bytes(bytes))
+                            .style(Style::default().fg(swap_color(bytes))),
+                        _ => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                    },
+                    ProcessColumn::Nice => match app.nice(p.pid) {
+                        Some(nice) => Cell::from(nice.to_string())
+                            .style(Style::default().fg(nice_color(nice))),
+                        None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                    },
+                })
+                .collect();
+            // Change feed: new rows bright, exited ones struck through.
+            let row_style = match app.process_changes.get(&p.pid) {
+                Some(ProcessChange::New) => Style::default().fg(Color::LightGreen),
+                Some(ProcessChange::Gone) => Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::CROSSED_OUT),
+                _ => Style::default(),
+            };
+            Row::new(cells).style(row_style.bg(bg))
         })
         .collect();
 
     let row_count = rows.len();
 
-    let widths = [
-        Constraint::Length(1),
-        Constraint::Length(7),
-        Constraint::Length(8),
-        Constraint::Min(12),
-        Constraint::Length(7),
-        Constraint::Length(12),
-        Constraint::Length(8),
-    ];
+    let widths: Vec<Constraint> = columns.iter().map(|col| column_width(*col)).collect();
 
     let sort_arrow = if app.sort_reverse {
         "\u{25b2}"
@@ -145,14 +238,30 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         ProcessSort::Memory => "Mem",
         ProcessSort::Pid => "PID",
         ProcessSort::Name => "Name",
+        ProcessSort::Swap => "Swap",
     };
 
-    let count_label = if !app.process_filter.is_empty() || app.filter_mode {
+    // Show "shown/total" when filtering or when display.max_processes cut the list.
+    let truncated = app.processes.len() < app.total_process_count;
+    let count_label = if !app.process_filter.is_empty() || app.filter_mode || truncated {
         format!("{}/{}", app.processes.len(), app.total_process_count)
     } else {
         format!("{}", app.processes.len())
     };
-    let tree_tag = if app.tree_mode { " tree" } else { "" };
+    let tree_tag = if app.diff_mode {
+        " changes"
+    } else if app.group_by_exe {
+        " by exe"
+    } else if app.tree_mode {
+        " tree"
+    } else {
+        ""
+    };
+    let kthread_tag = if app.kernel_threads_hidden > 0 {
+        format!(" {} kthreads hidden", app.kernel_threads_hidden)
+    } else {
+        String::new()
+    };
     let visible_cpu: f32 = app.processes.iter().map(|p| p.cpu_usage).sum();
     let cpu_tag = if visible_cpu >= 1.0 {
         format!(" {visible_cpu:.0}%")
@@ -192,17 +301,27 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
     };
     let title = if app.filter_mode {
         format!(" Processes ({count_label}) [/{}|] ", app.process_filter)
+    } else if let Some(name) = &app.active_saved_filter {
+        format!(" Processes ({count_label}{cpu_tag}{state_tag}{kthread_tag}) [filter: @{name}] ")
     } else if !app.process_filter.is_empty() {
         format!(
-            " Processes ({count_label}{cpu_tag}{state_tag}) [filter: {}] ",
+            " Processes ({count_label}{cpu_tag}{state_tag}{kthread_tag}) [filter: {}] ",
             app.process_filter
         )
     } else {
-        format!(" Processes ({count_label}{cpu_tag}{state_tag}) [sort: {sort_name}{sort_arrow}{tree_tag}] ")
+        format!(" Processes ({count_label}{cpu_tag}{state_tag}{kthread_tag}) [sort: {sort_name}{sort_arrow}{tree_tag}] ")
     };
 
-    // Scroll position indicator.
-    let scroll_tag = if row_count > 0 {
+    // Scroll position indicator, with a page count once the list overflows.
+    let scroll_tag = if row_count > 0 && page_rows > 0 && row_count > page_rows {
+        format!(
+            " {}/{} page {}/{} ",
+            app.process_scroll + 1,
+            row_count,
+            app.process_scroll / page_rows + 1,
+            row_count.div_ceil(page_rows)
+        )
+    } else if row_count > 0 {
         format!(" {}/{} ", app.process_scroll + 1, row_count)
     } else {
         String::new()
@@ -214,48 +333,289 @@ pub fn draw_processes(frame: &mut Frame, area: Rect, app: &mut App) {
         Color::Blue
     };
 
-    let table = Table::new(rows, widths)
-        .header(header)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .title_bottom(
-                    Line::from(Span::styled(
-                        scroll_tag,
-                        Style::default().fg(Color::DarkGray),
-                    ))
-                    .right_aligned(),
-                )
-                .border_style(Style::default().fg(border_color)),
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(
+            Line::from(Span::styled(
+                scroll_tag,
+                Style::default().fg(Color::DarkGray),
+            ))
+            .right_aligned(),
         )
-        .row_highlight_style(
+        .border_style(Style::default().fg(border_color));
+    let table = Table::new(rows, widths)
+        .row_highlight_style(selection(
+            app.selection_style,
             Style::default()
                 .bg(Color::Rgb(60, 60, 80))
                 .fg(Color::White)
                 .add_modifier(Modifier::BOLD),
-        )
+        ))
         .highlight_symbol(">> ");
 
-    let mut state = TableState::default().with_selected(Some(app.process_scroll));
+    let mut state = TableState::default()
+        .with_selected(Some(app.process_scroll))
+        .with_offset(offset);
+    render_scrolling_table(
+        frame,
+        area,
+        Some(block),
+        table,
+        header,
+        row_count,
+        &mut state,
+    );
+}
+
+/// First visible row so the selection sits at least `scrolloff` rows above
+/// the bottom edge (the margin is capped at half the view, which centers it).
+/// With `scrolloff` 0 this matches ratatui's own keep-selected-visible offset.
+fn scroll_offset(selected: usize, rows: usize, visible: usize, scrolloff: usize) -> usize {
+    if visible == 0 {
+        return selected;
+    }
+    let margin = scrolloff.min((visible - 1) / 2);
+    (selected + margin + 1)
+        .saturating_sub(visible)
+        .min(rows.saturating_sub(visible))
+}
+
+/// Borderless process rows for the mini layout (short panes): PID, name,
+/// CPU, and memory in the current sort order, scrolled to keep the
+/// selection on screen.
+pub fn draw_top_processes(frame: &mut Frame, area: Rect, app: &mut App) {
+    let visible = area.height as usize;
+    app.process_visible_rows = visible;
+    let offset = scroll_offset(app.process_scroll, app.processes.len(), visible, 0);
+    app.process_rows_rect = Some((area, offset));
+    let total_mem = app.sys.snapshot().mem_total;
+    let rows: Vec<Row> = app
+        .processes
+        .iter()
+        .map(|p| {
+            Row::new(vec![
+                Cell::from(p.pid.to_string()).style(Style::default().fg(Color::DarkGray)),
+                Cell::from(truncate_name(&p.name, 20)),
+                Cell::from(format!("{:.1}%", p.cpu_usage))
+                    .style(Style::default().fg(cpu_color(p.cpu_usage))),
+                Cell::from(format_mem(p.memory_bytes, total_mem)),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Min(8),
+        Constraint::Length(7),
+        Constraint::Length(12),
+    ];
+    let table = Table::new(rows, widths).row_highlight_style(selection(
+        app.selection_style,
+        Style::default()
+            .bg(Color::Rgb(60, 60, 80))
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ));
+    let mut state = TableState::default()
+        .with_selected(Some(app.process_scroll))
+        .with_offset(offset);
     frame.render_stateful_widget(table, area, &mut state);
+}
 
-    // Scrollbar.
-    if row_count > 0 {
-        let mut scrollbar_state = ScrollbarState::new(row_count).position(app.process_scroll);
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(None)
-            .end_symbol(None);
-        frame.render_stateful_widget(
-            scrollbar,
-            area.inner(ratatui::layout::Margin {
-                vertical: 1,
-                horizontal: 0,
-            }),
-            &mut scrollbar_state,
-        );
+/// Centered popup for the process opened with `o`: identity, current
+/// usage, and a CPU graph of the samples taken while the popup was open.
+pub fn draw_process_detail(frame: &mut Frame, area: Rect, app: &App) {
+    if !app.show_process_detail {
+        return;
     }
+    let Some(pid) = app.detail_pid else {
+        return;
+    };
+
+    let popup_width = 64u16.min(area.width.saturating_sub(4));
+    let popup_height = 16u16.min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+    frame.render_widget(Clear, popup_area);
+
+    let row = app.processes.iter().find(|p| p.pid == pid);
+    let title = match row {
+        Some(p) if p.group_count > 1 => format!(" {} ({} processes) ", p.name, p.group_count),
+        Some(p) => format!(" {} ({pid}) ", p.name),
+        None => format!(" Process {pid} "),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(Line::from(Span::styled(
+            " Any key to close ",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(3)])
+        .split(inner);
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::DarkGray));
+    let lines: Vec<Line> = match row {
+        Some(p) => {
+            let total_mem = app.sys.snapshot().mem_total;
+            let cmd = if p.cmd.is_empty() { &p.name } else { &p.cmd };
+            // A group has no single parent; CPU, Mem and Up are its totals/max.
+            let ppid = if p.group_count > 1 {
+                "-".to_string()
+            } else {
+                p.ppid.to_string()
+            };
+            vec![
+                Line::from(vec![
+                    label(" PPID  "),
+                    Span::raw(format!("{ppid:<8}")),
+                    label("User  "),
+                    Span::raw(p.user.clone()),
+                ]),
+                Line::from(vec![
+                    label(" State "),
+                    Span::raw(format!("{:<8}", p.state.label())),
+                    label("Up    "),
+                    Span::raw(format_duration(p.run_time_secs)),
+                ]),
+                Line::from(vec![
+                    label(" CPU   "),
+                    Span::styled(
+                        format!("{:<8}", format!("{:.1}%", p.cpu_usage)),
+                        Style::default().fg(cpu_color(p.cpu_usage)),
+                    ),
+                    label("Mem   "),
+                    Span::raw(format_mem(p.memory_bytes, total_mem)),
+                ]),
+                Line::from(vec![
+                    label(" Cmd   "),
+                    Span::raw(truncate_name(cmd, inner.width.saturating_sub(8) as usize)),
+                ]),
+            ]
+        }
+        None => vec![Line::from(Span::styled(
+            " Not in the table right now (idle or exited).",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let data: Vec<u64> = app
+        .detail_cpu_history
+        .iter()
+        .map(|v| v.round() as u64)
+        .collect();
+    let peak = data.iter().copied().max().unwrap_or(0);
+    let current = data.last().copied().unwrap_or(0);
+    // Multi-threaded processes can exceed 100% of one core.
+    let max = peak.max(100);
+    let graph = Block::default()
+        .borders(Borders::TOP)
+        .title(format!(
+            " CPU {current}% (pk:{peak}%, {} samples) ",
+            data.len()
+        ))
+        .border_style(Style::default().fg(Color::DarkGray));
+    render_series(
+        frame,
+        chunks[1],
+        Some(graph),
+        &data,
+        Some(max),
+        cpu_color(current as f32),
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
+}
+
+/// Process CPU% color: green under 20%, through yellow, to red from 90%.
+fn cpu_color(usage: f32) -> Color {
+    let color = if usage >= 90.0 {
+        Color::Red
+    } else if usage >= 70.0 {
+        Color::Rgb(255, 100, 0)
+    } else if usage >= 50.0 {
+        Color::Yellow
+    } else if usage >= 20.0 {
+        Color::Rgb(150, 255, 0)
+    } else {
+        Color::Green
+    };
+    gate(color)
+}
+
+/// Header label for a column (Name is swapped for "Cmd" when `show_cmd` is on).
+fn column_label(col: ProcessColumn) -> &'static str {
+    match col {
+        ProcessColumn::State => "S",
+        ProcessColumn::Pid => "PID",
+        ProcessColumn::User => "User",
+        ProcessColumn::Name => "Name",
+        ProcessColumn::Cpu => "CPU%",
+        ProcessColumn::Mem => "Mem",
+        ProcessColumn::Time => "Time",
+        ProcessColumn::Cgroup => "Unit",
+        ProcessColumn::Fds => "FDs",
+        ProcessColumn::Swap => "Swap",
+        ProcessColumn::Nice => "NI",
+    }
+}
+
+fn column_width(col: ProcessColumn) -> Constraint {
+    match col {
+        ProcessColumn::State => Constraint::Length(1),
+        ProcessColumn::Pid => Constraint::Length(7),
+        ProcessColumn::User => Constraint::Length(8),
+        ProcessColumn::Name => Constraint::Min(12),
+        ProcessColumn::Cpu => Constraint::Length(7),
+        ProcessColumn::Mem => Constraint::Length(12),
+        ProcessColumn::Time => Constraint::Length(8),
+        ProcessColumn::Cgroup => Constraint::Length(16),
+        ProcessColumn::Fds => Constraint::Length(5),
+        ProcessColumn::Swap => Constraint::Length(9),
+        ProcessColumn::Nice => Constraint::Length(4),
+    }
+}
+
+/// Nice color (htop-style): red for boosted priority, blue for niced-down.
+fn nice_color(nice: i32) -> Color {
+    gate(match nice {
+        n if n < 0 => Color::Red,
+        n if n > 0 => Color::Blue,
+        _ => Color::Gray,
+    })
+}
+
+/// Swapped-out size color: magenta from 100 MiB (a likely thrash source),
+/// yellow below that.
+fn swap_color(bytes: u64) -> Color {
+    gate(if bytes >= 100 * 1024 * 1024 {
+        Color::Magenta
+    } else {
+        Color::Yellow
+    })
+}
+
+/// Open-FD count color: yellow from 512, red from 1024 (a common soft
+/// `RLIMIT_NOFILE`, where "too many open files" starts).
+fn fd_color(count: usize) -> Color {
+    let color = if count >= 1024 {
+        Color::Red
+    } else if count >= 512 {
+        Color::Yellow
+    } else {
+        Color::Gray
+    };
+    gate(color)
 }
 
 fn format_duration(secs: u64) -> String {
@@ -310,6 +670,13 @@ fn format_bytes(bytes: u64) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fd_color_thresholds() {
+        assert_eq!(fd_color(40), Color::Gray);
+        assert_eq!(fd_color(600), Color::Yellow);
+        assert_eq!(fd_color(4096), Color::Red);
+    }
+
     #[test]
     fn test_format_duration() {
         // Under 1 hour: "m:ss" format
@@ -323,6 +690,18 @@ mod tests {
         assert!(result.contains("1d"), "got: {result}");
     }
 
+    #[test]
+    fn test_scroll_offset() {
+        // No margin: the selection rides the bottom edge.
+        assert_eq!(scroll_offset(5, 100, 10, 0), 0);
+        assert_eq!(scroll_offset(15, 100, 10, 0), 6);
+        // Margin of 3 keeps three rows below the selection.
+        assert_eq!(scroll_offset(15, 100, 10, 3), 9);
+        // Huge margins center; the end of the list never leaves blank rows.
+        assert_eq!(scroll_offset(50, 100, 11, 99), 45);
+        assert_eq!(scroll_offset(99, 100, 10, 3), 90);
+    }
+
     #[test]
     fn test_format_bytes() {
         assert!(format_bytes(500).contains("KiB"));
@@ -0,0 +1,50 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState};
+
+use super::selection;
+use crate::app::{App, SIGNAL_MENU};
+
+/// Centered signal picker popup for the selected process.
+pub fn draw_signal_menu(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(selected) = app.signal_menu else {
+        return;
+    };
+
+    let popup_width = 30u16.min(area.width.saturating_sub(4));
+    let popup_height = (SIGNAL_MENU.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = match &app.signal_target {
+        Some(t) => format!(" Signal {} ({}) ", t.name, t.pid),
+        None => " Signal ".to_string(),
+    };
+
+    let items: Vec<ListItem> = SIGNAL_MENU
+        .iter()
+        .map(|(_, name)| ListItem::new(format!(" {name}")))
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .title_bottom(Line::from(" Enter send  Esc cancel ").centered())
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .highlight_style(selection(
+            app.selection_style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, popup_area, &mut state);
+}
@@ -1,7 +1,93 @@
+use std::collections::VecDeque;
+
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Sparkline as RatatuiSparkline};
+use ratatui::symbols::Marker;
+use ratatui::widgets::{
+    Axis, Bar, BarChart, BarGroup, Block, BorderType, Borders, Chart, Dataset, GraphType,
+    Paragraph, Sparkline as RatatuiSparkline,
+};
 
+use super::format_rate;
 use crate::app::App;
+use crate::config::{CpuView, SparklineNewest, SparklineStyle};
+use crate::ui::gradient::{gate, net_rate_color};
+
+/// Render a history series as bars or a braille line, per `display.sparkline_style`,
+/// oldest-first `data` flipped when `display.sparkline_newest` is "left".
+/// `max` of None scales to the series peak. An empty series (before the
+/// first sample) shows "collecting…" inside the block.
+#[allow(clippy::too_many_arguments)]
+pub fn render_series(
+    frame: &mut Frame,
+    area: Rect,
+    block: Option<Block>,
+    data: &[u64],
+    max: Option<u64>,
+    color: Color,
+    style: SparklineStyle,
+    newest: SparklineNewest,
+) {
+    if data.is_empty() {
+        render_collecting(frame, area, block);
+        return;
+    }
+    let color = gate(color);
+    let reversed: Vec<u64>;
+    let data = match newest {
+        SparklineNewest::Right => data,
+        SparklineNewest::Left => {
+            reversed = data.iter().rev().copied().collect();
+            &reversed
+        }
+    };
+    match style {
+        SparklineStyle::Bar => {
+            let mut sparkline = RatatuiSparkline::default()
+                .data(data)
+                .style(Style::default().fg(color));
+            if let Some(max) = max {
+                sparkline = sparkline.max(max);
+            }
+            if let Some(block) = block {
+                sparkline = sparkline.block(block);
+            }
+            frame.render_widget(sparkline, area);
+        }
+        SparklineStyle::Braille => {
+            let top = max
+                .unwrap_or_else(|| data.iter().copied().max().unwrap_or(0))
+                .max(1);
+            let points: Vec<(f64, f64)> = data
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| (i as f64, v.min(top) as f64))
+                .collect();
+            let dataset = Dataset::default()
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(&points);
+            let mut chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([0.0, data.len().saturating_sub(1).max(1) as f64]))
+                .y_axis(Axis::default().bounds([0.0, top as f64]));
+            if let Some(block) = block {
+                chart = chart.block(block);
+            }
+            frame.render_widget(chart, area);
+        }
+    }
+}
+
+/// Placeholder for a graph with no samples yet, so the slot keeps its
+/// border and title during the first refresh after launch.
+pub fn render_collecting(frame: &mut Frame, area: Rect, block: Option<Block>) {
+    let mut text =
+        Paragraph::new(" collecting\u{2026}").style(Style::default().fg(Color::DarkGray));
+    if let Some(block) = block {
+        text = text.block(block);
+    }
+    frame.render_widget(text, area);
+}
 
 /// Draw CPU usage sparkline (last 60 seconds).
 pub fn draw_cpu_sparkline(frame: &mut Frame, area: Rect, app: &App) {
@@ -15,22 +101,24 @@ pub fn draw_cpu_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let peak = data.iter().copied().max().unwrap_or(0);
     let lo = data.iter().copied().min().unwrap_or(0);
 
-    let color = pct_gradient(current);
+    let color = app.gradient.color(current as f64);
     let title = format!(" CPU {current}% (avg:{avg} pk:{peak} lo:{lo}) ");
 
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .max(100)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        Some(100),
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw memory usage sparkline (last 60 seconds).
@@ -45,22 +133,24 @@ pub fn draw_mem_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let peak = data.iter().copied().max().unwrap_or(0);
     let lo = data.iter().copied().min().unwrap_or(0);
 
-    let color = pct_gradient(current);
+    let color = app.gradient.color(current as f64);
     let title = format!(" MEM {current}% (avg:{avg} pk:{peak} lo:{lo}) ");
 
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .max(100)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        Some(100),
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw swap usage sparkline (last 60 seconds).
@@ -70,22 +160,61 @@ pub fn draw_swap_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let peak = data.iter().copied().max().unwrap_or(0);
     let lo = data.iter().copied().min().unwrap_or(0);
 
-    let color = pct_gradient(current);
+    let color = app.gradient.color(current as f64);
     let title = format!(" Swap {current}% (pk:{peak} lo:{lo}) ");
 
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .max(100)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        Some(100),
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
+}
+
+/// One-row CPU + memory strip for the mini layout: "CPU 23% ▂▃▅ | MEM 61% ▄▄▄",
+/// without borders.
+pub fn draw_mini_strip(frame: &mut Frame, area: Rect, app: &App) {
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let mem = app.mem_history.back().copied().unwrap_or(0.0);
+    let series = [
+        ("CPU", app.cpu_display_total() as f64, &app.cpu_history),
+        ("MEM", mem, &app.mem_history),
+    ];
+    for (half, (label, current, history)) in halves.iter().zip(series) {
+        let color = app.gradient.color(current);
+        let text = format!("{label} {current:.0}% ");
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(text.len() as u16), Constraint::Min(0)])
+            .split(*half);
+        frame.render_widget(
+            Paragraph::new(text).style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            cols[0],
+        );
+        let data: Vec<u64> = history.iter().map(|v| *v as u64).collect();
+        render_series(
+            frame,
+            cols[1],
+            None,
+            &data,
+            Some(100),
+            color,
+            app.sparkline_style,
+            app.sparkline_newest,
+        );
+    }
 }
 
 /// Draw network RX rate sparkline (last 60 seconds).
@@ -93,22 +222,25 @@ pub fn draw_net_rx_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let data: Vec<u64> = app.net_rx_history.iter().map(|v| *v as u64).collect();
     let current = data.last().copied().unwrap_or(0);
     let peak = data.iter().copied().max().unwrap_or(0);
-    let label = format_rate(current);
-    let peak_label = format_rate(peak);
-    let color = net_rate_color(current);
-
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(format!(" RX {label} (pk:{peak_label}) "))
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let label = format_rate(current, app.net_idle_threshold);
+    let peak_label = format_rate(peak, app.net_idle_threshold);
+    let color = net_rate_color(current, app.net_idle_threshold);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" RX {label} (pk:{peak_label}) "))
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        None,
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw network TX rate sparkline (last 60 seconds).
@@ -116,22 +248,25 @@ pub fn draw_net_tx_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let data: Vec<u64> = app.net_tx_history.iter().map(|v| *v as u64).collect();
     let current = data.last().copied().unwrap_or(0);
     let peak = data.iter().copied().max().unwrap_or(0);
-    let label = format_rate(current);
-    let peak_label = format_rate(peak);
-    let color = net_rate_color(current);
-
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(format!(" TX {label} (pk:{peak_label}) "))
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let label = format_rate(current, app.net_idle_threshold);
+    let peak_label = format_rate(peak, app.net_idle_threshold);
+    let color = net_rate_color(current, app.net_idle_threshold);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" TX {label} (pk:{peak_label}) "))
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        None,
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw load average (1-minute) sparkline.
@@ -149,22 +284,24 @@ pub fn draw_load_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let peak = app.load_history.iter().cloned().fold(0.0f64, f64::max);
     let load_pct = ((current / cpu_count) * 100.0) as u64;
 
-    let color = pct_gradient(load_pct.min(100));
+    let color = app.gradient.color(load_pct.min(100) as f64);
     let title = format!(" Load {current:.2} (pk:{peak:.2}) ");
 
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .max(100)
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        Some(100),
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw max temperature sparkline (last 60 seconds).
@@ -174,28 +311,40 @@ pub fn draw_temp_sparkline(frame: &mut Frame, area: Rect, app: &App) {
     let peak = data.iter().copied().max().unwrap_or(0);
     let lo = data.iter().copied().min().unwrap_or(0);
 
-    let color = temp_color(current);
+    let color = app.temp_gradient.color(current as f64);
     let title = format!(" Temp {current}°C (pk:{peak} lo:{lo}) ");
 
-    let sparkline = RatatuiSparkline::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .border_style(Style::default().fg(Color::Blue)),
-        )
-        .data(&data)
-        .max(110) // max reasonable temp
-        .style(Style::default().fg(color));
-
-    frame.render_widget(sparkline, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(Color::Blue));
+    render_series(
+        frame,
+        area,
+        Some(block),
+        &data,
+        Some(110), // max reasonable temp
+        color,
+        app.sparkline_style,
+        app.sparkline_newest,
+    );
 }
 
 /// Draw per-core CPU mini sparklines in a compact grid (4 per row).
 pub fn draw_cpu_per_core(frame: &mut Frame, area: Rect, app: &App) {
+    if app.cpu_view == CpuView::Heatmap {
+        super::heatmap::draw_cpu_heatmap(frame, area, app);
+        return;
+    }
     let cores = app.cpu_per_core_history.len();
     if cores == 0 {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(" Cores ")
+            .border_style(Style::default().fg(Color::DarkGray));
+        render_collecting(frame, area, Some(block));
         return;
     }
 
@@ -243,7 +392,7 @@ pub fn draw_cpu_per_core(frame: &mut Frame, area: Rect, app: &App) {
 
         let data: Vec<u64> = history.iter().map(|v| *v as u64).collect();
         let current = data.last().copied().unwrap_or(0);
-        let color = pct_gradient(current);
+        let color = app.gradient.color(current as f64);
 
         // Show per-core frequency if available.
         let freq_tag = snap
@@ -256,107 +405,235 @@ pub fn draw_cpu_per_core(frame: &mut Frame, area: Rect, app: &App) {
             })
             .unwrap_or_default();
 
-        let sparkline = RatatuiSparkline::default()
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(format!(" C{core_idx} {current}%{freq_tag} "))
-                    .border_style(Style::default().fg(Color::DarkGray)),
+        // Orange title/border when the core looks throttled.
+        let throttled = possibly_throttling(
+            snap.cpu_freqs.get(core_idx).copied().unwrap_or(0),
+            app.cpu_freq_peak.get(core_idx).copied().unwrap_or(0),
+            history,
+        );
+        let (title_style, border_color) = if throttled {
+            (
+                Style::default().fg(gate(THROTTLE_COLOR)),
+                gate(THROTTLE_COLOR),
             )
-            .data(&data)
-            .max(100)
-            .style(Style::default().fg(color));
-
-        frame.render_widget(sparkline, cols[col_idx]);
+        } else {
+            (Style::default(), Color::DarkGray)
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!(" C{core_idx} {current}%{freq_tag} "))
+            .title_style(title_style)
+            .border_style(Style::default().fg(border_color));
+        render_series(
+            frame,
+            cols[col_idx],
+            Some(block),
+            &data,
+            Some(100),
+            color,
+            app.sparkline_style,
+            app.sparkline_newest,
+        );
     }
 }
 
-/// btm-style color gradient: green -> yellow -> red based on percentage.
-fn pct_gradient(pct: u64) -> Color {
-    if pct >= 90 {
-        Color::Red
-    } else if pct >= 80 {
-        Color::Rgb(255, 100, 0) // orange-red
-    } else if pct >= 65 {
-        Color::Yellow
-    } else if pct >= 40 {
-        Color::Rgb(150, 255, 0) // yellow-green
-    } else {
-        Color::Green
+/// Sample counts per 10% CPU bucket (0-10, 10-20, ..., 90-100; 100% lands in the last).
+pub fn cpu_buckets(history: &VecDeque<f64>) -> [u64; 10] {
+    let mut buckets = [0u64; 10];
+    for &v in history {
+        let idx = ((v.max(0.0) / 10.0) as usize).min(9);
+        buckets[idx] += 1;
     }
+    buckets
 }
 
-/// Temperature color: green (cool) -> yellow (warm) -> red (hot).
-fn temp_color(temp_c: u64) -> Color {
-    if temp_c >= 90 {
-        Color::Red
-    } else if temp_c >= 80 {
-        Color::Rgb(255, 100, 0)
-    } else if temp_c >= 65 {
-        Color::Yellow
-    } else if temp_c >= 45 {
-        Color::Rgb(150, 255, 0)
-    } else {
-        Color::Green
-    }
-}
+/// Draw how CPU time over the history window is distributed across 10%
+/// buckets, as horizontal bars. Shows bimodal load (mostly idle, short
+/// spikes) that the time-series sparkline smooths over.
+pub fn draw_cpu_histogram(frame: &mut Frame, area: Rect, app: &App) {
+    let buckets = cpu_buckets(&app.cpu_history);
+    let total: u64 = buckets.iter().sum();
 
-/// Network rate color: idle=DarkGray, low=Green, medium=Cyan, high=Yellow, very high=Magenta.
-fn net_rate_color(bytes_per_sec: u64) -> Color {
-    const MIB: u64 = 1024 * 1024;
-    const KIB: u64 = 1024;
-    if bytes_per_sec >= 10 * MIB {
-        Color::Magenta
-    } else if bytes_per_sec >= MIB {
-        Color::Yellow
-    } else if bytes_per_sec >= 100 * KIB {
-        Color::Cyan
-    } else if bytes_per_sec >= KIB {
-        Color::Green
-    } else if bytes_per_sec > 0 {
-        Color::DarkGray
-    } else {
-        Color::DarkGray
-    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" CPU distribution ({total} samples) "))
+        .border_style(Style::default().fg(Color::Blue));
+
+    let bars: Vec<Bar> = buckets
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let lo = i * 10;
+            let share = (count * 100).checked_div(total).unwrap_or(0);
+            Bar::default()
+                .label(Line::from(format!("{lo:>2}-{:<3}", lo + 10)))
+                .value(count)
+                .text_value(format!("{share}%"))
+                .style(Style::default().fg(app.gradient.color(lo as f64 + 5.0)))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(block)
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .max(total.max(1))
+        .data(BarGroup::default().bars(&bars));
+    frame.render_widget(chart, area);
 }
 
-fn format_rate(bytes_per_sec: u64) -> String {
-    const MIB: u64 = 1024 * 1024;
-    const KIB: u64 = 1024;
-    if bytes_per_sec >= MIB {
-        format!("{:.1} MB/s", bytes_per_sec as f64 / MIB as f64)
-    } else if bytes_per_sec >= KIB {
-        format!("{:.0} KB/s", bytes_per_sec as f64 / KIB as f64)
-    } else if bytes_per_sec > 0 {
-        format!("{} B/s", bytes_per_sec)
-    } else {
-        "idle".into()
+const THROTTLE_COLOR: Color = Color::Rgb(255, 165, 0);
+
+/// Heuristic only: a core is "possibly throttling" when its recent usage is
+/// high but its clock sits well below the highest frequency we've seen it
+/// run at. Frequency scaling, power profiles, and stale readings can all
+/// trip this, so it's a hint rather than a diagnosis.
+fn possibly_throttling(freq_mhz: u64, peak_mhz: u64, history: &VecDeque<f64>) -> bool {
+    const RECENT: usize = 5;
+    if freq_mhz == 0 || peak_mhz == 0 || history.len() < RECENT {
+        return false;
     }
+    let recent_avg = history.iter().rev().take(RECENT).sum::<f64>() / RECENT as f64;
+    recent_avg >= 80.0 && freq_mhz * 100 < peak_mhz * 85
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::gradient::Gradient;
+
+    #[test]
+    fn test_render_series_newest_left() {
+        let draw = |newest| {
+            crate::ui::testing::render_widget_to_string(3, 1, |frame, area| {
+                render_series(
+                    frame,
+                    area,
+                    None,
+                    &[0, 50, 100],
+                    Some(100),
+                    Color::White,
+                    SparklineStyle::Bar,
+                    newest,
+                );
+            })
+        };
+        assert!(draw(SparklineNewest::Right)
+            .trim_end()
+            .ends_with('\u{2588}'));
+        assert!(draw(SparklineNewest::Left).starts_with('\u{2588}'));
+    }
+
+    #[test]
+    fn test_render_series_braille_glyphs() {
+        let draw = |data: &[u64]| {
+            crate::ui::testing::render_widget_to_string(4, 1, |frame, area| {
+                render_series(
+                    frame,
+                    area,
+                    None,
+                    data,
+                    Some(100),
+                    Color::White,
+                    SparklineStyle::Braille,
+                    SparklineNewest::Right,
+                );
+            })
+        };
+        assert_eq!(
+            draw(&[100, 100, 100, 100]),
+            "\u{2809}\u{2809}\u{2809}\u{2809}"
+        );
+        assert_eq!(draw(&[0, 0, 0, 0]), "\u{28c0}\u{28c0}\u{28c0}\u{28c0}");
+        assert_eq!(draw(&[0, 100]), "\u{28c0}\u{2824}\u{2812}\u{2809}");
+        assert_eq!(
+            draw(&[0, 50, 100, 50, 0]),
+            "\u{2870}\u{280a}\u{2811}\u{2884}"
+        );
+    }
+
+    #[test]
+    fn test_empty_series_shows_collecting() {
+        let out = crate::ui::testing::render_widget_to_string(24, 3, |frame, area| {
+            let block = Block::default().borders(Borders::ALL).title(" CPU 0% ");
+            render_series(
+                frame,
+                area,
+                Some(block),
+                &[],
+                Some(100),
+                Color::White,
+                SparklineStyle::Braille,
+                SparklineNewest::Right,
+            );
+        });
+        assert!(out.contains("CPU 0%"), "{out}");
+        assert!(out.contains("collecting"), "{out}");
+    }
+
+    #[test]
+    fn test_per_core_before_first_sample() {
+        let app = App::test_new(crate::config::TuiConfig::default());
+        let out = crate::ui::testing::render_widget_to_string(40, 6, |frame, area| {
+            draw_cpu_per_core(frame, area, &app);
+        });
+        assert!(out.contains("Cores"), "{out}");
+        assert!(out.contains("collecting"), "{out}");
+    }
 
     #[test]
     fn test_pct_gradient_thresholds() {
         // <40% should be Green
-        let low = pct_gradient(20);
+        let gradient = Gradient::default();
+        let low = gradient.color(20.0);
         assert_eq!(low, Color::Green);
         // 90%+ should be Red
-        let high = pct_gradient(95);
+        let high = gradient.color(95.0);
         assert_eq!(high, Color::Red);
         // 65-79 should be Yellow
-        let mid = pct_gradient(70);
+        let mid = gradient.color(70.0);
         assert_eq!(mid, Color::Yellow);
     }
 
+    #[test]
+    fn test_cpu_buckets() {
+        let history: VecDeque<f64> = VecDeque::from(vec![0.0, 5.0, 9.9, 10.0, 55.0, 99.0, 100.0]);
+        let buckets = cpu_buckets(&history);
+        assert_eq!(buckets[0], 3);
+        assert_eq!(buckets[1], 1);
+        assert_eq!(buckets[5], 1);
+        assert_eq!(buckets[9], 2, "100% counts in the top bucket");
+        assert_eq!(buckets.iter().sum::<u64>(), 7);
+    }
+
     #[test]
     fn test_format_rate_units() {
-        assert!(format_rate(500).contains("B/s"));
-        assert!(format_rate(2048).contains("KB/s"));
-        assert!(format_rate(2 * 1024 * 1024).contains("MB/s"));
-        assert_eq!(format_rate(0), "idle");
+        assert!(format_rate(500, 0).contains("B/s"));
+        assert!(format_rate(2048, 0).contains("KB/s"));
+        assert!(format_rate(2 * 1024 * 1024, 0).contains("MB/s"));
+        assert_eq!(format_rate(0, 0), "idle");
+    }
+
+    #[test]
+    fn test_possibly_throttling() {
+        let busy: VecDeque<f64> = VecDeque::from(vec![95.0; 10]);
+        let idle: VecDeque<f64> = VecDeque::from(vec![10.0; 10]);
+        // High usage at a clock well below peak.
+        assert!(possibly_throttling(2000, 4000, &busy));
+        // At (or near) peak clock: fine.
+        assert!(!possibly_throttling(3900, 4000, &busy));
+        // Low clock while idle is just power saving.
+        assert!(!possibly_throttling(800, 4000, &idle));
+        // Unknown frequency or too little history.
+        assert!(!possibly_throttling(0, 4000, &busy));
+        assert!(!possibly_throttling(
+            2000,
+            4000,
+            &VecDeque::from(vec![95.0; 2])
+        ));
     }
 }
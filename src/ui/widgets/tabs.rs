@@ -1,11 +1,19 @@
+use std::time::Duration;
+
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Tabs as RatatuiTabs};
 
-use crate::app::{App, Tab};
+use super::format_interval;
+use crate::app::App;
 
 pub fn draw_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
-    let hostname = app.sys.snapshot().hostname.clone();
-    let titles: Vec<Line> = Tab::ALL
+    // A configured `[general] label` replaces the hostname in the title.
+    let title_name = match app.cfg.title_label() {
+        Some(label) => label.to_string(),
+        None => app.sys.snapshot().hostname.clone(),
+    };
+    let titles: Vec<Line> = app
+        .tab_order
         .iter()
         .enumerate()
         .map(|(i, t)| {
@@ -25,7 +33,8 @@ pub fn draw_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
         })
         .collect();
 
-    let selected = Tab::ALL
+    let selected = app
+        .tab_order
         .iter()
         .position(|t| *t == app.active_tab)
         .unwrap_or(0);
@@ -38,7 +47,7 @@ pub fn draw_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .title(format!(" prompt-pulse v3 :: {hostname} "))
+                .title(format!(" prompt-pulse v3 :: {title_name} "))
                 .title_style(
                     Style::default()
                         .fg(Color::Cyan)
@@ -47,13 +56,17 @@ pub fn draw_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
                 .title_bottom(Line::from(vec![]).right_aligned())
                 .title(
                     Line::from({
-                        let mut spans = vec![Span::styled(
-                            format!(" {clock} "),
-                            Style::default().fg(Color::DarkGray),
-                        )];
+                        let age = app.last_successful_read.map(|t| t.elapsed());
+                        let mut spans = vec![
+                            Span::styled(" \u{25cf}", heartbeat_style(age)),
+                            Span::styled(
+                                format!(" {clock} "),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                        ];
                         if app.refresh_ms != 1000 {
                             spans.push(Span::styled(
-                                format!("{:.1}s ", app.refresh_ms as f64 / 1000.0),
+                                format!("{} ", format_interval(app.refresh_ms)),
                                 Style::default().fg(Color::Cyan),
                             ));
                         }
@@ -78,3 +91,32 @@ pub fn draw_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
 
     frame.render_widget(tabs, area);
 }
+
+/// Daemon heartbeat dot: bright right after a cache file is read, fading
+/// over a few seconds, gray while quiet, red after a minute without a
+/// successful read (or if none has succeeded yet).
+fn heartbeat_style(since_read: Option<Duration>) -> Style {
+    match since_read.map(|d| d.as_secs()) {
+        Some(0..=1) => Style::default()
+            .fg(Color::LightGreen)
+            .add_modifier(Modifier::BOLD),
+        Some(2..=5) => Style::default().fg(Color::Green),
+        Some(6..=60) => Style::default().fg(Color::DarkGray),
+        _ => Style::default().fg(Color::Red),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_fades_then_goes_red() {
+        let fg = |secs: Option<u64>| heartbeat_style(secs.map(Duration::from_secs)).fg;
+        assert_eq!(fg(Some(0)), Some(Color::LightGreen));
+        assert_eq!(fg(Some(3)), Some(Color::Green));
+        assert_eq!(fg(Some(30)), Some(Color::DarkGray));
+        assert_eq!(fg(Some(61)), Some(Color::Red));
+        assert_eq!(fg(None), Some(Color::Red));
+    }
+}
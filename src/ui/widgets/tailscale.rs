@@ -1,7 +1,11 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Cell, Row, Table};
+use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, TableState};
 
+use super::{no_data_text, render_scrolling_table, with_skew_warning};
 use crate::app::App;
+use crate::config::IpVersion;
+use crate::data::tailscale::PeerInfo;
+use crate::ui::gradient::gate;
 
 pub fn draw_tailscale(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
@@ -11,6 +15,7 @@ pub fn draw_tailscale(frame: &mut Frame, area: Rect, app: &App) {
 
     match &app.tailscale {
         Some(ts) => {
+            let block = with_skew_warning(block, ts.timestamp, app.clock_skew_secs);
             let online = ts.online_peers_sorted();
             // Aggregate bandwidth across all peers.
             let total_rx: i64 = online.iter().map(|p| p.rx_bytes).sum();
@@ -24,8 +29,13 @@ pub fn draw_tailscale(frame: &mut Frame, area: Rect, app: &App) {
             } else {
                 String::new()
             };
+            let dns_tag = if ts.magic_dns_suffix.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", ts.magic_dns_suffix.trim_end_matches('.'))
+            };
             let title = format!(
-                " Tailscale - {} ({}/{} online{bw_tag}) ",
+                " Tailscale - {}{dns_tag} ({}/{} online{bw_tag}) ",
                 ts.tailnet_name,
                 online.len(),
                 ts.total_peers
@@ -34,22 +44,60 @@ pub fn draw_tailscale(frame: &mut Frame, area: Rect, app: &App) {
             let hdr_style = Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD);
-            let header = Row::new(vec![
-                Cell::from("Host").style(hdr_style),
+            // Latency column only when the daemon measures it.
+            let show_latency = ts.has_latency();
+            let mut header_cells = vec![
+                Cell::from(if app.ts_show_fqdn { "FQDN" } else { "Host" }).style(hdr_style),
                 Cell::from("OS").style(hdr_style),
                 Cell::from("IP").style(hdr_style),
                 Cell::from("Seen").style(hdr_style),
+            ];
+            if show_latency {
+                header_cells.push(Cell::from("Ping").style(hdr_style));
+            }
+            header_cells.extend([
                 Cell::from("RX").style(hdr_style),
                 Cell::from("TX").style(hdr_style),
             ]);
+            let header = Row::new(header_cells);
+
+            let ip_width = match app.ip_version {
+                IpVersion::V4 => 16,
+                IpVersion::V6 => 26,
+            };
+            let mut widths = vec![
+                Constraint::Min(14),
+                Constraint::Length(8),
+                Constraint::Length(ip_width),
+                Constraint::Length(6),
+            ];
+            if show_latency {
+                widths.push(Constraint::Length(7));
+            }
+            // Exact counts only when they fit; a squeezed table keeps "1.2G".
+            let raw_width = online
+                .iter()
+                .flat_map(|p| [p.rx_bytes, p.tx_bytes])
+                .map(|b| format_bytes_raw(b).len() as u16)
+                .max()
+                .unwrap_or(0)
+                .max(9);
+            let raw =
+                app.ts_raw_bytes && raw_bytes_fit(&widths, raw_width, area.width.saturating_sub(2));
+            let bytes_width = if raw { raw_width } else { 9 };
+            widths.extend([
+                Constraint::Length(bytes_width),
+                Constraint::Length(bytes_width),
+            ]);
+            let fmt_bytes = if raw { format_bytes_raw } else { format_bytes };
 
             let rows: Vec<Row> = online
                 .iter()
                 .enumerate()
                 .map(|(i, p)| {
-                    let ip = p.tailscale_ips.first().cloned().unwrap_or_default();
+                    let ip = p.ip(app.ip_version).unwrap_or_default().to_string();
                     let bg = if i % 2 == 1 {
-                        Color::Rgb(30, 30, 40)
+                        gate(Color::Rgb(30, 30, 40))
                     } else {
                         Color::Reset
                     };
@@ -64,44 +112,110 @@ pub fn draw_tailscale(frame: &mut Frame, area: Rect, app: &App) {
                     } else {
                         Color::DarkGray
                     };
-                    Row::new(vec![
-                        Cell::from(p.hostname.clone()).style(Style::default().fg(Color::Green)),
+                    let mut cells = vec![
+                        Cell::from(p.display_name(app.ts_show_fqdn).to_string())
+                            .style(Style::default().fg(Color::Green)),
                         Cell::from(p.os.clone()).style(Style::default().fg(Color::Gray)),
                         Cell::from(ip).style(Style::default().fg(Color::Cyan)),
                         Cell::from(seen).style(Style::default().fg(seen_color)),
-                        Cell::from(format_bytes(p.rx_bytes))
+                    ];
+                    if show_latency {
+                        cells.push(latency_cell(p.latency_ms));
+                    }
+                    cells.extend([
+                        Cell::from(fmt_bytes(p.rx_bytes))
                             .style(Style::default().fg(Color::DarkGray)),
-                        Cell::from(format_bytes(p.tx_bytes))
+                        Cell::from(fmt_bytes(p.tx_bytes))
                             .style(Style::default().fg(Color::DarkGray)),
-                    ])
-                    .style(Style::default().bg(bg))
+                    ]);
+                    Row::new(cells).style(Style::default().bg(bg))
                 })
                 .collect();
 
-            let widths = [
-                Constraint::Min(14),
-                Constraint::Length(8),
-                Constraint::Length(16),
-                Constraint::Length(6),
-                Constraint::Length(9),
-                Constraint::Length(9),
-            ];
+            let block = block.title(title);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
 
-            let table = Table::new(rows, widths)
-                .header(header)
-                .block(block.title(title));
+            // Local node header line (when the daemon reports it and there's room).
+            let table_area = match ts.self_peer() {
+                Some(me) if inner.height >= 3 => {
+                    let chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Length(1), Constraint::Min(0)])
+                        .split(inner);
+                    frame.render_widget(
+                        Paragraph::new(self_line(me, app.ts_show_fqdn, app.ip_version)),
+                        chunks[0],
+                    );
+                    chunks[1]
+                }
+                _ => inner,
+            };
 
-            frame.render_widget(table, area);
+            let row_count = rows.len();
+            let table = Table::new(rows, widths);
+            render_scrolling_table(
+                frame,
+                table_area,
+                None,
+                table,
+                header,
+                row_count,
+                &mut TableState::default(),
+            );
         }
         None => {
-            let paragraph = ratatui::widgets::Paragraph::new("Waiting for daemon data...")
-                .style(Style::default().fg(Color::DarkGray))
-                .block(block.title(" Tailscale "));
+            let paragraph = Paragraph::new(no_data_text(
+                "Waiting for daemon data...",
+                app.daemon_silence(),
+            ))
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block.title(" Tailscale "));
             frame.render_widget(paragraph, area);
         }
     }
 }
 
+/// "Self: host  100.x.y.z  [exit node]" header for the local machine.
+fn self_line(me: &PeerInfo, fqdn: bool, version: IpVersion) -> Line<'static> {
+    let ip = me.ip(version).unwrap_or("-").to_string();
+    let mut spans = vec![
+        Span::styled("Self: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            me.display_name(fqdn).to_string(),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(ip, Style::default().fg(Color::Cyan)),
+    ];
+    if me.exit_node {
+        spans.push(Span::styled(
+            "  [exit node]",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Peer latency, green under 50ms, yellow under 150ms, red above.
+fn latency_cell(latency_ms: Option<f64>) -> Cell<'static> {
+    match latency_ms {
+        Some(ms) => {
+            let color = if ms < 50.0 {
+                Color::Green
+            } else if ms < 150.0 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            Cell::from(format!("{ms:.0}ms")).style(Style::default().fg(color))
+        }
+        None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+    }
+}
+
 fn format_relative_time(t: chrono::DateTime<chrono::Utc>) -> String {
     let now = chrono::Utc::now();
     let delta = now.signed_duration_since(t);
@@ -117,6 +231,37 @@ fn format_relative_time(t: chrono::DateTime<chrono::Utc>) -> String {
     }
 }
 
+/// Whether two `raw_width` RX/TX columns fit in `width` next to the other
+/// columns (`widths`, whose `Min` host column keeps its minimum).
+fn raw_bytes_fit(widths: &[Constraint], raw_width: u16, width: u16) -> bool {
+    let fixed: u16 = widths
+        .iter()
+        .map(|c| match c {
+            Constraint::Length(n) | Constraint::Min(n) => *n,
+            _ => 0,
+        })
+        .sum();
+    // One column of spacing between each pair of columns.
+    let spacing = widths.len() as u16 + 1;
+    fixed + spacing + 2 * raw_width <= width
+}
+
+/// Exact byte count with thousands separators: 1288490188 -> "1,288,490,188".
+fn format_bytes_raw(bytes: i64) -> String {
+    if bytes <= 0 {
+        return "-".into();
+    }
+    let digits = bytes.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
 fn format_bytes(bytes: i64) -> String {
     let bytes = bytes as u64;
     const GIB: u64 = 1024 * 1024 * 1024;
@@ -134,3 +279,29 @@ fn format_bytes(bytes: i64) -> String {
         "-".into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes_raw() {
+        assert_eq!(format_bytes_raw(1_288_490_188), "1,288,490,188");
+        assert_eq!(format_bytes_raw(999), "999");
+        assert_eq!(format_bytes_raw(1000), "1,000");
+        assert_eq!(format_bytes_raw(0), "-");
+    }
+
+    #[test]
+    fn test_raw_bytes_fit_needs_room() {
+        let widths = [
+            Constraint::Min(14),
+            Constraint::Length(8),
+            Constraint::Length(16),
+            Constraint::Length(6),
+        ];
+        // 44 fixed + 5 spacing + 2 * 13 raw = 75.
+        assert!(raw_bytes_fit(&widths, 13, 75));
+        assert!(!raw_bytes_fit(&widths, 13, 74));
+    }
+}
@@ -2,6 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Borders, Row, Table};
 
 use crate::app::App;
+use crate::ui::gradient::gate;
 
 pub fn draw_temperatures(frame: &mut Frame, area: Rect, app: &App) {
     let snap = app.sys.snapshot();
@@ -35,7 +36,7 @@ pub fn draw_temperatures(frame: &mut Frame, area: Rect, app: &App) {
         .title(title)
         .border_style(Style::default().fg(border_color));
 
-    if snap.temperatures.is_empty() {
+    if snap.temperatures.is_empty() && snap.fans.is_empty() {
         let p = ratatui::widgets::Paragraph::new("No sensors")
             .style(Style::default().fg(Color::DarkGray))
             .block(block);
@@ -43,61 +44,64 @@ pub fn draw_temperatures(frame: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
-    let header = Row::new(vec!["Sensor", "Temp", "Max"]).style(
+    // Fans share the columns, so "Temp" only fits a temperature-only table.
+    let now_label = if snap.fans.is_empty() { "Temp" } else { "Now" };
+    let header = Row::new(vec!["Sensor", now_label, "Max"]).style(
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD),
     );
 
-    let rows: Vec<Row> = snap
-        .temperatures
-        .iter()
+    let temp_rows = snap.temperatures.iter().map(|t| {
+        let color = app.temp_gradient.color(t.temp_c as f64);
+        let cells = vec![
+            truncate_label(&t.label, 22),
+            format!("{:.0}°C", t.temp_c),
+            if t.max_c > 0.0 {
+                format!("{:.0}°C", t.max_c)
+            } else {
+                "-".into()
+            },
+        ];
+        (cells, color)
+    });
+    // Fans after the temperatures, colored by how close they run to their
+    // rated maximum (gray when the driver reports none).
+    let fan_rows = snap.fans.iter().map(|f| {
+        let color = f
+            .percent()
+            .map_or(Color::Gray, |pct| app.gradient.color(pct));
+        let cells = vec![
+            truncate_label(&f.label, 22),
+            format!("{}rpm", f.rpm),
+            f.max_rpm
+                .map_or_else(|| "-".into(), |max| format!("{max}rpm")),
+        ];
+        (cells, color)
+    });
+    let rows: Vec<Row> = temp_rows
+        .chain(fan_rows)
         .enumerate()
-        .map(|(i, t)| {
-            let color = temp_gradient(t.temp_c);
+        .map(|(i, (cells, color))| {
             let bg = if i % 2 == 1 {
-                Color::Rgb(30, 30, 40)
+                gate(Color::Rgb(30, 30, 40))
             } else {
                 Color::Reset
             };
-            Row::new(vec![
-                truncate_label(&t.label, 22),
-                format!("{:.0}°C", t.temp_c),
-                if t.max_c > 0.0 {
-                    format!("{:.0}°C", t.max_c)
-                } else {
-                    "-".into()
-                },
-            ])
-            .style(Style::default().fg(color).bg(bg))
+            Row::new(cells).style(Style::default().fg(color).bg(bg))
         })
         .collect();
 
     let widths = [
         Constraint::Min(12),
-        Constraint::Length(7),
-        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(8),
     ];
 
     let table = Table::new(rows, widths).header(header).block(block);
     frame.render_widget(table, area);
 }
 
-/// 5-step temperature gradient matching btm aesthetics.
-fn temp_gradient(temp: f32) -> Color {
-    if temp >= 90.0 {
-        Color::Red
-    } else if temp >= 80.0 {
-        Color::Rgb(255, 100, 0)
-    } else if temp >= 65.0 {
-        Color::Yellow
-    } else if temp >= 45.0 {
-        Color::Rgb(150, 255, 0)
-    } else {
-        Color::Green
-    }
-}
-
 fn truncate_label(label: &str, max: usize) -> String {
     if label.len() <= max {
         label.to_string()
@@ -0,0 +1,74 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use super::format_rate;
+use crate::app::App;
+use crate::ui::gradient::net_rate_color;
+
+/// One-line vital signs under the tab bar (`display.top_strip`):
+/// "CPU 23%  MEM 61%  NET ↓1.2 MB/s ↑30 KB/s  LOAD 0.52", each value
+/// colored like its full widget.
+pub fn draw_top_strip(frame: &mut Frame, area: Rect, app: &App) {
+    frame.render_widget(Paragraph::new(strip_line(app)), area);
+}
+
+fn strip_line(app: &App) -> Line<'static> {
+    let cpu = app.cpu_display_total() as f64;
+    let mem = app.mem_history.back().copied().unwrap_or(0.0);
+    let rx = app.net_rx_history.back().copied().unwrap_or(0.0) as u64;
+    let tx = app.net_tx_history.back().copied().unwrap_or(0.0) as u64;
+    let load = app.load_history.back().copied().unwrap_or(0.0);
+    let cores = app.sys.snapshot().cpu_count.max(1) as f64;
+    let idle = app.net_idle_threshold;
+
+    let label = |text: &str| Span::styled(text.to_string(), Style::default().fg(Color::DarkGray));
+    let value = |text: String, color: Color| {
+        Span::styled(
+            text,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )
+    };
+    Line::from(vec![
+        label(" CPU "),
+        value(format!("{cpu:.0}%"), app.gradient.color(cpu)),
+        label("  MEM "),
+        value(format!("{mem:.0}%"), app.gradient.color(mem)),
+        label("  NET "),
+        value(
+            format!("\u{2193}{}", format_rate(rx, idle)),
+            net_rate_color(rx, idle),
+        ),
+        Span::raw(" "),
+        value(
+            format!("\u{2191}{}", format_rate(tx, idle)),
+            net_rate_color(tx, idle),
+        ),
+        label("  LOAD "),
+        value(
+            format!("{load:.2}"),
+            app.gradient.color((load / cores * 100.0).min(100.0)),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TuiConfig;
+
+    #[test]
+    fn test_strip_line() {
+        let mut app = App::test_new(TuiConfig::default());
+        app.mem_history.push_back(61.4);
+        app.net_rx_history.push_back(2048.0);
+        app.load_history.push_back(0.52);
+        let text: String = strip_line(&app)
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert!(text.contains("MEM 61%"), "{text}");
+        assert!(text.contains("NET \u{2193}2 KB/s \u{2191}idle"), "{text}");
+        assert!(text.ends_with("LOAD 0.52"), "{text}");
+    }
+}
@@ -5,9 +5,15 @@ use ratatui_image::{Resize, StatefulImage};
 use crate::app::App;
 
 pub fn draw_waifu(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.waifu_rect = Some(area);
+
     let protocol_name = format!("{:?}", app.picker.protocol_type());
     let category = app.cfg.waifu_category();
-    let fetch_indicator = if app.waifu_fetching { " ..." } else { "" };
+    let fetch_indicator = if app.waifu_fetching {
+        format!(" {}", app.spinner_char())
+    } else {
+        String::new()
+    };
 
     let gallery_info = if !app.waifu_gallery.is_empty() && app.waifu_index >= 0 {
         format!(" [{}/{}]", app.waifu_index + 1, app.waifu_gallery.len())